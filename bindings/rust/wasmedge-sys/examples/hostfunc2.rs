@@ -16,7 +16,8 @@ use std::{
     io::Read,
 };
 use wasmedge_sys::{
-    Config, FuncType, Function, ImportInstance, ImportModule, ImportObject, Loader, Vm, WasmValue,
+    Config, ExternRef, FuncType, Function, ImportInstance, ImportModule, ImportObject, Loader, Vm,
+    WasmValue,
 };
 use wasmedge_types::ValType;
 
@@ -88,7 +89,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut vm = Vm::create(Some(config), None)?;
     vm.register_wasm_from_import(ImportObject::Import(import))?;
 
-    let add_ref = WasmValue::from_extern_ref(&mut real_add);
+    let add_ref = WasmValue::from_extern_ref(ExternRef::new(real_add));
     match vm.run_wasm_from_module(
         module,
         "call_add",
@@ -1,13 +1,15 @@
-//! Defines WasmEdge AST Module, Export, and Import structs.
+//! Defines WasmEdge AST Module, Export, Import, and CustomSection structs.
 
 use super::ffi;
 use crate::{
-    error::{ExportError, ImportError, WasmEdgeError},
+    error::{ExportError, ImportError, ModuleError, WasmEdgeError},
     WasmEdgeResult,
 };
-use std::{borrow::Cow, ffi::CStr};
+use std::{borrow::Cow, collections::BTreeMap, ffi::CStr};
 use wasmedge_types::{
-    ExternalInstanceType, FuncType, GlobalType, MemoryType, Mutability, RefType, TableType, ValType,
+    inspector::{BinaryReader, ReadResult},
+    ExternalInstanceType, FuncType, GlobalType, MemoryType, Mutability, RefType, TableType,
+    ValType,
 };
 
 /// Defines compiled in-memory representation of an input WASM binary.
@@ -73,6 +75,60 @@ impl Module {
             })
             .collect()
     }
+
+    /// Returns the count of custom sections in the [Module].
+    pub fn count_of_custom_sections(&self) -> u32 {
+        unsafe { ffi::WasmEdge_ASTModuleListCustomSectionsLength(self.inner.0) }
+    }
+
+    /// Returns the custom sections in the [Module], in the order they appear in the wasm binary.
+    pub fn custom_sections(&self) -> Vec<CustomSection<'_>> {
+        (0..self.count_of_custom_sections())
+            .map(|idx| {
+                let name = unsafe {
+                    let raw_name = ffi::WasmEdge_ASTModuleGetCustomSectionName(self.inner.0, idx);
+                    match raw_name.Buf.is_null() {
+                        true => &[],
+                        false => std::slice::from_raw_parts(
+                            raw_name.Buf as *const u8,
+                            raw_name.Length as usize,
+                        ),
+                    }
+                };
+                let data = unsafe {
+                    let mut content = std::ptr::null();
+                    let len = ffi::WasmEdge_ASTModuleGetCustomSectionContent(
+                        self.inner.0,
+                        idx,
+                        &mut content,
+                    );
+                    match content.is_null() {
+                        true => &[],
+                        false => std::slice::from_raw_parts(content, len as usize),
+                    }
+                };
+                CustomSection { name, data }
+            })
+            .collect()
+    }
+
+    /// Returns the decoded `name` custom section of the [Module].
+    ///
+    /// If the [Module] has no `name` section, then `None` is returned. Unknown subsections are skipped.
+    ///
+    /// # Error
+    ///
+    /// If the `name` section is malformed, then an error is returned.
+    pub fn name_section(&self) -> WasmEdgeResult<Option<NameSection>> {
+        match self
+            .custom_sections()
+            .into_iter()
+            .find(|section| section.name == b"name")
+        {
+            Some(section) => NameSection::decode(section.data).map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -181,12 +237,8 @@ impl<'module> ImportType<'module> {
                     ))),
                     false => {
                         let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(ctx_mem_ty) };
-                        let limit: std::ops::RangeInclusive<u32> = limit.into();
 
-                        Ok(ExternalInstanceType::Memory(MemoryType::new(
-                            limit.start().to_owned(),
-                            Some(limit.end().to_owned()),
-                        )))
+                        Ok(ExternalInstanceType::Memory(MemoryType::from(limit)))
                     }
                 }
             }
@@ -205,12 +257,11 @@ impl<'module> ImportType<'module> {
 
                         // get the limit
                         let limit = unsafe { ffi::WasmEdge_TableTypeGetLimit(ctx_tab_ty) };
-                        let limit: std::ops::RangeInclusive<u32> = limit.into();
 
                         Ok(ExternalInstanceType::Table(TableType::new(
                             elem_ty,
-                            limit.start().to_owned(),
-                            Some(limit.end().to_owned()),
+                            limit.Min,
+                            limit.HasMax.then_some(limit.Max),
                         )))
                     }
                 }
@@ -322,12 +373,11 @@ impl<'module> ExportType<'module> {
 
                         // get the limit
                         let limit = unsafe { ffi::WasmEdge_TableTypeGetLimit(ctx_tab_ty) };
-                        let limit: std::ops::RangeInclusive<u32> = limit.into();
 
                         Ok(ExternalInstanceType::Table(TableType::new(
                             elem_ty,
-                            limit.start().to_owned(),
-                            Some(limit.end().to_owned()),
+                            limit.Min,
+                            limit.HasMax.then_some(limit.Max),
                         )))
                     }
                 }
@@ -342,12 +392,8 @@ impl<'module> ExportType<'module> {
                     ))),
                     false => {
                         let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(ctx_mem_ty) };
-                        let limit: std::ops::RangeInclusive<u32> = limit.into();
 
-                        Ok(ExternalInstanceType::Memory(MemoryType::new(
-                            limit.start().to_owned(),
-                            Some(limit.end().to_owned()),
-                        )))
+                        Ok(ExternalInstanceType::Memory(MemoryType::from(limit)))
                     }
                 }
             }
@@ -392,14 +438,134 @@ pub(crate) struct InnerExportType(pub(crate) *const ffi::WasmEdge_ExportTypeCont
 unsafe impl Send for InnerExportType {}
 unsafe impl Sync for InnerExportType {}
 
+/// Defines a custom section of a [Module], which consists of a name and the raw bytes following the name.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomSection<'module> {
+    name: &'module [u8],
+    data: &'module [u8],
+}
+impl<'module> CustomSection<'module> {
+    /// Returns the name of this custom section.
+    pub fn name(&self) -> Cow<'module, str> {
+        String::from_utf8_lossy(self.name)
+    }
+
+    /// Returns the content of this custom section, excluding the name.
+    pub fn data(&self) -> &'module [u8] {
+        self.data
+    }
+}
+
+/// Defines the decoded `name` custom section of a [Module], which maps the indexes of the module, functions and
+/// locals to their debug names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NameSection {
+    module: Option<String>,
+    funcs: BTreeMap<u32, String>,
+    locals: BTreeMap<u32, BTreeMap<u32, String>>,
+}
+impl NameSection {
+    /// Returns the name of the module.
+    pub fn module_name(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    /// Returns the name of the function at the given index.
+    ///
+    /// # Argument
+    ///
+    /// * `func_idx` - The index of the function in the function index space, imported functions included.
+    pub fn func_name(&self, func_idx: u32) -> Option<&str> {
+        self.funcs.get(&func_idx).map(|x| x.as_str())
+    }
+
+    /// Returns the names of the functions keyed by the function indexes.
+    pub fn func_names(&self) -> &BTreeMap<u32, String> {
+        &self.funcs
+    }
+
+    /// Returns the name of a local of the function at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `func_idx` - The index of the function in the function index space, imported functions included.
+    ///
+    /// * `local_idx` - The index of the local, parameters included.
+    pub fn local_name(&self, func_idx: u32, local_idx: u32) -> Option<&str> {
+        self.locals
+            .get(&func_idx)
+            .and_then(|locals| locals.get(&local_idx))
+            .map(|x| x.as_str())
+    }
+
+    /// Returns the names of the locals of the function at the given index, keyed by the local indexes.
+    ///
+    /// # Argument
+    ///
+    /// * `func_idx` - The index of the function in the function index space, imported functions included.
+    pub fn local_names(&self, func_idx: u32) -> Option<&BTreeMap<u32, String>> {
+        self.locals.get(&func_idx)
+    }
+
+    fn decode(bytes: &[u8]) -> WasmEdgeResult<Self> {
+        let mut names = NameSection::default();
+        let mut reader = BinaryReader::new(bytes);
+        let result: ReadResult<()> = (|| {
+            while !reader.is_empty() {
+                let id = reader.read_u8()?;
+                let size = reader.read_u32()? as usize;
+                let mut sub = reader.sub_reader(size)?;
+                match id {
+                    0 => names.module = Some(sub.read_name()?),
+                    1 => names.funcs = read_name_map(&mut sub)?,
+                    2 => {
+                        for (func_idx, locals) in sub.read_vec(|r| {
+                            let func_idx = r.read_u32()?;
+                            Ok((func_idx, read_name_map(r)?))
+                        })? {
+                            names.locals.insert(func_idx, locals);
+                        }
+                    }
+                    // skip the subsections of the extended name section proposal
+                    _ => continue,
+                }
+                if !sub.is_empty() {
+                    return Err(sub.error(format!(
+                        "subsection {} has {} trailing bytes",
+                        id,
+                        sub.remaining()
+                    )));
+                }
+            }
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => Ok(names),
+            Err(err) => Err(WasmEdgeError::Module(ModuleError::MalformedNameSection(
+                err.reason,
+            ))),
+        }
+    }
+}
+
+fn read_name_map(reader: &mut BinaryReader<'_>) -> ReadResult<BTreeMap<u32, String>> {
+    let entries = reader.read_vec(|r| {
+        let idx = r.read_u32()?;
+        Ok((idx, r.read_name()?))
+    })?;
+    Ok(entries.into_iter().collect())
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{Config, Loader};
     use std::{
         sync::{Arc, Mutex},
         thread,
     };
-    use wasmedge_types::{ExternalInstanceType, Mutability, RefType, ValType};
+    use wasmedge_types::{wat2wasm, ExternalInstanceType, Mutability, RefType, ValType};
 
     #[test]
     fn test_module_import() {
@@ -529,7 +695,7 @@ mod tests {
         if let ExternalInstanceType::Table(table_ty) = ty {
             assert_eq!(table_ty.elem_ty(), RefType::ExternRef);
             assert_eq!(table_ty.minimum(), 10);
-            assert_eq!(table_ty.maximum(), 30);
+            assert_eq!(table_ty.maximum(), Some(30));
         }
 
         // check the memory_type function
@@ -539,7 +705,7 @@ mod tests {
         matches!(ty, ExternalInstanceType::Memory(_));
         if let ExternalInstanceType::Memory(mem_ty) = ty {
             assert_eq!(mem_ty.minimum(), 2);
-            assert_eq!(mem_ty.maximum(), 2);
+            assert_eq!(mem_ty.maximum(), None);
         }
 
         // check the global_type function
@@ -679,7 +845,7 @@ mod tests {
         if let ExternalInstanceType::Table(table_ty) = ty {
             assert_eq!(table_ty.elem_ty(), RefType::ExternRef);
             assert_eq!(table_ty.minimum(), 10);
-            assert_eq!(table_ty.maximum(), 10);
+            assert_eq!(table_ty.maximum(), None);
         }
 
         // check the memory_type function
@@ -689,7 +855,7 @@ mod tests {
         matches!(ty, ExternalInstanceType::Memory(_));
         if let ExternalInstanceType::Memory(mem_ty) = ty {
             assert_eq!(mem_ty.minimum(), 1);
-            assert_eq!(mem_ty.maximum(), 3);
+            assert_eq!(mem_ty.maximum(), Some(3));
         }
 
         // check the global_type function
@@ -703,6 +869,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_module_custom_sections() {
+        let result = wat2wasm(
+            br#"
+            (module $meta_module
+                (@custom "meta" "\01\02")
+                (func $add (param $lhs i32) (param $rhs i32) (result i32)
+                    (local $tmp i32)
+                    local.get $lhs
+                    local.get $rhs
+                    i32.add)
+                (func $nop)
+                (export "add" (func $add))
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_bytes(&wasm_bytes);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+
+        // check custom sections
+        assert_eq!(module.count_of_custom_sections(), 2);
+        let sections = module.custom_sections();
+        assert_eq!(sections.len(), 2);
+        let meta = sections.iter().find(|x| x.name() == "meta");
+        assert!(meta.is_some());
+        assert_eq!(meta.unwrap().data(), [0x01, 0x02]);
+        assert!(sections.iter().any(|x| x.name() == "name"));
+
+        // check name section
+        let result = module.name_section();
+        assert!(result.is_ok());
+        let names = result.unwrap();
+        assert!(names.is_some());
+        let names = names.unwrap();
+        assert_eq!(names.module_name(), Some("meta_module"));
+        assert_eq!(names.func_name(0), Some("add"));
+        assert_eq!(names.func_name(1), Some("nop"));
+        assert_eq!(names.func_name(2), None);
+        assert_eq!(names.func_names().len(), 2);
+        assert_eq!(names.local_name(0, 0), Some("lhs"));
+        assert_eq!(names.local_name(0, 1), Some("rhs"));
+        assert_eq!(names.local_name(0, 2), Some("tmp"));
+        assert_eq!(names.local_name(1, 0), None);
+
+        // a module without custom sections
+        let result = wat2wasm(b"(module)");
+        assert!(result.is_ok());
+        let result = loader.from_bytes(result.unwrap());
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert_eq!(module.count_of_custom_sections(), 0);
+        assert!(module.custom_sections().is_empty());
+        let result = module.name_section();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+
+        // a malformed name section
+        let result = NameSection::decode(&[0x01, 0x05, 0x01, 0x00]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Module(ModuleError::MalformedNameSection("unexpected end".into()))
+        );
+    }
+
     #[test]
     fn test_module_send() {
         let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
@@ -830,7 +1068,7 @@ mod tests {
             if let ExternalInstanceType::Table(table_ty) = ty {
                 assert_eq!(table_ty.elem_ty(), RefType::ExternRef);
                 assert_eq!(table_ty.minimum(), 10);
-                assert_eq!(table_ty.maximum(), 10);
+                assert_eq!(table_ty.maximum(), None);
             }
 
             // check the memory_type function
@@ -840,7 +1078,7 @@ mod tests {
             matches!(ty, ExternalInstanceType::Memory(_));
             if let ExternalInstanceType::Memory(mem_ty) = ty {
                 assert_eq!(mem_ty.minimum(), 1);
-                assert_eq!(mem_ty.maximum(), 3);
+                assert_eq!(mem_ty.maximum(), Some(3));
             }
 
             // check the global_type function
@@ -989,7 +1227,7 @@ mod tests {
             if let ExternalInstanceType::Table(table_ty) = ty {
                 assert_eq!(table_ty.elem_ty(), RefType::ExternRef);
                 assert_eq!(table_ty.minimum(), 10);
-                assert_eq!(table_ty.maximum(), 10);
+                assert_eq!(table_ty.maximum(), None);
             }
 
             // check the memory_type function
@@ -999,7 +1237,7 @@ mod tests {
             matches!(ty, ExternalInstanceType::Memory(_));
             if let ExternalInstanceType::Memory(mem_ty) = ty {
                 assert_eq!(mem_ty.minimum(), 1);
-                assert_eq!(mem_ty.maximum(), 3);
+                assert_eq!(mem_ty.maximum(), Some(3));
             }
 
             // check the global_type function
@@ -50,7 +50,8 @@ use wasmedge_types::{CompilerOptimizationLevel, CompilerOutputFormat};
 ///
 ///       Also see [Annotations Proposal](https://github.com/WebAssembly/annotations/blob/master/proposals/annotations/Overview.md).
 ///
-///     - `Memory64` supports 64-bit memory indexes.
+///     - `Memory64` supports 64-bit memory indexes. The WasmEdge runtime does not implement this proposal yet, so
+///       the modules declaring 64-bit memories fail to load even if the option is turned on.
 ///
 ///       Also see [Memory64 Proposal](https://github.com/WebAssembly/memory64/blob/main/proposals/memory64/Overview.md).
 ///
@@ -548,6 +549,9 @@ impl Config {
 
     /// Enables or disables the Memory64 option.
     ///
+    /// The WasmEdge runtime does not implement the Memory64 proposal yet. Turning on this option has no effect:
+    /// the memories are always indexed by 32-bit values.
+    ///
     /// # Argument
     ///
     /// * `enable` - Whether the option turns on or not.
@@ -0,0 +1,82 @@
+//! Reads the WasmEdge Diagnostic of a failed loading or validation.
+
+use crate::{
+    error::{Diagnostic, WasmEdgeError},
+    ffi,
+    utils::check,
+    WasmEdgeResult,
+};
+use std::sync::Mutex;
+
+/// Runs a loading or validation call, and attaches the [Diagnostic] recorded by the call to the returned error.
+///
+/// The lock is held until the diagnostic is read, so that a concurrent call on the same context cannot overwrite
+/// the diagnostic in between.
+pub(crate) fn diagnosed(
+    lock: &Mutex<()>,
+    call: impl FnOnce() -> ffi::WasmEdge_Result,
+    diagnostic: impl FnOnce() -> *const ffi::WasmEdge_DiagnosticContext,
+) -> WasmEdgeResult<()> {
+    let _guard = lock.lock().expect("[wasmedge-sys] try lock failed.");
+    check(call()).map_err(|err| match (err, from_raw(diagnostic())) {
+        (WasmEdgeError::Core(error), Some(diagnostic)) => WasmEdgeError::Diagnosed {
+            error,
+            diagnostic: Box::new(diagnostic),
+        },
+        (err, _) => err,
+    })
+}
+
+/// Reads the diagnostic from the given context. Returns `None` if nothing is recorded.
+fn from_raw(ctx: *const ffi::WasmEdge_DiagnosticContext) -> Option<Diagnostic> {
+    if ctx.is_null() {
+        return None;
+    }
+
+    let section = match to_string(unsafe { ffi::WasmEdge_DiagnosticGetSection(ctx) }) {
+        Some(section) if section != "module" => Some(section),
+        _ => None,
+    };
+    let mut offset = 0u64;
+    let offset = unsafe { ffi::WasmEdge_DiagnosticGetOffset(ctx, &mut offset) }.then_some(offset);
+    let mut func_index = 0u32;
+    let func_index = unsafe { ffi::WasmEdge_DiagnosticGetFunctionIndex(ctx, &mut func_index) }
+        .then_some(func_index);
+    let instruction = to_string(unsafe { ffi::WasmEdge_DiagnosticGetInstruction(ctx) });
+
+    let len =
+        unsafe { ffi::WasmEdge_DiagnosticGetExpectedTypes(ctx, std::ptr::null_mut(), 0) as usize };
+    let mut expected = Vec::with_capacity(len);
+    unsafe {
+        ffi::WasmEdge_DiagnosticGetExpectedTypes(ctx, expected.as_mut_ptr(), len as u32);
+        expected.set_len(len);
+    }
+    let len =
+        unsafe { ffi::WasmEdge_DiagnosticGetActualTypes(ctx, std::ptr::null_mut(), 0) as usize };
+    let mut actual = Vec::with_capacity(len);
+    unsafe {
+        ffi::WasmEdge_DiagnosticGetActualTypes(ctx, actual.as_mut_ptr(), len as u32);
+        actual.set_len(len);
+    }
+
+    let diagnostic = Diagnostic {
+        section,
+        offset,
+        func_index,
+        instruction,
+        expected: expected.into_iter().map(Into::into).collect(),
+        actual: actual.into_iter().map(Into::into).collect(),
+    };
+    match diagnostic.is_empty() {
+        true => None,
+        false => Some(diagnostic),
+    }
+}
+
+fn to_string(s: ffi::WasmEdge_String) -> Option<String> {
+    if s.Buf.is_null() || s.Length == 0 {
+        return None;
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(s.Buf as *const u8, s.Length as usize) };
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
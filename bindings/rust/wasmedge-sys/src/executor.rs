@@ -2,16 +2,28 @@
 
 use super::ffi;
 use crate::{
-    error::WasmEdgeError, instance::module::InnerInstance, types::WasmEdgeString, utils::check,
+    ast_module::NameSection,
+    error::{CoreError, Trap, TrapFrame, WasmEdgeError},
+    instance::{
+        function::{take_host_failure, HostFailure},
+        module::InnerInstance,
+    },
+    types::WasmEdgeString,
+    utils::check,
     Config, Engine, FuncRef, Function, ImportObject, Instance, Module, Statistics, Store,
     WasmEdgeResult, WasmValue,
 };
+use std::collections::HashMap;
 
 /// Defines an execution environment for both pure WASM and compiled WASM.
 #[derive(Debug)]
 pub struct Executor {
     pub(crate) inner: InnerExecutor,
     pub(crate) registered: bool,
+    /// The name section of the latest anonymous module, used to resolve the function names of trap backtraces.
+    active_names: Option<NameSection>,
+    /// The name sections of the named modules.
+    names: HashMap<String, NameSection>,
 }
 impl Executor {
     /// Creates a new [executor](crate::Executor) to be associated with the given [config](crate::Config) and [statistics](crate::Statistics).
@@ -56,6 +68,8 @@ impl Executor {
             false => Ok(Executor {
                 inner: InnerExecutor(ctx),
                 registered: false,
+                active_names: None,
+                names: HashMap::new(),
             }),
         }
     }
@@ -117,7 +131,8 @@ impl Executor {
     ///
     /// # Error
     ///
-    /// If fail to register the given [module](crate::Module), then an error is returned.
+    /// If fail to register the given [module](crate::Module), then an error is returned. If the start function traps,
+    /// then a [Trap](crate::error::Trap) is returned, which carries the backtrace of the WASM call stack.
     pub fn register_named_module(
         &mut self,
         store: &mut Store,
@@ -126,20 +141,45 @@ impl Executor {
     ) -> WasmEdgeResult<Instance> {
         let mut instance_ctx = std::ptr::null_mut();
         let mod_name: WasmEdgeString = name.as_ref().into();
-        unsafe {
+        let names = module.name_section().ok().flatten();
+        take_host_failure();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorRegister(
                 self.inner.0,
                 &mut instance_ctx,
                 store.inner.0,
                 module.inner.0 as *const _,
                 mod_name.as_raw(),
-            ))?;
-        }
+            ))
+            .map_err(|err| {
+                trap(err, |module_name, func_index| match module_name {
+                    Some(module_name) if module_name == name.as_ref() => names
+                        .as_ref()
+                        .and_then(|names| names.func_name(func_index))
+                        .map(ToString::to_string),
+                    _ => self.func_name(module_name, func_index),
+                })
+            })
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_instantiation(start, &result);
+        result?;
+        match names {
+            Some(names) => self.names.insert(name.as_ref().to_string(), names),
+            None => self.names.remove(name.as_ref()),
+        };
 
-        Ok(Instance {
+        let instance = Instance {
             inner: InnerInstance(instance_ctx),
             registered: false,
-        })
+            #[cfg(feature = "metrics")]
+            memory_pages: Default::default(),
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_memory_pages(&instance);
+        Ok(instance)
     }
 
     /// Registers and instantiates a WasmEdge [module](crate::Module) into a [store](crate::Store) as an anonymous module.
@@ -156,25 +196,61 @@ impl Executor {
     ///
     /// # Error
     ///
-    /// If fail to instantiate the given [module](crate::Module), then an error is returned.
+    /// If fail to instantiate the given [module](crate::Module), then an error is returned. If the start function traps,
+    /// then a [Trap](crate::error::Trap) is returned, which carries the backtrace of the WASM call stack.
     pub fn register_active_module(
         &mut self,
         store: &mut Store,
         module: &Module,
     ) -> WasmEdgeResult<Instance> {
         let mut instance_ctx = std::ptr::null_mut();
-        unsafe {
+        let names = module.name_section().ok().flatten();
+        take_host_failure();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorInstantiate(
                 self.inner.0,
                 &mut instance_ctx,
                 store.inner.0,
                 module.inner.0 as *const _,
-            ))?;
-        }
-        Ok(Instance {
+            ))
+            .map_err(|err| {
+                trap(err, |module_name, func_index| match module_name {
+                    None => names
+                        .as_ref()
+                        .and_then(|names| names.func_name(func_index))
+                        .map(ToString::to_string),
+                    _ => self.func_name(module_name, func_index),
+                })
+            })
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_instantiation(start, &result);
+        result?;
+        self.active_names = names;
+
+        let instance = Instance {
             inner: InnerInstance(instance_ctx),
             registered: false,
-        })
+            #[cfg(feature = "metrics")]
+            memory_pages: Default::default(),
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_memory_pages(&instance);
+        Ok(instance)
+    }
+
+    /// Resolves the name of a function from the name section of the module, which is the latest anonymous module if
+    /// the module name is `None`.
+    fn func_name(&self, module_name: Option<&str>, func_index: u32) -> Option<String> {
+        let names = match module_name {
+            Some(name) => self.names.get(name),
+            None => self.active_names.as_ref(),
+        };
+        names
+            .and_then(|names| names.func_name(func_index))
+            .map(ToString::to_string)
     }
 }
 impl Drop for Executor {
@@ -184,20 +260,82 @@ impl Drop for Executor {
         }
     }
 }
+/// Converts the error of invoking a function into the failure of the host function called during the invocation, if
+/// any, or into a [Trap] which carries the backtrace recorded by the invocation.
+///
+/// Only an execution error or a panic of a host function is reported as a [Trap]. `func_name` resolves the name of a
+/// function by the name of its module and its index.
+pub(crate) fn trap(
+    err: WasmEdgeError,
+    func_name: impl Fn(Option<&str>, u32) -> Option<String>,
+) -> WasmEdgeError {
+    let host_panic = match take_host_failure() {
+        Some(HostFailure::Error(err)) => return err,
+        Some(HostFailure::Panic(message)) => Some(message),
+        None => None,
+    };
+    let error = match err {
+        WasmEdgeError::Core(error @ CoreError::Execution(_)) => error,
+        WasmEdgeError::Core(error) if host_panic.is_some() => error,
+        err => return err,
+    };
+
+    let len = unsafe { ffi::WasmEdge_TrapGetBacktraceLength() };
+    if len == 0 && host_panic.is_none() {
+        return WasmEdgeError::Core(error);
+    }
+    let mut raw_frames = Vec::with_capacity(len as usize);
+    unsafe {
+        let len = ffi::WasmEdge_TrapGetBacktrace(raw_frames.as_mut_ptr(), len).min(len);
+        raw_frames.set_len(len as usize);
+    }
+
+    let frames = raw_frames
+        .iter()
+        .map(|frame| {
+            let module_name = match frame.ModuleName.Length {
+                0 => None,
+                len => {
+                    let bytes = unsafe {
+                        std::slice::from_raw_parts(frame.ModuleName.Buf as *const u8, len as usize)
+                    };
+                    Some(String::from_utf8_lossy(bytes).into_owned())
+                }
+            };
+            TrapFrame {
+                func_name: func_name(module_name.as_deref(), frame.FunctionIndex),
+                module_name,
+                func_index: frame.FunctionIndex,
+                offset: frame.HasOffset.then_some(frame.Offset),
+            }
+        })
+        .collect();
+    WasmEdgeError::Trap(Trap {
+        error,
+        frames,
+        host_panic,
+    })
+}
+
 impl Engine for Executor {
     fn run_func(
         &mut self,
         func: &Function,
         params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
-        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let params = params.into_iter().collect::<Vec<_>>();
 
-        // get the length of the function's returns
+        // check the arguments against the function's parameters
         let func_ty = func.ty()?;
+        func_ty.check_params(&params)?;
+        let raw_params = params.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        // get the length of the function's returns
         let returns_len = func_ty.returns_len();
         let mut returns = Vec::with_capacity(returns_len as usize);
 
-        unsafe {
+        take_host_failure();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorInvoke(
                 self.inner.0,
                 func.inner.0 as *const _,
@@ -205,9 +343,17 @@ impl Engine for Executor {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
+            ))
+            .map_err(|err| {
+                trap(err, |module_name, func_index| {
+                    self.func_name(module_name, func_index)
+                })
+            })
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_execution(&result);
+        result?;
+        unsafe { returns.set_len(returns_len as usize) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
@@ -217,14 +363,19 @@ impl Engine for Executor {
         func_ref: &FuncRef,
         params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
-        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let params = params.into_iter().collect::<Vec<_>>();
 
-        // get the length of the function's returns
+        // check the arguments against the function's parameters
         let func_ty = func_ref.ty()?;
+        func_ty.check_params(&params)?;
+        let raw_params = params.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        // get the length of the function's returns
         let returns_len = func_ty.returns_len();
         let mut returns = Vec::with_capacity(returns_len as usize);
 
-        unsafe {
+        take_host_failure();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorInvoke(
                 self.inner.0,
                 func_ref.inner.0 as *const _,
@@ -232,9 +383,17 @@ impl Engine for Executor {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
+            ))
+            .map_err(|err| {
+                trap(err, |module_name, func_index| {
+                    self.func_name(module_name, func_index)
+                })
+            })
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_execution(&result);
+        result?;
+        unsafe { returns.set_len(returns_len as usize) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
@@ -249,8 +408,8 @@ unsafe impl Sync for InnerExecutor {}
 mod tests {
     use super::*;
     use crate::{
-        Config, FuncType, Function, Global, GlobalType, ImportInstance, ImportModule, MemType,
-        Memory, Statistics, Table, TableType,
+        error::CoreExecutionError, Config, FuncType, Function, Global, GlobalType, ImportInstance,
+        ImportModule, Loader, MemType, Memory, Statistics, Table, TableType, Validator,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -447,6 +606,111 @@ mod tests {
         handle.join().unwrap();
     }
 
+    #[test]
+    fn test_executor_trap_backtrace() {
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_wat(
+            r#"
+            (module
+                (func $divide (param i32) (result i32)
+                    i32.const 1
+                    local.get 0
+                    i32.div_s)
+                (func (export "entry") (param i32) (result i32)
+                    local.get 0
+                    call $divide)
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        assert!(validator.validate(&module).is_ok());
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.get_func("entry");
+        assert!(result.is_ok());
+        let func = result.unwrap();
+
+        // the trap carries the frames of the invocation
+        let result = executor.run_func(&func, [WasmValue::from_i32(0)]);
+        assert!(result.is_err());
+        let trap = match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => trap,
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(
+            trap.error,
+            CoreError::Execution(CoreExecutionError::DivideByZero)
+        );
+        assert_eq!(trap.frames.len(), 2);
+        assert_eq!(trap.frames[0].module_name, None);
+        assert_eq!(trap.frames[0].func_index, 0);
+        assert_eq!(trap.frames[0].func_name.as_deref(), Some("divide"));
+        assert_eq!(trap.frames[1].func_index, 1);
+        assert_eq!(trap.frames[1].func_name, None);
+        assert!(trap.frames[0].offset.is_some());
+
+        // the same function runs without trapping
+        let result = executor.run_func(&func, [WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // the function names of a named module are resolved by the module name
+        let result = executor.register_named_module(&mut store, &module, "math");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.get_func("entry");
+        assert!(result.is_ok());
+        let func = result.unwrap();
+        let result = executor.run_func(&func, [WasmValue::from_i32(0)]);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => {
+                assert_eq!(trap.frames.len(), 2);
+                assert_eq!(trap.frames[0].module_name.as_deref(), Some("math"));
+                assert_eq!(trap.frames[0].func_name.as_deref(), Some("divide"));
+                assert!(trap.to_string().contains("math!divide @ 0x"));
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+
+        // a trap in the start function of a named module is resolved as well
+        let result = loader.from_wat(
+            r#"
+            (module
+                (func $boom unreachable)
+                (start $boom)
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert!(validator.validate(&module).is_ok());
+        let result = executor.register_named_module(&mut store, &module, "boom");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => {
+                assert_eq!(trap.frames.len(), 1);
+                assert_eq!(trap.frames[0].module_name.as_deref(), Some("boom"));
+                assert_eq!(trap.frames[0].func_name.as_deref(), Some("boom"));
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
     fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
@@ -1,14 +1,66 @@
 //! Defines WasmEdge Function and FuncType structs.
 
 use crate::{
-    error::{FuncError, WasmEdgeError},
+    error::{FuncError, FuncTypePosition, WasmEdgeError},
     ffi, BoxedFn, Engine, WasmEdgeResult, WasmValue, HOST_FUNCS,
 };
 use core::ffi::c_void;
 use rand::Rng;
-use std::convert::TryInto;
+use std::{
+    cell::RefCell,
+    convert::TryInto,
+    panic::{self, AssertUnwindSafe},
+    sync::PoisonError,
+};
 use wasmedge_types::ValType;
 
+thread_local! {
+    /// The failure of the latest host function call on this thread which cannot be expressed by an error code.
+    static HOST_FAILURE: RefCell<Option<HostFailure>> = const { RefCell::new(None) };
+}
+
+/// Defines the failures of host function calls that are reported out of band.
+pub(crate) enum HostFailure {
+    /// The host function panicked with the given payload.
+    Panic(String),
+    /// The host function returned values that do not match its function type.
+    Error(WasmEdgeError),
+}
+
+/// Takes the failure of the latest host function call on the current thread.
+pub(crate) fn take_host_failure() -> Option<HostFailure> {
+    HOST_FAILURE.with(|failure| failure.borrow_mut().take())
+}
+
+fn set_host_failure(failure: HostFailure) {
+    HOST_FAILURE.with(|slot| *slot.borrow_mut() = Some(failure));
+}
+
+/// Checks the types of the given values against the expected types, reporting the first mismatch at the position
+/// built by `position`.
+pub(crate) fn check_types(
+    types: impl IntoIterator<Item = ValType>,
+    values: &[WasmValue],
+    position: fn(usize) -> FuncTypePosition,
+) -> WasmEdgeResult<()> {
+    let mut types = types.into_iter();
+    let mut values = values.iter().map(WasmValue::ty);
+    let mut index = 0;
+    loop {
+        match (types.next(), values.next()) {
+            (None, None) => return Ok(()),
+            (expected, actual) if expected == actual => index += 1,
+            (expected, actual) => {
+                return Err(WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                    position: position(index),
+                    expected,
+                    actual,
+                }))
+            }
+        }
+    }
+}
+
 extern "C" fn wraper_fn(
     key_ptr: *mut c_void,
     _data: *mut c_void,
@@ -18,7 +70,40 @@ extern "C" fn wraper_fn(
     returns: *mut ffi::WasmEdge_Value,
     return_len: u32,
 ) -> ffi::WasmEdge_Result {
+    // a panic must not unwind into the C++ frames of WasmEdge, so it is caught here and reported as a failure
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        call_host_fn(key_ptr, params, param_len, returns, return_len)
+    }));
+
+    match result {
+        Ok(Ok(())) => ffi::WasmEdge_Result { Code: 0 },
+        Ok(Err(c)) => ffi::WasmEdge_Result { Code: c },
+        Err(payload) => {
+            let message = match payload.downcast::<String>() {
+                Ok(message) => *message,
+                Err(payload) => match payload.downcast::<&'static str>() {
+                    Ok(message) => message.to_string(),
+                    Err(_) => String::from("Box<dyn Any>"),
+                },
+            };
+            set_host_failure(HostFailure::Panic(message));
+
+            // 0x02: runtime error
+            ffi::WasmEdge_Result { Code: 0x02 }
+        }
+    }
+}
+
+fn call_host_fn(
+    key_ptr: *mut c_void,
+    params: *const ffi::WasmEdge_Value,
+    param_len: u32,
+    returns: *mut ffi::WasmEdge_Value,
+    return_len: u32,
+) -> Result<(), u8> {
     let key = key_ptr as *const usize as usize;
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_host_call();
 
     let input = {
         let raw_input = unsafe {
@@ -37,24 +122,27 @@ extern "C" fn wraper_fn(
         .expect("len of returns should not greater than usize");
     let raw_returns = unsafe { std::slice::from_raw_parts_mut(returns, return_len) };
 
-    let result = {
-        let host_functions = HOST_FUNCS.lock().expect("[wasmedge-sys] try lock failed.");
-        let real_fn = host_functions
+    let (v, checked) = {
+        // the lock is poisoned if a host function panicked while holding it, which leaves the map intact
+        let host_functions = HOST_FUNCS.lock().unwrap_or_else(PoisonError::into_inner);
+        let (real_fn, return_types) = host_functions
             .get(&key)
             .expect("host function should be there");
-        real_fn(input)
+        let v = real_fn(input)?;
+        let checked = check_types(return_types.iter().copied(), &v, FuncTypePosition::Return);
+        (v, checked)
     };
 
-    match result {
-        Ok(v) => {
-            assert!(v.len() == return_len);
-            for (idx, item) in v.into_iter().enumerate() {
-                raw_returns[idx] = item.as_raw();
-            }
-            ffi::WasmEdge_Result { Code: 0 }
-        }
-        Err(c) => ffi::WasmEdge_Result { Code: c as u8 },
+    if let Err(err) = checked {
+        set_host_failure(HostFailure::Error(err));
+
+        // 0x02: runtime error
+        return Err(0x02);
     }
+    for (idx, item) in v.into_iter().enumerate() {
+        raw_returns[idx] = item.as_raw();
+    }
+    Ok(())
 }
 
 /// Defines a host function.
@@ -131,7 +219,7 @@ impl Function {
     /// let func = Function::create(&func_ty, Box::new(real_add), 0).expect("fail to create a Function instance");
     /// ```
     pub fn create(ty: &FuncType, real_fn: BoxedFn, cost: u64) -> WasmEdgeResult<Self> {
-        let mut host_functions = HOST_FUNCS.lock().expect("[wasmedge-sys] try lock failed.");
+        let mut host_functions = HOST_FUNCS.lock().unwrap_or_else(PoisonError::into_inner);
         if host_functions.len() >= host_functions.capacity() {
             return Err(WasmEdgeError::Func(FuncError::CreateBinding(format!(
                 "The number of the host functions reaches the upper bound: {}",
@@ -145,7 +233,7 @@ impl Function {
         while host_functions.contains_key(&key) {
             key = rng.gen();
         }
-        host_functions.insert(key, real_fn);
+        host_functions.insert(key, (real_fn, ty.returns_type_iter().collect()));
 
         let ctx = unsafe {
             ffi::WasmEdge_FunctionInstanceCreateBinding(
@@ -354,6 +442,15 @@ impl FuncType {
         types.into_iter().map(Into::into)
     }
 
+    /// Checks the given arguments against the parameter types of a [Function].
+    ///
+    /// # Error
+    ///
+    /// If the number or the types of the arguments mismatch, then a [FuncTypeMismatch](crate::error::FuncError::FuncTypeMismatch) error naming the first offending parameter is returned.
+    pub(crate) fn check_params(&self, params: &[WasmValue]) -> WasmEdgeResult<()> {
+        check_types(self.params_type_iter(), params, FuncTypePosition::Param)
+    }
+
     ///Returns the number of the returns of a [Function].
     pub fn returns_len(&self) -> u32 {
         unsafe { ffi::WasmEdge_FunctionTypeGetReturnsLength(self.inner.0) }
@@ -5,7 +5,9 @@
 
 use crate::{
     error::{GlobalError, WasmEdgeError},
-    ffi, WasmEdgeResult, WasmValue,
+    ffi,
+    types::{root_extern_ref, unroot_extern_refs},
+    WasmEdgeResult, WasmValue, WasmValueType,
 };
 use wasmedge_types::{Mutability, ValType};
 
@@ -14,6 +16,8 @@ use wasmedge_types::{Mutability, ValType};
 pub struct Global {
     pub(crate) inner: InnerGlobal,
     pub(crate) registered: bool,
+    // The address of the module instance owning the global, or of the global itself if it is not owned by any module instance.
+    pub(crate) owner: usize,
 }
 impl Global {
     /// Creates a new [Global] instance to be associated with the given [GlobalType] and [WasmValue](crate::WasmValue).
@@ -22,17 +26,27 @@ impl Global {
     ///
     /// # Errors
     ///
-    /// If fail to create a [Global] instance, then an error is returned.
+    /// If the type of `val` does not match [GlobalType], or fail to create a [Global] instance, then an error is returned.
     ///
     pub fn create(ty: &GlobalType, val: WasmValue) -> WasmEdgeResult<Self> {
+        if ty.value_type() != val.ty() {
+            return Err(WasmEdgeError::Global(GlobalError::UnmatchedValType {
+                expected: ty.value_type(),
+                actual: val.ty(),
+            }));
+        }
         let ctx = unsafe { ffi::WasmEdge_GlobalInstanceCreate(ty.inner.0, val.as_raw()) };
 
         match ctx.is_null() {
             true => Err(WasmEdgeError::Global(GlobalError::Create)),
-            false => Ok(Self {
-                inner: InnerGlobal(ctx),
-                registered: false,
-            }),
+            false => {
+                root_extern_ref(ctx as usize, ctx as usize, 0, &val);
+                Ok(Self {
+                    inner: InnerGlobal(ctx),
+                    registered: false,
+                    owner: ctx as usize,
+                })
+            }
         }
     }
 
@@ -61,6 +75,8 @@ impl Global {
 
     /// Sets the value of the [Global] instance.
     ///
+    /// If `val` holds an [ExternRef](crate::ExternRef), then the host object is kept alive until it is overwritten, or the [Global] is destroyed. A [Global] obtained from a [module instance](crate::Instance) is destroyed along with the module instance, and one added to an [import module](crate::ImportModule) along with the import module.
+    ///
     /// Notice that only the [Global] instance of [Mutability::Var](wasmedge_types::Mutability::Var) type can be set a new value. Setting a new value for a [Global] of [Mutability::Const](wasmedge_types::Mutability::Const) causes a failure.
     ///
     /// # Argument
@@ -93,16 +109,77 @@ impl Global {
             return Err(WasmEdgeError::Global(GlobalError::ModifyConst));
         }
         if ty.value_type() != val.ty() {
-            return Err(WasmEdgeError::Global(GlobalError::UnmatchedValType));
+            return Err(WasmEdgeError::Global(GlobalError::UnmatchedValType {
+                expected: ty.value_type(),
+                actual: val.ty(),
+            }));
         }
         unsafe { ffi::WasmEdge_GlobalInstanceSetValue(self.inner.0, val.as_raw()) }
+        root_extern_ref(self.owner, self.inner.0 as usize, 0, &val);
         Ok(())
     }
+
+    /// Returns the value of the [Global] instance as the Rust type `T`.
+    ///
+    /// # Errors
+    ///
+    /// If the value type of the [Global] does not match `T`, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{Global, GlobalType, WasmValue};
+    /// use wasmedge_types::{ValType, Mutability};
+    ///
+    /// let ty = GlobalType::create(ValType::I32, Mutability::Const).expect("fail to create a GlobalType");
+    /// let global = Global::create(&ty, WasmValue::from_i32(1314)).expect("fail to create a Global");
+    ///
+    /// assert_eq!(global.get::<i32>().expect("fail to get the value"), 1314);
+    /// assert!(global.get::<i64>().is_err());
+    /// ```
+    pub fn get<T: WasmValueType>(&self) -> WasmEdgeResult<T> {
+        let val_ty = self.ty()?.value_type();
+        if val_ty != T::ty() {
+            return Err(WasmEdgeError::Global(GlobalError::UnmatchedValType {
+                expected: val_ty,
+                actual: T::ty(),
+            }));
+        }
+        Ok(T::from_wasm_value(&self.get_value()))
+    }
+
+    /// Sets the value of the [Global] instance from the Rust value of type `T`.
+    ///
+    /// # Argument
+    ///
+    /// * `val` - The new value to be set.
+    ///
+    /// # Errors
+    ///
+    /// If the [Global] is immutable, or its value type does not match `T`, then an error is returned.
+    pub fn set<T: WasmValueType>(&mut self, val: T) -> WasmEdgeResult<()> {
+        self.set_value(val.into_wasm_value())
+    }
+
+    /// Takes a snapshot of the type and the current value of the [Global] instance.
+    ///
+    /// The snapshots can be compared with each other, for example, to check which exported globals are changed by a function call.
+    ///
+    /// # Errors
+    ///
+    /// If fail to get the type, then an error is returned.
+    pub fn snapshot(&self) -> WasmEdgeResult<GlobalSnapshot> {
+        Ok(GlobalSnapshot {
+            ty: self.ty()?.into(),
+            value: self.get_value(),
+        })
+    }
 }
 impl Drop for Global {
     fn drop(&mut self) {
         if !self.registered && !self.inner.0.is_null() {
             unsafe { ffi::WasmEdge_GlobalInstanceDelete(self.inner.0) };
+            unroot_extern_refs(self.owner);
         }
     }
 }
@@ -112,6 +189,32 @@ pub(crate) struct InnerGlobal(pub(crate) *mut ffi::WasmEdge_GlobalInstanceContex
 unsafe impl Send for InnerGlobal {}
 unsafe impl Sync for InnerGlobal {}
 
+/// Defines a snapshot of a [Global] instance, which is created by [Global::snapshot].
+///
+/// Two snapshots are equal if they have the same [GlobalType](wasmedge_types::GlobalType) and their values have the same bit pattern; in particular, a `NaN` value is equal to itself, and references are compared by identity.
+#[derive(Debug, Clone)]
+pub struct GlobalSnapshot {
+    ty: wasmedge_types::GlobalType,
+    value: WasmValue,
+}
+impl GlobalSnapshot {
+    /// Returns the type of the [Global] when the snapshot was taken.
+    pub fn ty(&self) -> &wasmedge_types::GlobalType {
+        &self.ty
+    }
+
+    /// Returns the value of the [Global] when the snapshot was taken.
+    pub fn value(&self) -> &WasmValue {
+        &self.value
+    }
+}
+impl PartialEq for GlobalSnapshot {
+    fn eq(&self, other: &Self) -> bool {
+        self.ty == other.ty && self.value.bits() == other.value.bits()
+    }
+}
+impl Eq for GlobalSnapshot {}
+
 /// Defines the type of a wasm global variable.
 ///
 /// A [GlobalType] classifies a global variable that hold a value and can either be mutable or immutable.
@@ -183,11 +286,12 @@ unsafe impl Sync for InnerGlobalType {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ExternRef;
     use std::{
         sync::{Arc, Mutex},
         thread,
     };
-    use wasmedge_types::{Mutability, ValType};
+    use wasmedge_types::{Mutability, RefType, ValType};
 
     #[test]
     fn test_global_type() {
@@ -299,6 +403,135 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_global_typed() {
+        // create a Var Global instance of F64 type
+        let result = GlobalType::create(ValType::F64, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_f64(13.14));
+        assert!(result.is_ok());
+        let mut global_var = result.unwrap();
+
+        // get and set the value in the matched type
+        let result = global_var.get::<f64>();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 13.14);
+        let result = global_var.set(1.314f64);
+        assert!(result.is_ok());
+        let result = global_var.get::<f64>();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 1.314);
+
+        // get and set the value in the mis-matched type
+        let result = global_var.get::<i32>();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Global(GlobalError::UnmatchedValType {
+                expected: ValType::F64,
+                actual: ValType::I32,
+            })
+        );
+        let result = global_var.set(1314i64);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Global(GlobalError::UnmatchedValType {
+                expected: ValType::F64,
+                actual: ValType::I64,
+            })
+        );
+        assert_eq!(global_var.get::<f64>().unwrap(), 1.314);
+
+        // create a Const Global instance of I32 type
+        let result = GlobalType::create(ValType::I32, Mutability::Const);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_i32(1314));
+        assert!(result.is_ok());
+        let mut global_const = result.unwrap();
+        let result = global_const.set(0i32);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Global(GlobalError::ModifyConst)
+        );
+        assert_eq!(global_const.get::<i32>().unwrap(), 1314);
+
+        // create a Global instance with a mis-matched value
+        let result = Global::create(&ty, WasmValue::from_f32(13.14));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Global(GlobalError::UnmatchedValType {
+                expected: ValType::I32,
+                actual: ValType::F32,
+            })
+        );
+
+        // create a Var Global instance of ExternRef type
+        let result = GlobalType::create(ValType::ExternRef, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_null_ref(RefType::ExternRef));
+        assert!(result.is_ok());
+        let mut global_ref = result.unwrap();
+        let result = global_ref.get::<Option<ExternRef>>();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+        let result = global_ref.set(Some(ExternRef::new("hello")));
+        assert!(result.is_ok());
+        let result = global_ref.get::<Option<ExternRef>>();
+        assert!(result.is_ok());
+        let extern_ref = result.unwrap().unwrap();
+        assert_eq!(extern_ref.downcast_ref::<&str>(), Some(&"hello"));
+    }
+
+    #[test]
+    fn test_global_snapshot() {
+        // create a Var Global instance
+        let result = GlobalType::create(ValType::F32, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_f32(f32::NAN));
+        assert!(result.is_ok());
+        let mut global = result.unwrap();
+
+        // take snapshots
+        let result = global.snapshot();
+        assert!(result.is_ok());
+        let before = result.unwrap();
+        assert_eq!(
+            before.ty(),
+            &wasmedge_types::GlobalType::new(ValType::F32, Mutability::Var)
+        );
+        assert!(before.value().to_f32().is_nan());
+        let result = global.snapshot();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), before);
+
+        // modify the value
+        let result = global.set(1.0f32);
+        assert!(result.is_ok());
+        let result = global.snapshot();
+        assert!(result.is_ok());
+        let after = result.unwrap();
+        assert_ne!(after, before);
+        assert_eq!(after.value().to_f32(), 1.0);
+
+        // a Global of another type holding the same bits
+        let result = GlobalType::create(ValType::I32, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_i32(1.0f32.to_bits() as i32));
+        assert!(result.is_ok());
+        let other = result.unwrap();
+        let result = other.snapshot();
+        assert!(result.is_ok());
+        assert_ne!(result.unwrap(), after);
+    }
+
     #[test]
     fn test_global_send() {
         {
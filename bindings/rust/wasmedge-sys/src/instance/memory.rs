@@ -4,14 +4,28 @@
 //! `MemType` specifies the limits on the size of a memory by a range. The start of
 //! the limit range specifies min size (initial size) of that memory, while the end
 //! restricts the size to which the memory can grow later.
+//!
+//! The memories are indexed by 32-bit values, so the offsets, lengths, and page counts accepted by `Memory`
+//! are `u32`. The WasmEdge runtime does not implement the Memory64 proposal yet, regardless of
+//! `Config::memory64`.
+//!
+//! A shared `Memory` (see the Threads proposal) can be accessed by the host and the
+//! guests from multiple threads at the same time. The `atomic_*` methods of `Memory`
+//! load and store the values of such a memory atomically from the host, and wait on or
+//! notify its addresses through the waiter table of the WasmEdge runtime.
 
 use crate::{
-    error::{MemError, WasmEdgeError},
+    error::{CoreError, CoreExecutionError, MemError, WasmEdgeError},
     ffi,
     utils::check,
     WasmEdgeResult,
 };
-use std::ops::RangeInclusive;
+use std::{
+    ops::RangeInclusive,
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+    time::Duration,
+};
+use wasmedge_types::AtomicWaitResult;
 
 /// Defines a WebAssembly memory instance, which is a linear memory described by its [type](crate::MemType). Each memory instance consists of a vector of bytes and an optional maximum size, and its size is a multiple of the WebAssembly page size (*64KiB* of each page).
 #[derive(Debug)]
@@ -250,6 +264,183 @@ impl Memory {
     pub fn grow(&mut self, count: u32) -> WasmEdgeResult<()> {
         unsafe { check(ffi::WasmEdge_MemoryInstanceGrowPage(self.inner.0, count)) }
     }
+
+    /// Atomically loads a `u32` value from the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 4 bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_load_u32(&self, offset: u32) -> WasmEdgeResult<u32> {
+        let atomic = unsafe { &*self.atomic_ptr::<AtomicU32>(offset)? };
+        Ok(atomic.load(Ordering::SeqCst))
+    }
+
+    /// Atomically loads a `u64` value from the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 8 bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_load_u64(&self, offset: u32) -> WasmEdgeResult<u64> {
+        let atomic = unsafe { &*self.atomic_ptr::<AtomicU64>(offset)? };
+        Ok(atomic.load(Ordering::SeqCst))
+    }
+
+    /// Atomically stores a `u32` value into the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 4 bytes.
+    ///
+    /// * `value` - The value to store.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_store_u32(&self, offset: u32, value: u32) -> WasmEdgeResult<()> {
+        let atomic = unsafe { &*self.atomic_ptr::<AtomicU32>(offset)? };
+        atomic.store(value, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Atomically stores a `u64` value into the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 8 bytes.
+    ///
+    /// * `value` - The value to store.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_store_u64(&self, offset: u32, value: u64) -> WasmEdgeResult<()> {
+        let atomic = unsafe { &*self.atomic_ptr::<AtomicU64>(offset)? };
+        atomic.store(value, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Wakes up the threads waiting on the given address of the [Memory], and returns the number of the woken threads.
+    ///
+    /// The waiters are kept by the WasmEdge runtime, and are woken up in the waiting order. If the [Memory] is not shared, then no thread can wait on it, and `0` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 4 bytes.
+    ///
+    /// * `count` - The maximum number of the waiters to wake up.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_notify(&self, offset: u32, count: u32) -> WasmEdgeResult<u32> {
+        self.atomic_ptr::<AtomicU32>(offset)?;
+        let mut woken = 0;
+        unsafe {
+            check(ffi::WasmEdge_MemoryInstanceAtomicNotify(
+                self.inner.0,
+                offset,
+                count,
+                &mut woken,
+            ))?;
+        }
+        Ok(woken)
+    }
+
+    /// Blocks the current thread until it is woken up by [atomic_notify](crate::Memory::atomic_notify), or the timeout expires.
+    ///
+    /// If the `u32` value at the given address is not equal to `expected`, then the function returns [AtomicWaitResult::NotEqual] immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 4 bytes.
+    ///
+    /// * `expected` - The expected value at the given address.
+    ///
+    /// * `timeout` - The maximum time to wait. `None` means waiting forever.
+    ///
+    /// # Errors
+    ///
+    /// If the [Memory] is not shared, or the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_wait32(
+        &self,
+        offset: u32,
+        expected: u32,
+        timeout: Option<Duration>,
+    ) -> WasmEdgeResult<AtomicWaitResult> {
+        self.atomic_ptr::<AtomicU32>(offset)?;
+        let mut result = 0;
+        unsafe {
+            check(ffi::WasmEdge_MemoryInstanceAtomicWait32(
+                self.inner.0,
+                offset,
+                expected,
+                to_nanos(timeout),
+                &mut result,
+            ))?;
+        }
+        Ok(result.into())
+    }
+
+    /// Blocks the current thread until it is woken up by [atomic_notify](crate::Memory::atomic_notify), or the timeout expires.
+    ///
+    /// If the `u64` value at the given address is not equal to `expected`, then the function returns [AtomicWaitResult::NotEqual] immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data offset in the [Memory]. It must be aligned to 8 bytes.
+    ///
+    /// * `expected` - The expected value at the given address.
+    ///
+    /// * `timeout` - The maximum time to wait. `None` means waiting forever.
+    ///
+    /// # Errors
+    ///
+    /// If the [Memory] is not shared, or the `offset` is unaligned or out of bounds, then an error is returned.
+    pub fn atomic_wait64(
+        &self,
+        offset: u32,
+        expected: u64,
+        timeout: Option<Duration>,
+    ) -> WasmEdgeResult<AtomicWaitResult> {
+        self.atomic_ptr::<AtomicU64>(offset)?;
+        let mut result = 0;
+        unsafe {
+            check(ffi::WasmEdge_MemoryInstanceAtomicWait64(
+                self.inner.0,
+                offset,
+                expected,
+                to_nanos(timeout),
+                &mut result,
+            ))?;
+        }
+        Ok(result.into())
+    }
+
+    // Returns the pointer to the atomic value of type `T` at the given offset.
+    fn atomic_ptr<T>(&self, offset: u32) -> WasmEdgeResult<*const T> {
+        let size = std::mem::size_of::<T>() as u32;
+        if offset & (size - 1) != 0 {
+            return Err(WasmEdgeError::Mem(MemError::UnalignedAtomic(offset)));
+        }
+        let ptr = unsafe { ffi::WasmEdge_MemoryInstanceGetPointer(self.inner.0, offset, size) };
+        match ptr.is_null() {
+            true => Err(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::MemoryOutOfBounds,
+            ))),
+            false if ptr as usize & (std::mem::align_of::<T>() - 1) != 0 => {
+                Err(WasmEdgeError::Mem(MemError::UnalignedAtomic(offset)))
+            }
+            false => Ok(ptr as *const T),
+        }
+    }
 }
 impl Drop for Memory {
     fn drop(&mut self) {
@@ -259,6 +450,14 @@ impl Drop for Memory {
     }
 }
 
+// Converts the timeout of waiting to nanoseconds. A negative value means waiting forever.
+fn to_nanos(timeout: Option<Duration>) -> i64 {
+    match timeout {
+        Some(timeout) => i64::try_from(timeout.as_nanos()).unwrap_or(i64::MAX),
+        None => -1,
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct InnerMemory(pub(crate) *mut ffi::WasmEdge_MemoryInstanceContext);
 unsafe impl Send for InnerMemory {}
@@ -288,7 +487,11 @@ impl MemType {
     /// ```
     ///
     pub fn create(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
-        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(ffi::WasmEdge_Limit::from(limit)) };
+        Self::create_with_limit(ffi::WasmEdge_Limit::from(limit))
+    }
+
+    fn create_with_limit(limit: ffi::WasmEdge_Limit) -> WasmEdgeResult<Self> {
+        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(limit) };
         match ctx.is_null() {
             true => Err(WasmEdgeError::MemTypeCreate),
             false => Ok(Self {
@@ -313,6 +516,44 @@ impl MemType {
         let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
         RangeInclusive::from(limit)
     }
+
+    /// Create a new shared [MemType] to be associated with the given limit range for the capacity.
+    ///
+    /// A [Memory] of a shared [MemType] can be imported by the wasm modules compiled with the threads support, for example, with `-pthread`. Notice that the `threads` option should be enabled in the [Config](crate::Config) to load such modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The linear memory size. The start value of the limit range specifies the min size (also, initial size) of the memory, while the end value specifies the max size allowed to grow. A shared memory always has a max size.
+    ///
+    /// # Errors
+    ///
+    /// If fail to create a [MemType], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::MemType;
+    ///
+    /// let ty = MemType::create_shared(1..=10).expect("fail to create a shared MemType");
+    /// assert!(ty.is_shared());
+    /// assert_eq!(ty.limit(), 1..=10);
+    /// ```
+    ///
+    pub fn create_shared(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
+        let (min, max) = limit.into_inner();
+        Self::create_with_limit(ffi::WasmEdge_Limit {
+            HasMax: true,
+            Min: min,
+            Max: max,
+            Shared: true,
+        })
+    }
+
+    /// Returns `true` if the [MemType] declares a shared memory.
+    pub fn is_shared(&self) -> bool {
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
+        limit.Shared
+    }
 }
 impl Drop for MemType {
     fn drop(&mut self) {
@@ -321,19 +562,17 @@ impl Drop for MemType {
         }
     }
 }
-impl From<wasmedge_types::MemoryType> for MemType {
-    fn from(ty: wasmedge_types::MemoryType) -> Self {
-        MemType::create(ty.minimum()..=ty.maximum()).expect(
-            "[wasmedge] Failed to convert wasmedge_types::MemoryType into wasmedge_sys::MemType.",
-        )
+impl TryFrom<wasmedge_types::MemoryType> for MemType {
+    type Error = WasmEdgeError;
+
+    fn try_from(ty: wasmedge_types::MemoryType) -> Result<Self, Self::Error> {
+        MemType::create_with_limit((&ty).into())
     }
 }
 impl From<MemType> for wasmedge_types::MemoryType {
     fn from(ty: MemType) -> Self {
-        wasmedge_types::MemoryType::new(
-            ty.limit().start().to_owned(),
-            Some(ty.limit().end().to_owned()),
-        )
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(ty.inner.0) };
+        wasmedge_types::MemoryType::from(limit)
     }
 }
 
@@ -345,7 +584,6 @@ unsafe impl Sync for InnerMemType {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error::{CoreError, CoreExecutionError, WasmEdgeError};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -456,6 +694,169 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_memory_shared() {
+        // create a shared MemType
+        let result = MemType::create_shared(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(ty.is_shared());
+        assert_eq!(ty.limit(), 1..=2);
+
+        // the min and max sizes are allowed to be equal
+        let result = MemType::create_shared(1..=1);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(ty.is_shared());
+        assert_eq!(ty.limit(), 1..=1);
+
+        // non-shared MemType
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        assert!(!result.unwrap().is_shared());
+
+        // convert between wasmedge_types::MemoryType and MemType
+        let result = MemType::try_from(wasmedge_types::MemoryType::new_shared(1, 2));
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(ty.is_shared());
+        let ty: wasmedge_types::MemoryType = ty.into();
+        assert_eq!(ty, wasmedge_types::MemoryType::new_shared(1, 2));
+
+        // create a shared Memory
+        let result = MemType::create_shared(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mem = result.unwrap();
+        let result = mem.ty();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_shared());
+    }
+
+    #[test]
+    fn test_memory_atomics() {
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mem = result.unwrap();
+
+        // load and store
+        let result = mem.atomic_store_u32(8, 1314);
+        assert!(result.is_ok());
+        let result = mem.atomic_load_u32(8);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 1314);
+        let result = mem.atomic_store_u64(16, u64::MAX);
+        assert!(result.is_ok());
+        let result = mem.atomic_load_u64(16);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), u64::MAX);
+
+        // unaligned access
+        let result = mem.atomic_load_u32(2);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::UnalignedAtomic(2))
+        );
+        let result = mem.atomic_store_u64(4, 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::UnalignedAtomic(4))
+        );
+
+        // out of bounds access
+        let result = mem.atomic_load_u32(u32::pow(2, 16));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+        );
+
+        // no waiters on a non-shared memory
+        let result = mem.atomic_notify(8, 1);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0);
+        let result = mem.atomic_notify(2, 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::UnalignedAtomic(2))
+        );
+
+        // wait on a non-shared memory
+        let result = mem.atomic_wait32(8, 1314, None);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::ExpectSharedMemory))
+        );
+    }
+
+    #[test]
+    fn test_memory_wait_notify() {
+        let result = MemType::create_shared(1..=1);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mem = Arc::new(result.unwrap());
+
+        // the value does not match
+        let result = mem.atomic_wait32(0, 1, None);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), AtomicWaitResult::NotEqual);
+
+        // no one notifies the waiter
+        let result = mem.atomic_wait64(8, 0, Some(Duration::from_millis(10)));
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), AtomicWaitResult::TimedOut);
+
+        // unaligned and out of bounds waits
+        let result = mem.atomic_wait64(4, 0, None);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::UnalignedAtomic(4))
+        );
+        let result = mem.atomic_wait32(u32::pow(2, 16), 0, None);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+        );
+
+        // wake up the waiters from another host thread
+        let handles = (0..2)
+            .map(|_| {
+                let mem_cloned = Arc::clone(&mem);
+                thread::spawn(move || mem_cloned.atomic_wait32(0, 0, None))
+            })
+            .collect::<Vec<_>>();
+        let mut woken = 0;
+        while woken < 2 {
+            let result = mem.atomic_notify(0, 2 - woken);
+            assert!(result.is_ok());
+            woken += result.unwrap();
+            thread::yield_now();
+        }
+        for handle in handles {
+            let result = handle.join().unwrap();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), AtomicWaitResult::Ok);
+        }
+
+        // the notified waiters are removed
+        let result = mem.atomic_notify(0, 1);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0);
+    }
+
     #[test]
     fn test_memory_send() {
         {
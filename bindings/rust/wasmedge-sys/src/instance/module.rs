@@ -4,10 +4,11 @@ use crate::{
     error::{InstanceError, WasmEdgeError},
     ffi,
     instance::{function::InnerFunc, global::InnerGlobal, memory::InnerMemory, table::InnerTable},
-    types::WasmEdgeString,
+    types::{transfer_extern_refs, unroot_extern_refs, WasmEdgeString},
     utils::string_to_c_char,
     Function, Global, Memory, Table, WasmEdgeResult,
 };
+use wasmedge_types::ExternalInstanceType;
 
 /// An [Instance] represents an instantiated module. In the instantiation process, An [Instance] is created from al[Module](crate::Module). From an [Instance] the exported [functions](crate::Function), [tables](crate::Table), [memories](crate::Memory), and [globals](crate::Global) can be fetched.
 ///
@@ -25,6 +26,9 @@ use crate::{
 pub struct Instance {
     pub(crate) inner: InnerInstance,
     pub(crate) registered: bool,
+    /// The pages of the exported memories accounted in the metrics, which are released when the instance is dropped.
+    #[cfg(feature = "metrics")]
+    pub(crate) memory_pages: crate::metrics::MemoryPages,
 }
 impl Drop for Instance {
     fn drop(&mut self) {
@@ -32,6 +36,7 @@ impl Drop for Instance {
             unsafe {
                 ffi::WasmEdge_ModuleInstanceDelete(self.inner.0);
             }
+            unroot_extern_refs(self.inner.0 as usize);
         }
     }
 }
@@ -58,16 +63,19 @@ impl Instance {
     ///
     /// # Error
     ///
-    /// If fail to find the target [function](crate::Function), then an error is returned.
+    /// If fail to find the target [function](crate::Function), then an error is returned. If `name` is exported as
+    /// another kind of instance, the error reports both the expected and the found kinds.
     pub fn get_func(&self, name: impl AsRef<str>) -> WasmEdgeResult<Function> {
         let func_name: WasmEdgeString = name.as_ref().into();
         let func_ctx = unsafe {
             ffi::WasmEdge_ModuleInstanceFindFunction(self.inner.0 as *const _, func_name.as_raw())
         };
         match func_ctx.is_null() {
-            true => Err(WasmEdgeError::Instance(InstanceError::NotFoundFunc(
-                name.as_ref().to_string(),
-            ))),
+            true => Err(self.export_not_found(
+                name.as_ref(),
+                ffi::WasmEdge_ExternalType_Function,
+                InstanceError::NotFoundFunc(name.as_ref().to_string()),
+            )),
             false => Ok(Function {
                 inner: InnerFunc(func_ctx),
                 registered: true,
@@ -83,19 +91,23 @@ impl Instance {
     ///
     /// # Error
     ///
-    /// If fail to find the target [table instance](crate::Table), then an error is returned.
+    /// If fail to find the target [table instance](crate::Table), then an error is returned. If `name` is exported as
+    /// another kind of instance, the error reports both the expected and the found kinds.
     pub fn get_table(&self, name: impl AsRef<str>) -> WasmEdgeResult<Table> {
         let table_name: WasmEdgeString = name.as_ref().into();
         let ctx = unsafe {
             ffi::WasmEdge_ModuleInstanceFindTable(self.inner.0 as *const _, table_name.as_raw())
         };
         match ctx.is_null() {
-            true => Err(WasmEdgeError::Instance(InstanceError::NotFoundTable(
-                name.as_ref().to_string(),
-            ))),
+            true => Err(self.export_not_found(
+                name.as_ref(),
+                ffi::WasmEdge_ExternalType_Table,
+                InstanceError::NotFoundTable(name.as_ref().to_string()),
+            )),
             false => Ok(Table {
                 inner: InnerTable(ctx),
                 registered: true,
+                owner: self.inner.0 as usize,
             }),
         }
     }
@@ -108,16 +120,19 @@ impl Instance {
     ///
     /// # Error
     ///
-    /// If fail to find the target [memory instance](crate::Memory), then an error is returned.
+    /// If fail to find the target [memory instance](crate::Memory), then an error is returned. If `name` is exported as
+    /// another kind of instance, the error reports both the expected and the found kinds.
     pub fn get_memory(&self, name: impl AsRef<str>) -> WasmEdgeResult<Memory> {
         let mem_name: WasmEdgeString = name.as_ref().into();
         let ctx = unsafe {
             ffi::WasmEdge_ModuleInstanceFindMemory(self.inner.0 as *const _, mem_name.as_raw())
         };
         match ctx.is_null() {
-            true => Err(WasmEdgeError::Instance(InstanceError::NotFoundMem(
-                name.as_ref().to_string(),
-            ))),
+            true => Err(self.export_not_found(
+                name.as_ref(),
+                ffi::WasmEdge_ExternalType_Memory,
+                InstanceError::NotFoundMem(name.as_ref().to_string()),
+            )),
             false => Ok(Memory {
                 inner: InnerMemory(ctx),
                 registered: true,
@@ -133,23 +148,142 @@ impl Instance {
     ///
     /// # Error
     ///
-    /// If fail to find the target [global instance](crate::Global), then an error is returned.
+    /// If fail to find the target [global instance](crate::Global), then an error is returned. If `name` is exported as
+    /// another kind of instance, the error reports both the expected and the found kinds.
     pub fn get_global(&self, name: impl AsRef<str>) -> WasmEdgeResult<Global> {
         let global_name: WasmEdgeString = name.as_ref().into();
         let ctx = unsafe {
             ffi::WasmEdge_ModuleInstanceFindGlobal(self.inner.0 as *const _, global_name.as_raw())
         };
         match ctx.is_null() {
-            true => Err(WasmEdgeError::Instance(InstanceError::NotFoundGlobal(
-                name.as_ref().to_string(),
-            ))),
+            true => Err(self.export_not_found(
+                name.as_ref(),
+                ffi::WasmEdge_ExternalType_Global,
+                InstanceError::NotFoundGlobal(name.as_ref().to_string()),
+            )),
             false => Ok(Global {
                 inner: InnerGlobal(ctx),
                 registered: true,
+                owner: self.inner.0 as usize,
             }),
         }
     }
 
+    /// Returns the exported instance by name, whatever its kind is.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the target exported instance.
+    ///
+    /// # Error
+    ///
+    /// If no instance is exported with the given name, then an error is returned.
+    pub fn get_export(&self, name: impl AsRef<str>) -> WasmEdgeResult<Extern> {
+        let name = name.as_ref();
+        self.find_export(name)
+            .ok_or_else(|| WasmEdgeError::Instance(InstanceError::NotFoundExport(name.to_string())))
+    }
+
+    /// Returns an iterator over all the exported instances of this module instance in the export order, yielding the
+    /// name and the [Extern] of each export.
+    ///
+    /// # Error
+    ///
+    /// If fail to get an exported instance, then an error is yielded in its place.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for export in instance.exports() {
+    ///     let (name, export) = export?;
+    ///     println!("{}: {}", name, export.ty()?);
+    /// }
+    /// ```
+    pub fn exports(&self) -> impl Iterator<Item = WasmEdgeResult<(String, Extern)>> + '_ {
+        let len = unsafe { ffi::WasmEdge_ModuleInstanceListExportLength(self.inner.0) };
+        let mut names = Vec::with_capacity(len as usize);
+        let mut types = Vec::with_capacity(len as usize);
+        unsafe {
+            ffi::WasmEdge_ModuleInstanceListExport(
+                self.inner.0,
+                names.as_mut_ptr(),
+                types.as_mut_ptr(),
+                len,
+            );
+            names.set_len(len as usize);
+            types.set_len(len as usize);
+        }
+
+        names
+            .into_iter()
+            .zip(types)
+            .map(move |(name, ty): (ffi::WasmEdge_String, _)| {
+                let name: String = name.into();
+                let export = match ty {
+                    ffi::WasmEdge_ExternalType_Function => self.get_func(&name).map(Extern::Func),
+                    ffi::WasmEdge_ExternalType_Table => self.get_table(&name).map(Extern::Table),
+                    ffi::WasmEdge_ExternalType_Memory => self.get_memory(&name).map(Extern::Memory),
+                    _ => self.get_global(&name).map(Extern::Global),
+                };
+                export.map(|export| (name, export))
+            })
+    }
+
+    fn find_export(&self, name: &str) -> Option<Extern> {
+        let raw_name: WasmEdgeString = name.into();
+        let ctx = self.inner.0 as *const _;
+        unsafe {
+            let func_ctx = ffi::WasmEdge_ModuleInstanceFindFunction(ctx, raw_name.as_raw());
+            if !func_ctx.is_null() {
+                return Some(Extern::Func(Function {
+                    inner: InnerFunc(func_ctx),
+                    registered: true,
+                }));
+            }
+            let table_ctx = ffi::WasmEdge_ModuleInstanceFindTable(ctx, raw_name.as_raw());
+            if !table_ctx.is_null() {
+                return Some(Extern::Table(Table {
+                    inner: InnerTable(table_ctx),
+                    registered: true,
+                    owner: self.inner.0 as usize,
+                }));
+            }
+            let mem_ctx = ffi::WasmEdge_ModuleInstanceFindMemory(ctx, raw_name.as_raw());
+            if !mem_ctx.is_null() {
+                return Some(Extern::Memory(Memory {
+                    inner: InnerMemory(mem_ctx),
+                    registered: true,
+                }));
+            }
+            let global_ctx = ffi::WasmEdge_ModuleInstanceFindGlobal(ctx, raw_name.as_raw());
+            if !global_ctx.is_null() {
+                return Some(Extern::Global(Global {
+                    inner: InnerGlobal(global_ctx),
+                    registered: true,
+                    owner: self.inner.0 as usize,
+                }));
+            }
+        }
+        None
+    }
+
+    fn export_not_found(
+        &self,
+        name: &str,
+        expected: ffi::WasmEdge_ExternalType,
+        err: InstanceError,
+    ) -> WasmEdgeError {
+        match self.find_export(name).map(|found| found.ty()) {
+            Some(Ok(found)) => WasmEdgeError::Instance(InstanceError::MismatchedExport {
+                name: name.to_string(),
+                expected: expected.into(),
+                found,
+            }),
+            Some(Err(err)) => err,
+            None => WasmEdgeError::Instance(err),
+        }
+    }
+
     /// Returns the length of the exported [function instances](crate::Function) in this module instance.
     pub fn func_len(&self) -> u32 {
         unsafe { ffi::WasmEdge_ModuleInstanceListFunctionLength(self.inner.0) }
@@ -276,6 +410,68 @@ pub(crate) struct InnerInstance(pub(crate) *mut ffi::WasmEdge_ModuleInstanceCont
 unsafe impl Send for InnerInstance {}
 unsafe impl Sync for InnerInstance {}
 
+/// An [Extern] is an instance exported from a [module instance](crate::Instance), which is one of a
+/// [function](crate::Function), a [table](crate::Table), a [memory](crate::Memory) or a [global](crate::Global).
+#[derive(Debug)]
+pub enum Extern {
+    /// An exported [function instance](crate::Function).
+    Func(Function),
+    /// An exported [table instance](crate::Table).
+    Table(Table),
+    /// An exported [memory instance](crate::Memory).
+    Memory(Memory),
+    /// An exported [global instance](crate::Global).
+    Global(Global),
+}
+impl Extern {
+    /// Returns the type of this exported instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to get the type of the underlying instance, then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<ExternalInstanceType> {
+        let ty = match self {
+            Extern::Func(func) => ExternalInstanceType::Func(func.ty()?.into()),
+            Extern::Table(table) => ExternalInstanceType::Table(table.ty()?.into()),
+            Extern::Memory(memory) => ExternalInstanceType::Memory(memory.ty()?.into()),
+            Extern::Global(global) => ExternalInstanceType::Global(global.ty()?.into()),
+        };
+        Ok(ty)
+    }
+
+    /// Returns the [function instance](crate::Function) if this is an exported function.
+    pub fn into_func(self) -> Option<Function> {
+        match self {
+            Extern::Func(func) => Some(func),
+            _ => None,
+        }
+    }
+
+    /// Returns the [table instance](crate::Table) if this is an exported table.
+    pub fn into_table(self) -> Option<Table> {
+        match self {
+            Extern::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Returns the [memory instance](crate::Memory) if this is an exported memory.
+    pub fn into_memory(self) -> Option<Memory> {
+        match self {
+            Extern::Memory(memory) => Some(memory),
+            _ => None,
+        }
+    }
+
+    /// Returns the [global instance](crate::Global) if this is an exported global.
+    pub fn into_global(self) -> Option<Global> {
+        match self {
+            Extern::Global(global) => Some(global),
+            _ => None,
+        }
+    }
+}
+
 /// An [ImportModule] represents a host module with a name. A host module consists of one or more host [function](crate::Function), [table](crate::Table), [memory](crate::Memory), and [global](crate::Global) instances,  which are defined outside wasm modules and fed into wasm modules as imports.
 ///
 /// # Example
@@ -359,6 +555,7 @@ impl Drop for ImportModule {
             unsafe {
                 ffi::WasmEdge_ModuleInstanceDelete(self.inner.0);
             }
+            unroot_extern_refs(self.inner.0 as usize);
         }
     }
 }
@@ -405,6 +602,7 @@ impl ImportInstance for ImportModule {
         unsafe {
             ffi::WasmEdge_ModuleInstanceAddTable(self.inner.0, table_name.as_raw(), table.inner.0);
         }
+        transfer_extern_refs(table.inner.0 as usize, self.inner.0 as usize);
         table.inner.0 = std::ptr::null_mut();
     }
 
@@ -425,6 +623,7 @@ impl ImportInstance for ImportModule {
                 global.inner.0,
             );
         }
+        transfer_extern_refs(global.inner.0 as usize, self.inner.0 as usize);
         global.inner.0 = std::ptr::null_mut();
     }
 }
@@ -450,6 +649,7 @@ impl Drop for WasiModule {
             unsafe {
                 ffi::WasmEdge_ModuleInstanceDelete(self.inner.0);
             }
+            unroot_extern_refs(self.inner.0 as usize);
         }
     }
 }
@@ -587,6 +787,7 @@ impl ImportInstance for WasiModule {
         unsafe {
             ffi::WasmEdge_ModuleInstanceAddTable(self.inner.0, table_name.as_raw(), table.inner.0);
         }
+        transfer_extern_refs(table.inner.0 as usize, self.inner.0 as usize);
         table.inner.0 = std::ptr::null_mut();
     }
 
@@ -607,6 +808,7 @@ impl ImportInstance for WasiModule {
                 global.inner.0,
             );
         }
+        transfer_extern_refs(global.inner.0 as usize, self.inner.0 as usize);
         global.inner.0 = std::ptr::null_mut();
     }
 }
@@ -646,6 +848,7 @@ impl Drop for WasmEdgeProcessModule {
             unsafe {
                 ffi::WasmEdge_ModuleInstanceDelete(self.inner.0);
             }
+            unroot_extern_refs(self.inner.0 as usize);
         }
     }
 }
@@ -723,6 +926,7 @@ impl ImportInstance for WasmEdgeProcessModule {
         unsafe {
             ffi::WasmEdge_ModuleInstanceAddTable(self.inner.0, table_name.as_raw(), table.inner.0);
         }
+        transfer_extern_refs(table.inner.0 as usize, self.inner.0 as usize);
         table.inner.0 = std::ptr::null_mut();
     }
 
@@ -743,6 +947,7 @@ impl ImportInstance for WasmEdgeProcessModule {
                 global.inner.0,
             );
         }
+        transfer_extern_refs(global.inner.0 as usize, self.inner.0 as usize);
         global.inner.0 = std::ptr::null_mut();
     }
 }
@@ -805,6 +1010,15 @@ impl ImportObject {
             ImportObject::WasmEdgeProcess(wasmedge_process) => wasmedge_process.name(),
         }
     }
+
+    /// Returns the raw pointer to the inner module instance context.
+    pub(crate) fn as_ptr(&self) -> *mut ffi::WasmEdge_ModuleInstanceContext {
+        match self {
+            ImportObject::Import(import) => import.inner.0,
+            ImportObject::Wasi(wasi) => wasi.inner.0,
+            ImportObject::WasmEdgeProcess(wasmedge_process) => wasmedge_process.inner.0,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1203,6 +1417,124 @@ mod tests {
         assert_eq!(result.unwrap(), ["global"]);
     }
 
+    #[test]
+    fn test_instance_exports() {
+        let vm = create_vm();
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        let result = store.module("extern_module");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+
+        // iterate over all the exports
+        let result = instance.exports().collect::<WasmEdgeResult<Vec<_>>>();
+        assert!(result.is_ok());
+        let exports = result.unwrap();
+        assert_eq!(exports.len(), 4);
+        let names = exports
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["add", "table", "mem", "global"]);
+        for (_, export) in exports.iter() {
+            assert!(export.ty().is_ok());
+        }
+
+        // check the types of the exports
+        let result = exports[0].1.ty();
+        assert!(result.is_ok());
+        match result.unwrap() {
+            ExternalInstanceType::Func(ty) => {
+                assert_eq!(ty.args(), Some(&[ValType::I32, ValType::I32][..]));
+                assert_eq!(ty.returns(), Some(&[ValType::I32][..]));
+            }
+            _ => panic!("expected a function type"),
+        }
+        let result = exports[3].1.ty();
+        assert!(result.is_ok());
+        match result.unwrap() {
+            ExternalInstanceType::Global(ty) => {
+                assert_eq!(ty.value_ty(), ValType::F32);
+                assert_eq!(ty.mutability(), Mutability::Const);
+            }
+            _ => panic!("expected a global type"),
+        }
+
+        // get an export by name
+        let result = instance.get_export("mem");
+        assert!(result.is_ok());
+        let export = result.unwrap();
+        assert!(export.into_memory().is_some());
+
+        let result = instance.get_export("not-exist");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Instance(InstanceError::NotFoundExport("not-exist".into()))
+        );
+
+        // look up an export with the wrong kind
+        let result = instance.get_func("mem");
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(
+            err,
+            WasmEdgeError::Instance(InstanceError::MismatchedExport {
+                name: "mem".into(),
+                expected: ExternalInstanceType::from(ffi::WasmEdge_ExternalType_Function),
+                found: exports[2].1.ty().unwrap(),
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "Fail to find the target function (mem), which is exported as a memory"
+        );
+
+        let result = instance.get_global("not-exist");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Instance(InstanceError::NotFoundGlobal("not-exist".into()))
+        );
+
+        // the exports of a wasm module are listed in the export order, whatever their kinds are
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_bytes(
+            br#"
+            (module
+                (global (export "g") i32 (i32.const 0))
+                (memory (export "m") 1)
+                (func (export "f"))
+                (table (export "t") 1 funcref)
+                (export "f2" (func 0))
+            )
+            "#,
+        );
+        assert!(result.is_ok());
+        assert!(vm.validate().is_ok());
+        assert!(vm.instantiate().is_ok());
+        let result = vm.active_module();
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.exports().collect::<WasmEdgeResult<Vec<_>>>();
+        assert!(result.is_ok());
+        let exports = result.unwrap();
+        let names = exports
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["g", "m", "f", "t", "f2"]);
+        assert!(matches!(exports[0].1, Extern::Global(_)));
+        assert!(matches!(exports[1].1, Extern::Memory(_)));
+        assert!(matches!(exports[2].1, Extern::Func(_)));
+        assert!(matches!(exports[3].1, Extern::Table(_)));
+        assert!(matches!(exports[4].1, Extern::Func(_)));
+    }
+
     #[test]
     fn test_instance_get() {
         let module_name = "extern_module";
@@ -8,12 +8,12 @@
 use crate::{
     error::{TableError, WasmEdgeError},
     ffi,
-    types::WasmValue,
+    types::{copy_extern_refs, fill_extern_refs, root_extern_ref, unroot_extern_refs, WasmValue},
     utils::check,
-    WasmEdgeResult,
+    ExternRef, FuncRef, WasmEdgeResult,
 };
-use std::ops::RangeInclusive;
-use wasmedge_types::RefType;
+use std::ops::{Range, RangeInclusive};
+use wasmedge_types::{RefType, ValType};
 
 /// A WasmEdge [Table] defines a WebAssembly table instance described by its [type](crate::TableType). A table is an array-like structure and stores function references.
 ///
@@ -22,6 +22,8 @@ use wasmedge_types::RefType;
 pub struct Table {
     pub(crate) inner: InnerTable,
     pub(crate) registered: bool,
+    // The address of the module instance owning the table, or of the table itself if it is not owned by any module instance.
+    pub(crate) owner: usize,
 }
 impl Table {
     /// Creates a new [Table] to be associated with the given element type and the size.
@@ -54,6 +56,7 @@ impl Table {
             false => Ok(Table {
                 inner: InnerTable(ctx),
                 registered: false,
+                owner: ctx as usize,
             }),
         }
     }
@@ -104,6 +107,8 @@ impl Table {
     ///
     /// - `idx` specifies the position of the new data to be stored in the [Table].
     ///
+    /// If `data` holds an [ExternRef](crate::ExternRef), then the host object is kept alive until the slot is overwritten, or the [Table] is destroyed. A [Table] obtained from a [module instance](crate::Instance) is destroyed along with the module instance, and one added to an [import module](crate::ImportModule) along with the import module.
+    ///
     /// # Error
     ///
     /// If fail to set data, then an error is returned.
@@ -113,8 +118,10 @@ impl Table {
                 self.inner.0,
                 data.as_raw(),
                 idx,
-            ))
+            ))?;
         }
+        root_extern_ref(self.owner, self.inner.0 as usize, idx, &data);
+        Ok(())
     }
 
     /// Returns the capacity of the [Table].
@@ -134,7 +141,7 @@ impl Table {
     /// ```
     ///
     pub fn capacity(&self) -> usize {
-        unsafe { ffi::WasmEdge_TableInstanceGetSize(self.inner.0) as usize }
+        self.size() as usize
     }
 
     /// Increases the capacity of the [Table].
@@ -151,6 +158,232 @@ impl Table {
     pub fn grow(&mut self, size: u32) -> WasmEdgeResult<()> {
         unsafe { check(ffi::WasmEdge_TableInstanceGrow(self.inner.0, size)) }
     }
+
+    /// Increases the capacity of the [Table], and initializes the new elements with the given value.
+    ///
+    /// # Arguments
+    ///
+    /// - `size` specifies the size to be added to the [Table].
+    ///
+    /// - `init` specifies the value of the new elements. Its type must match the element type of the [Table].
+    ///
+    /// # Error
+    ///
+    /// If the type of `init` does not match the element type, or fail to increase the size of the [Table], then an error is returned.
+    pub fn grow_with(&mut self, size: u32, init: WasmValue) -> WasmEdgeResult<()> {
+        self.check_elem_ty(&init)?;
+        let old_size = self.size();
+        self.grow(size)?;
+        self.fill(old_size, size, init)
+    }
+
+    /// Returns the function reference at a specific position in the [Table].
+    ///
+    /// If the element is a null reference, then `None` is returned.
+    ///
+    /// # Argument
+    ///
+    /// - `idx` specifies the position in the [Table].
+    ///
+    /// # Error
+    ///
+    /// If the [Table] does not store function references, or `idx` is out of bounds, then an error is returned.
+    pub fn get_func(&self, idx: u32) -> WasmEdgeResult<Option<FuncRef>> {
+        self.check_elem_ty(&WasmValue::from_null_ref(RefType::FuncRef))?;
+        Ok(self.get_data(idx)?.func_ref())
+    }
+
+    /// Sets a function reference at a specific position in the [Table].
+    ///
+    /// # Arguments
+    ///
+    /// - `func_ref` specifies the function reference. `None` stores a null reference.
+    ///
+    /// - `idx` specifies the position in the [Table].
+    ///
+    /// # Error
+    ///
+    /// If the [Table] does not store function references, or `idx` is out of bounds, then an error is returned.
+    pub fn set_func(&mut self, func_ref: Option<FuncRef>, idx: u32) -> WasmEdgeResult<()> {
+        let data = match func_ref {
+            Some(func_ref) => WasmValue::from_func_ref(func_ref),
+            None => WasmValue::from_null_ref(RefType::FuncRef),
+        };
+        self.check_elem_ty(&data)?;
+        self.set_data(data, idx)
+    }
+
+    /// Returns the [ExternRef](crate::ExternRef) at a specific position in the [Table].
+    ///
+    /// If the element is a null reference, or refers to a host object that has been dropped, then `None` is returned.
+    ///
+    /// # Argument
+    ///
+    /// - `idx` specifies the position in the [Table].
+    ///
+    /// # Error
+    ///
+    /// If the [Table] does not store external references, or `idx` is out of bounds, then an error is returned.
+    pub fn get_extern(&self, idx: u32) -> WasmEdgeResult<Option<ExternRef>> {
+        self.check_elem_ty(&WasmValue::from_null_ref(RefType::ExternRef))?;
+        Ok(self.get_data(idx)?.extern_ref().cloned())
+    }
+
+    /// Sets an [ExternRef](crate::ExternRef) at a specific position in the [Table].
+    ///
+    /// # Arguments
+    ///
+    /// - `extern_ref` specifies the external reference. `None` stores a null reference.
+    ///
+    /// - `idx` specifies the position in the [Table].
+    ///
+    /// # Error
+    ///
+    /// If the [Table] does not store external references, or `idx` is out of bounds, then an error is returned.
+    pub fn set_extern(&mut self, extern_ref: Option<ExternRef>, idx: u32) -> WasmEdgeResult<()> {
+        let data = match extern_ref {
+            Some(extern_ref) => WasmValue::from_extern_ref(extern_ref),
+            None => WasmValue::from_null_ref(RefType::ExternRef),
+        };
+        self.check_elem_ty(&data)?;
+        self.set_data(data, idx)
+    }
+
+    /// Returns an iterator over the elements of the [Table].
+    ///
+    /// The iterator visits the elements in the range of the capacity at the moment this method is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{TableType, Table};
+    /// use wasmedge_types::RefType;
+    ///
+    /// let ty = TableType::create(RefType::FuncRef, 10..=20).expect("fail to create a TableType");
+    /// let table = Table::create(&ty).expect("fail to create a Table");
+    ///
+    /// assert_eq!(table.iter().filter(|elem| matches!(elem, Ok(elem) if elem.is_null_ref())).count(), 10);
+    /// ```
+    pub fn iter(&self) -> TableIter<'_> {
+        TableIter {
+            table: self,
+            range: 0..self.size(),
+        }
+    }
+
+    /// Sets the elements in the given range of the [Table] to the given value.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the position of the first element to be set.
+    ///
+    /// - `len` specifies the number of the elements to be set.
+    ///
+    /// - `val` specifies the value. Its type must match the element type of the [Table].
+    ///
+    /// # Error
+    ///
+    /// If the type of `val` does not match the element type, or the range is out of bounds, then an error is returned, and the [Table] is left unchanged.
+    pub fn fill(&mut self, offset: u32, len: u32, val: WasmValue) -> WasmEdgeResult<()> {
+        self.check_elem_ty(&val)?;
+        unsafe {
+            check(ffi::WasmEdge_TableInstanceFill(
+                self.inner.0,
+                val.as_raw(),
+                offset,
+                len,
+            ))?;
+        }
+        fill_extern_refs(self.owner, self.inner.0 as usize, offset, len, &val);
+        Ok(())
+    }
+
+    /// Copies the elements in a range of the [Table] to another range of the same [Table].
+    ///
+    /// The source and destination ranges may overlap.
+    ///
+    /// # Arguments
+    ///
+    /// - `dst` specifies the position of the first element to be written.
+    ///
+    /// - `src` specifies the position of the first element to be read.
+    ///
+    /// - `len` specifies the number of the elements to be copied.
+    ///
+    /// # Error
+    ///
+    /// If either range is out of bounds, then an error is returned, and the [Table] is left unchanged.
+    pub fn copy(&mut self, dst: u32, src: u32, len: u32) -> WasmEdgeResult<()> {
+        unsafe {
+            check(ffi::WasmEdge_TableInstanceCopy(
+                self.inner.0,
+                self.inner.0,
+                dst,
+                src,
+                len,
+            ))?;
+        }
+        let item = self.inner.0 as usize;
+        copy_extern_refs((self.owner, item, src), (self.owner, item, dst), len);
+        Ok(())
+    }
+
+    /// Copies the elements in a range of another [Table] to a range of the [Table].
+    ///
+    /// # Arguments
+    ///
+    /// - `dst` specifies the position of the first element to be written in the [Table].
+    ///
+    /// - `src_table` specifies the [Table] to read from. Its element type must match the one of the [Table].
+    ///
+    /// - `src` specifies the position of the first element to be read in `src_table`.
+    ///
+    /// - `len` specifies the number of the elements to be copied.
+    ///
+    /// # Error
+    ///
+    /// If the element types do not match, or either range is out of bounds, then an error is returned, and the [Table] is left unchanged.
+    pub fn copy_from(
+        &mut self,
+        dst: u32,
+        src_table: &Table,
+        src: u32,
+        len: u32,
+    ) -> WasmEdgeResult<()> {
+        if src_table.ty()?.elem_ty() != self.ty()?.elem_ty() {
+            return Err(WasmEdgeError::Table(TableError::UnmatchedRefType));
+        }
+        unsafe {
+            check(ffi::WasmEdge_TableInstanceCopy(
+                self.inner.0,
+                src_table.inner.0,
+                dst,
+                src,
+                len,
+            ))?;
+        }
+        copy_extern_refs(
+            (src_table.owner, src_table.inner.0 as usize, src),
+            (self.owner, self.inner.0 as usize, dst),
+            len,
+        );
+        Ok(())
+    }
+
+    fn size(&self) -> u32 {
+        unsafe { ffi::WasmEdge_TableInstanceGetSize(self.inner.0) }
+    }
+
+    fn check_elem_ty(&self, val: &WasmValue) -> WasmEdgeResult<()> {
+        let elem_ty = match self.ty()?.elem_ty() {
+            RefType::FuncRef => ValType::FuncRef,
+            RefType::ExternRef => ValType::ExternRef,
+        };
+        match val.ty() == elem_ty {
+            true => Ok(()),
+            false => Err(WasmEdgeError::Table(TableError::UnmatchedRefType)),
+        }
+    }
 }
 impl Drop for Table {
     fn drop(&mut self) {
@@ -158,6 +391,7 @@ impl Drop for Table {
             unsafe {
                 ffi::WasmEdge_TableInstanceDelete(self.inner.0);
             }
+            unroot_extern_refs(self.owner);
         }
     }
 }
@@ -167,6 +401,25 @@ pub(crate) struct InnerTable(pub(crate) *mut ffi::WasmEdge_TableInstanceContext)
 unsafe impl Send for InnerTable {}
 unsafe impl Sync for InnerTable {}
 
+/// An iterator over the elements of a [Table], which is created by [Table::iter].
+#[derive(Debug)]
+pub struct TableIter<'a> {
+    table: &'a Table,
+    range: Range<u32>,
+}
+impl<'a> Iterator for TableIter<'a> {
+    type Item = WasmEdgeResult<WasmValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.range.next()?;
+        Some(self.table.get_data(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
 /// A WasmEdge [TableType] classifies a [Table] instance over elements of element types within a size range.
 #[derive(Debug)]
 pub struct TableType {
@@ -202,7 +455,11 @@ impl TableType {
     /// ```
     ///
     pub fn create(elem_ty: RefType, limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
-        let ctx = unsafe { ffi::WasmEdge_TableTypeCreate(elem_ty.into(), limit.into()) };
+        Self::create_with_limit(elem_ty, limit.into())
+    }
+
+    fn create_with_limit(elem_ty: RefType, limit: ffi::WasmEdge_Limit) -> WasmEdgeResult<Self> {
+        let ctx = unsafe { ffi::WasmEdge_TableTypeCreate(elem_ty.into(), limit) };
         match ctx.is_null() {
             true => Err(WasmEdgeError::TableTypeCreate),
             false => Ok(Self {
@@ -239,18 +496,15 @@ impl TableType {
 }
 impl From<wasmedge_types::TableType> for TableType {
     fn from(ty: wasmedge_types::TableType) -> Self {
-        TableType::create(ty.elem_ty(), ty.minimum()..=ty.maximum()).expect(
+        TableType::create_with_limit(ty.elem_ty(), (&ty).into()).expect(
             "[wasmedge] Failed to convert wasmedge_types::TableType into wasmedge_sys::TableType.",
         )
     }
 }
 impl From<TableType> for wasmedge_types::TableType {
     fn from(ty: TableType) -> Self {
-        wasmedge_types::TableType::new(
-            ty.elem_ty(),
-            ty.limit().start().to_owned(),
-            Some(ty.limit().end().to_owned()),
-        )
+        let limit = unsafe { ffi::WasmEdge_TableTypeGetLimit(ty.inner.0) };
+        wasmedge_types::TableType::new(ty.elem_ty(), limit.Min, limit.HasMax.then_some(limit.Max))
     }
 }
 
@@ -262,7 +516,10 @@ unsafe impl Sync for InnerTableType {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{FuncType, Function};
+    use crate::{
+        error::{CoreError, CoreExecutionError},
+        FuncType, Function,
+    };
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -371,6 +628,211 @@ mod tests {
         assert_eq!(return_tys, [ValType::I32]);
     }
 
+    #[test]
+    fn test_table_func() {
+        // create a host function
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_add), 0);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+
+        // create a Table instance
+        let result = TableType::create(RefType::FuncRef, 10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+
+        // set and get a function reference
+        let result = table.set_func(Some(host_func.as_ref()), 3);
+        assert!(result.is_ok());
+        let result = table.get_func(3);
+        assert!(result.is_ok());
+        let func_ref = result.unwrap();
+        assert!(func_ref.is_some());
+        let result = func_ref.unwrap().ty();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().params_len(), 2);
+
+        // null reference
+        let result = table.get_func(4);
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+        let result = table.set_func(None, 3);
+        assert!(result.is_ok());
+        let result = table.get_func(3);
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+
+        // out of bounds
+        let result = table.get_func(10);
+        assert!(result.is_err());
+        let result = table.set_func(Some(host_func.as_ref()), 10);
+        assert!(result.is_err());
+
+        // unmatched element type
+        let result = table.get_extern(0);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::UnmatchedRefType)
+        );
+        let result = table.set_extern(Some(ExternRef::new(1314)), 0);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::UnmatchedRefType)
+        );
+    }
+
+    #[test]
+    fn test_table_bulk() {
+        // create a Table instance
+        let result = TableType::create(RefType::ExternRef, 10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+        let elems = |table: &Table| {
+            table
+                .iter()
+                .map(|elem| {
+                    elem.unwrap()
+                        .extern_ref()
+                        .map(|r| *r.downcast_ref::<i32>().unwrap())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // iterate over the elements
+        assert_eq!(table.iter().count(), 10);
+        assert!(table
+            .iter()
+            .all(|elem| matches!(elem, Ok(elem) if elem.is_null_ref())));
+
+        // fill a range
+        let result = table.fill(2, 3, WasmValue::from_extern_ref(ExternRef::new(1)));
+        assert!(result.is_ok());
+        let result = table.set_extern(Some(ExternRef::new(2)), 5);
+        assert!(result.is_ok());
+        assert_eq!(
+            elems(&table),
+            [
+                None,
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(2),
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+        let result = table.fill(8, 3, WasmValue::from_null_ref(RefType::ExternRef));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::TableOutOfBounds))
+        );
+        let result = table.fill(0, 1, WasmValue::from_null_ref(RefType::FuncRef));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::UnmatchedRefType)
+        );
+
+        // copy overlapping ranges
+        let result = table.copy(3, 2, 4);
+        assert!(result.is_ok());
+        assert_eq!(
+            elems(&table),
+            [
+                None,
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(2),
+                None,
+                None,
+                None
+            ]
+        );
+        let result = table.copy(0, 2, 5);
+        assert!(result.is_ok());
+        assert_eq!(
+            elems(&table),
+            [
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(2),
+                Some(1),
+                Some(2),
+                None,
+                None,
+                None
+            ]
+        );
+        let result = table.copy(8, 0, 3);
+        assert!(result.is_err());
+        let result = table.copy(0, u32::MAX, 2);
+        assert!(result.is_err());
+
+        // grow with an init value
+        let result = table.grow_with(2, WasmValue::from_extern_ref(ExternRef::new(3)));
+        assert!(result.is_ok());
+        assert_eq!(table.capacity(), 12);
+        assert_eq!(elems(&table)[10..], [Some(3), Some(3)]);
+        let result = table.grow_with(2, WasmValue::from_null_ref(RefType::FuncRef));
+        assert!(result.is_err());
+        assert_eq!(table.capacity(), 12);
+        let result = table.grow_with(10, WasmValue::from_null_ref(RefType::ExternRef));
+        assert!(result.is_err());
+        assert_eq!(table.capacity(), 12);
+
+        // copy from another table
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut other = result.unwrap();
+        let result = other.copy_from(5, &table, 9, 3);
+        assert!(result.is_ok());
+        assert_eq!(
+            elems(&other),
+            [
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(3),
+                Some(3),
+                None,
+                None
+            ]
+        );
+        let result = TableType::create(RefType::FuncRef, 10..=20);
+        assert!(result.is_ok());
+        let result = Table::create(&result.unwrap());
+        assert!(result.is_ok());
+        let func_table = result.unwrap();
+        let result = other.copy_from(0, &func_table, 0, 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::UnmatchedRefType)
+        );
+    }
+
     #[test]
     fn test_table_send() {
         // create a TableType instance
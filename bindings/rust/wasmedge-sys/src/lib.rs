@@ -105,6 +105,7 @@ pub mod ast_module;
 pub mod compiler;
 #[doc(hidden)]
 pub mod config;
+mod diagnostic;
 #[doc(hidden)]
 pub mod executor;
 #[doc(hidden)]
@@ -112,7 +113,15 @@ pub mod instance;
 #[doc(hidden)]
 pub mod io;
 #[doc(hidden)]
+pub mod linker;
+#[doc(hidden)]
 pub mod loader;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[doc(hidden)]
+pub mod pool;
+#[doc(hidden)]
+pub mod profile;
 #[doc(hidden)]
 pub mod statistics;
 #[doc(hidden)]
@@ -125,7 +134,7 @@ pub mod validator;
 pub mod vm;
 
 #[doc(inline)]
-pub use ast_module::{ExportType, ImportType, Module};
+pub use ast_module::{CustomSection, ExportType, ImportType, Module, NameSection};
 #[doc(inline)]
 #[cfg(feature = "aot")]
 pub use compiler::Compiler;
@@ -136,33 +145,50 @@ pub use executor::Executor;
 #[doc(inline)]
 pub use instance::{
     function::{FuncRef, FuncType, Function},
-    global::{Global, GlobalType},
+    global::{Global, GlobalSnapshot, GlobalType},
     memory::{MemType, Memory},
     module::{
-        ImportInstance, ImportModule, ImportObject, Instance, WasiModule, WasmEdgeProcessModule,
+        Extern, ImportInstance, ImportModule, ImportObject, Instance, WasiModule,
+        WasmEdgeProcessModule,
     },
     table::{Table, TableType},
 };
 #[doc(inline)]
+pub use linker::{InstancePre, Linker};
+#[doc(inline)]
 pub use loader::Loader;
 #[doc(inline)]
+pub use pool::{PoolMetrics, PooledVm, ResetPolicy, VmPool};
+#[doc(inline)]
+pub use profile::{FunctionProfile, Profile, ProfileEntry, ProfileMetric};
+#[doc(inline)]
 pub use statistics::Statistics;
 #[doc(inline)]
 pub use store::Store;
 #[doc(inline)]
-pub use types::WasmValue;
+pub use types::{ExternRef, WasmValue, WasmValueType};
 #[doc(inline)]
 pub use validator::Validator;
 #[doc(inline)]
 pub use vm::Vm;
+#[doc(inline)]
+pub use wasmedge_types::error::Diagnostic;
+#[doc(inline)]
+pub use wasmedge_types::inspector::{
+    ConstExpr, DataSegment, ElementSegment, FunctionBody, FunctionInfo, GlobalInfo, ImportInfo,
+    ModuleInfo, SegmentMode,
+};
 
 use wasmedge_types::{error, WasmEdgeResult};
 
 /// Type alias for a boxed native function.
 pub type BoxedFn = Box<dyn Fn(Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> + Send + Sync>;
 
+/// A host function with the return types declared by its function type.
+type HostFunc = (BoxedFn, Vec<wasmedge_types::ValType>);
+
 lazy_static! {
-    static ref HOST_FUNCS: Arc<Mutex<HashMap<usize, BoxedFn>>> =
+    static ref HOST_FUNCS: Arc<Mutex<HashMap<usize, HostFunc>>> =
         Arc::new(Mutex::new(HashMap::with_capacity(
             std::env::var("MAX_HOST_FUNC_LENGTH")
                 .map(|s| s
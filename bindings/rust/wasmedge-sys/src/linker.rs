@@ -0,0 +1,1187 @@
+//! Defines WasmEdge Linker.
+
+use crate::{
+    error::{GlobalError, LinkError, LinkerError, WasmEdgeError},
+    instance::module::InnerInstance,
+    Config, Executor, Function, Global, ImportInstance, ImportModule, ImportObject, Instance,
+    MemType, Memory, Module, Store, Table, Validator, WasmEdgeResult, WasmValue,
+};
+use std::{collections::HashMap, sync::Arc};
+use wasmedge_types::{ExternalInstanceType, RefType, ValType};
+
+/// Defines a [Linker], which resolves the imports of [modules](crate::Module) by name.
+///
+/// A [Linker] collects the host [functions](crate::Function), [tables](crate::Table), [memories](crate::Memory) and [globals](crate::Global), the [import objects](crate::ImportObject), and the exports of the registered [module instances](crate::Instance) under `(module, name)` keys. Before a [module](crate::Module) is instantiated, all its imports are checked against the definitions, and every unresolved or type-mismatched import is reported at once.
+///
+/// The host definitions and import objects are registered into the [Store](crate::Store) at the first instantiation. The [Store](crate::Store) shares the ownership of the registered import objects, so the [Linker] can be dropped before it.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sys::{
+///     Engine, Executor, FuncType, Function, Linker, Loader, Store, Validator, WasmValue,
+/// };
+/// use wasmedge_types::{wat2wasm, ValType};
+///
+/// fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+///     Ok(vec![WasmValue::from_i32(inputs[0].to_i32() + inputs[1].to_i32())])
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let wasm_bytes = wat2wasm(
+///         br#"
+///         (module
+///             (import "host" "add" (func $add (param i32 i32) (result i32)))
+///             (func (export "add_one") (param i32) (result i32)
+///                 (call $add (local.get 0) (i32.const 1)))
+///         )
+///         "#,
+///     )?;
+///     let module = Loader::create(None)?.from_bytes(&wasm_bytes)?;
+///     Validator::create(None)?.validate(&module)?;
+///
+///     // define the host function
+///     let mut linker = Linker::new();
+///     let ty = FuncType::create([ValType::I32; 2], [ValType::I32])?;
+///     linker.define_func("host", "add", Function::create(&ty, Box::new(real_add), 0)?)?;
+///
+///     // instantiate the module
+///     let mut executor = Executor::create(None, None)?;
+///     let mut store = Store::create()?;
+///     let instance = linker.instantiate(&mut executor, &mut store, &module)?;
+///
+///     let add_one = instance.get_func("add_one")?;
+///     let returns = executor.run_func(&add_one, [WasmValue::from_i32(1)])?;
+///     assert_eq!(returns[0].to_i32(), 2);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Linker {
+    modules: HashMap<String, LinkerModule>,
+    store: Option<usize>,
+}
+impl Linker {
+    /// Creates a new empty [Linker].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines a [host function](crate::Function) under the given module name and item name.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module name of the import.
+    ///
+    /// * `name` - The item name of the import.
+    ///
+    /// * `func` - The host function.
+    ///
+    /// # Error
+    ///
+    /// If the item has been defined, or the module has been registered, then an error is returned.
+    pub fn define_func(
+        &mut self,
+        module: impl AsRef<str>,
+        name: impl AsRef<str>,
+        func: Function,
+    ) -> WasmEdgeResult<()> {
+        let ty = ExternalInstanceType::Func(func.ty()?.into());
+        self.define(module.as_ref(), name.as_ref(), ty, Definition::Func(func))
+    }
+
+    /// Defines a [table](crate::Table) under the given module name and item name.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module name of the import.
+    ///
+    /// * `name` - The item name of the import.
+    ///
+    /// * `table` - The table.
+    ///
+    /// # Error
+    ///
+    /// If the item has been defined, or the module has been registered, then an error is returned.
+    pub fn define_table(
+        &mut self,
+        module: impl AsRef<str>,
+        name: impl AsRef<str>,
+        table: Table,
+    ) -> WasmEdgeResult<()> {
+        let ty = ExternalInstanceType::Table(table.ty()?.into());
+        self.define(module.as_ref(), name.as_ref(), ty, Definition::Table(table))
+    }
+
+    /// Defines a [memory](crate::Memory) under the given module name and item name.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module name of the import.
+    ///
+    /// * `name` - The item name of the import.
+    ///
+    /// * `memory` - The memory.
+    ///
+    /// # Error
+    ///
+    /// If the item has been defined, or the module has been registered, then an error is returned.
+    pub fn define_memory(
+        &mut self,
+        module: impl AsRef<str>,
+        name: impl AsRef<str>,
+        memory: Memory,
+    ) -> WasmEdgeResult<()> {
+        let ty = ExternalInstanceType::Memory(memory.ty()?.into());
+        self.define(
+            module.as_ref(),
+            name.as_ref(),
+            ty,
+            Definition::Memory(memory),
+        )
+    }
+
+    /// Defines a [global](crate::Global) under the given module name and item name.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module name of the import.
+    ///
+    /// * `name` - The item name of the import.
+    ///
+    /// * `global` - The global.
+    ///
+    /// # Error
+    ///
+    /// If the item has been defined, or the module has been registered, then an error is returned.
+    pub fn define_global(
+        &mut self,
+        module: impl AsRef<str>,
+        name: impl AsRef<str>,
+        global: Global,
+    ) -> WasmEdgeResult<()> {
+        let ty = ExternalInstanceType::Global(global.ty()?.into());
+        self.define(
+            module.as_ref(),
+            name.as_ref(),
+            ty,
+            Definition::Global(global),
+        )
+    }
+
+    /// Defines all the items of an [import object](crate::ImportObject), for example, a [WasiModule](crate::WasiModule), under its name.
+    ///
+    /// # Argument
+    ///
+    /// * `import` - The import object.
+    ///
+    /// # Error
+    ///
+    /// If a module of the same name has been defined, then an error is returned.
+    pub fn define_import_object(&mut self, import: ImportObject) -> WasmEdgeResult<()> {
+        let module = import.name();
+        if self.modules.contains_key(&module) {
+            return Err(WasmEdgeError::Linker(LinkerError::DuplicateModule(module)));
+        }
+
+        let view = Instance {
+            inner: InnerInstance(import.as_ptr()),
+            registered: true,
+            #[cfg(feature = "metrics")]
+            memory_pages: Default::default(),
+        };
+        let types = exported_types(&view)?;
+
+        self.modules.insert(
+            module,
+            LinkerModule {
+                types,
+                source: Source::Import {
+                    object: Arc::new(import),
+                    registered: false,
+                },
+            },
+        );
+        Ok(())
+    }
+
+    /// Defines all the exports of a named [module instance](crate::Instance) registered in a [Store](crate::Store) under its name.
+    ///
+    /// Notice that the [module instance](crate::Instance) should be alive as long as the modules importing it are in use.
+    ///
+    /// # Argument
+    ///
+    /// * `instance` - The named module instance.
+    ///
+    /// # Error
+    ///
+    /// If the module instance is anonymous, or a module of the same name has been defined, then an error is returned.
+    pub fn define_instance(&mut self, instance: &Instance) -> WasmEdgeResult<()> {
+        let module = instance
+            .name()
+            .ok_or(WasmEdgeError::Linker(LinkerError::AnonymousInstance))?;
+        if self.modules.contains_key(&module) {
+            return Err(WasmEdgeError::Linker(LinkerError::DuplicateModule(module)));
+        }
+
+        let types = exported_types(instance)?;
+        self.modules.insert(
+            module,
+            LinkerModule {
+                types,
+                source: Source::Instance,
+            },
+        );
+        Ok(())
+    }
+
+    /// Checks all the imports of a [module](crate::Module) against the definitions in the [Linker].
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The module to check.
+    ///
+    /// # Error
+    ///
+    /// If any import is not defined or the type of the definition does not match, then an error listing all such imports is returned.
+    pub fn check(&self, module: &Module) -> WasmEdgeResult<()> {
+        let mut errors = Vec::new();
+        for import in module.imports() {
+            let module_name = import.module_name().into_owned();
+            let name = import.name().into_owned();
+            let expected = import.ty()?;
+            let actual = self
+                .modules
+                .get(&module_name)
+                .and_then(|module| module.types.get(&name));
+            match actual {
+                None => errors.push(LinkError::Unknown {
+                    module: module_name,
+                    name,
+                    ty: expected,
+                }),
+                Some(actual) if !is_compatible(&expected, actual) => {
+                    errors.push(LinkError::IncompatibleType {
+                        module: module_name,
+                        name,
+                        expected,
+                        actual: actual.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(WasmEdgeError::Linker(LinkerError::UnresolvedImports(
+                errors,
+            ))),
+        }
+    }
+
+    /// Defines stubs for all the imports of a [module](crate::Module) which are not defined in the [Linker].
+    ///
+    /// This is useful for inspecting or testing a module of which only some functions are called. An unresolved function import is stubbed with a [host function](crate::Function) that traps with [Unreachable](wasmedge_types::error::CoreExecutionError::Unreachable) when called. An unresolved table, memory or global import is stubbed with a new instance of the imported type, of which the elements are null references, the pages are zero-filled, or the value is zero or a null reference, respectively.
+    ///
+    /// The imports which are defined but incompatible are left as is, and are still reported by [check](crate::Linker::check).
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The module of which the unresolved imports are stubbed.
+    ///
+    /// # Error
+    ///
+    /// If an unresolved import belongs to a module which has been registered or defined by an [import object](crate::ImportObject) or a [module instance](crate::Instance), or fail to create a stub, then an error is returned.
+    pub fn define_unknown_imports_as_stubs(&mut self, module: &Module) -> WasmEdgeResult<()> {
+        for import in module.imports() {
+            let module_name = import.module_name();
+            let name = import.name();
+            let defined = self
+                .modules
+                .get(module_name.as_ref())
+                .map(|module| module.types.contains_key(name.as_ref()));
+            if let Some(true) = defined {
+                continue;
+            }
+
+            match import.ty()? {
+                ExternalInstanceType::Func(ty) => {
+                    let func =
+                        Function::create(&ty.into(), Box::new(|_| Err(TRAP_UNREACHABLE)), 0)?;
+                    self.define_func(module_name, name, func)?;
+                }
+                ExternalInstanceType::Table(ty) => {
+                    let table = Table::create(&ty.into())?;
+                    self.define_table(module_name, name, table)?;
+                }
+                ExternalInstanceType::Memory(ty) => {
+                    let memory = Memory::create(&MemType::try_from(ty)?)?;
+                    self.define_memory(module_name, name, memory)?;
+                }
+                ExternalInstanceType::Global(ty) => {
+                    let value = default_value(ty.value_ty())?;
+                    let global = Global::create(&ty.into(), value)?;
+                    self.define_global(module_name, name, global)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the imports of a [module](crate::Module), and then instantiates it into a [Store](crate::Store) as an anonymous module.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` - The [Executor](crate::Executor) to instantiate the module.
+    ///
+    /// * `store` - The [Store](crate::Store) into which the module is instantiated.
+    ///
+    /// * `module` - The module to instantiate.
+    ///
+    /// # Error
+    ///
+    /// If any import can not be resolved, or fail to instantiate the module, then an error is returned.
+    pub fn instantiate(
+        &mut self,
+        executor: &mut Executor,
+        store: &mut Store,
+        module: &Module,
+    ) -> WasmEdgeResult<Instance> {
+        self.check(module)?;
+        self.register(executor, store)?;
+        executor.register_active_module(store, module)
+    }
+
+    /// Resolves the imports of a [module](crate::Module), instantiates it into a [Store](crate::Store) with the given name, and then defines its exports in the [Linker].
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` - The [Executor](crate::Executor) to instantiate the module.
+    ///
+    /// * `store` - The [Store](crate::Store) into which the module is instantiated.
+    ///
+    /// * `module` - The module to instantiate.
+    ///
+    /// * `name` - The name of the new module instance.
+    ///
+    /// # Error
+    ///
+    /// If a module of the same name has been defined, any import can not be resolved, or fail to instantiate the module, then an error is returned.
+    pub fn instantiate_named(
+        &mut self,
+        executor: &mut Executor,
+        store: &mut Store,
+        module: &Module,
+        name: impl AsRef<str>,
+    ) -> WasmEdgeResult<Instance> {
+        if self.modules.contains_key(name.as_ref()) {
+            return Err(WasmEdgeError::Linker(LinkerError::DuplicateModule(
+                name.as_ref().to_string(),
+            )));
+        }
+        self.check(module)?;
+        self.register(executor, store)?;
+        let instance = executor.register_named_module(store, module, name)?;
+        self.define_instance(&instance)?;
+        Ok(instance)
+    }
+
+    /// Validates a [module](crate::Module) and resolves its imports against the definitions in the [Linker], and then returns an [InstancePre] from which instances of the module can be created into any [Store](crate::Store) without validating and resolving again.
+    ///
+    /// The host definitions are moved into import modules, so no more definitions can be added to the modules which the [module](crate::Module) imports from. The [InstancePre] shares the import modules and import objects with the [Linker], therefore the instances created from it share the host [functions](crate::Function), [tables](crate::Table), [memories](crate::Memory) and [globals](crate::Global) as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module to pre-instantiate.
+    ///
+    /// * `config` - The optional [Config](crate::Config) for validating the module.
+    ///
+    /// # Error
+    ///
+    /// If fail to validate the module, any import can not be resolved, or any import is from a [module instance](crate::Instance) which is bound to a [Store](crate::Store), then an error is returned.
+    pub fn instantiate_pre(
+        &mut self,
+        module: Module,
+        config: Option<Config>,
+    ) -> WasmEdgeResult<InstancePre> {
+        Validator::create(config)?.validate(&module)?;
+        self.check(&module)?;
+        self.seal()?;
+
+        let mut imports: Vec<(String, Arc<ImportObject>)> = Vec::new();
+        for import in module.imports() {
+            let module_name = import.module_name();
+            if imports.iter().any(|(name, _)| name == module_name.as_ref()) {
+                continue;
+            }
+            match self.modules.get(module_name.as_ref()).map(|m| &m.source) {
+                Some(Source::Import { object, .. }) => {
+                    imports.push((module_name.into_owned(), Arc::clone(object)))
+                }
+                _ => {
+                    return Err(WasmEdgeError::Linker(LinkerError::StoreBoundModule(
+                        module_name.into_owned(),
+                    )))
+                }
+            }
+        }
+
+        Ok(InstancePre {
+            module,
+            imports: imports.into_iter().map(|(_, object)| object).collect(),
+        })
+    }
+
+    fn define(
+        &mut self,
+        module: &str,
+        name: &str,
+        ty: ExternalInstanceType,
+        def: Definition,
+    ) -> WasmEdgeResult<()> {
+        let entry = self
+            .modules
+            .entry(module.to_string())
+            .or_insert_with(|| LinkerModule {
+                types: HashMap::new(),
+                source: Source::Host(Vec::new()),
+            });
+        match &mut entry.source {
+            Source::Host(defs) => {
+                if entry.types.contains_key(name) {
+                    return Err(WasmEdgeError::Linker(LinkerError::DuplicateDefinition {
+                        module: module.to_string(),
+                        name: name.to_string(),
+                    }));
+                }
+                entry.types.insert(name.to_string(), ty);
+                defs.push((name.to_string(), def));
+                Ok(())
+            }
+            _ => Err(WasmEdgeError::Linker(LinkerError::ModuleRegistered(
+                module.to_string(),
+            ))),
+        }
+    }
+
+    /// Registers the host definitions and import objects which are not registered yet into the store.
+    fn register(&mut self, executor: &mut Executor, store: &mut Store) -> WasmEdgeResult<()> {
+        let store_addr = store.inner.0 as usize;
+        if matches!(self.store, Some(addr) if addr != store_addr) {
+            return Err(WasmEdgeError::Linker(LinkerError::StoreMismatch));
+        }
+
+        self.seal()?;
+        for entry in self.modules.values_mut() {
+            if let Source::Import { object, registered } = &mut entry.source {
+                if !*registered {
+                    executor.register_import_object(store, object)?;
+                    store.keep_import(object);
+                    *registered = true;
+                    self.store = Some(store_addr);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the host definitions into import modules, after which no more definitions can be added to them.
+    fn seal(&mut self) -> WasmEdgeResult<()> {
+        for (module, entry) in self.modules.iter_mut() {
+            if let Source::Host(defs) = &mut entry.source {
+                let mut import = ImportModule::create(module)?;
+                for (name, def) in defs.drain(..) {
+                    match def {
+                        Definition::Func(func) => import.add_func(name, func),
+                        Definition::Table(table) => import.add_table(name, table),
+                        Definition::Memory(memory) => import.add_memory(name, memory),
+                        Definition::Global(global) => import.add_global(name, global),
+                    }
+                }
+                entry.source = Source::Import {
+                    object: Arc::new(ImportObject::Import(import)),
+                    registered: false,
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Defines an [InstancePre], which is a validated [module](crate::Module) with all its imports resolved.
+///
+/// An [InstancePre] is created by [Linker::instantiate_pre](crate::Linker::instantiate_pre), and is used to create fresh [module instances](crate::Instance) into new [Stores](crate::Store) cheaply. It is `Send + Sync`, so it can be shared by worker threads, for example, in an [Arc](std::sync::Arc).
+///
+/// The import objects are registered into a [Store](crate::Store) at the first instantiation into it. The [Store](crate::Store) shares the ownership of the registered import objects, so an [InstancePre] can be dropped before the [Stores](crate::Store) into which it instantiates.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sys::{Engine, Executor, Linker, Loader, Store, WasmValue};
+/// use wasmedge_types::wat2wasm;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let wasm_bytes = wat2wasm(
+///         br#"
+///         (module
+///             (func (export "add_one") (param i32) (result i32)
+///                 (i32.add (local.get 0) (i32.const 1)))
+///         )
+///         "#,
+///     )?;
+///     let module = Loader::create(None)?.from_bytes(&wasm_bytes)?;
+///
+///     // validate the module and resolve its imports once
+///     let mut linker = Linker::new();
+///     let instance_pre = linker.instantiate_pre(module, None)?;
+///
+///     // instantiate the module into fresh stores
+///     let mut executor = Executor::create(None, None)?;
+///     for i in 0..3 {
+///         let mut store = Store::create()?;
+///         let instance = instance_pre.instantiate(&mut executor, &mut store)?;
+///         let add_one = instance.get_func("add_one")?;
+///         let returns = executor.run_func(&add_one, [WasmValue::from_i32(i)])?;
+///         assert_eq!(returns[0].to_i32(), i + 1);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct InstancePre {
+    module: Module,
+    imports: Vec<Arc<ImportObject>>,
+}
+impl InstancePre {
+    /// Returns the validated [module](crate::Module).
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Instantiates the [module](crate::Module) into a [Store](crate::Store) as an anonymous module.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` - The [Executor](crate::Executor) to instantiate the module.
+    ///
+    /// * `store` - The [Store](crate::Store) into which the module is instantiated.
+    ///
+    /// # Error
+    ///
+    /// If the [Store](crate::Store) has registered another module with the name of an import object, or fail to instantiate the module, then an error is returned.
+    pub fn instantiate(
+        &self,
+        executor: &mut Executor,
+        store: &mut Store,
+    ) -> WasmEdgeResult<Instance> {
+        for import in self.imports.iter() {
+            match store.module(import.name()) {
+                Ok(instance) if instance.inner.0 == import.as_ptr() => {}
+                _ => {
+                    executor.register_import_object(store, import)?;
+                    store.keep_import(import);
+                }
+            }
+        }
+        executor.register_active_module(store, &self.module)
+    }
+}
+
+#[derive(Debug)]
+struct LinkerModule {
+    types: HashMap<String, ExternalInstanceType>,
+    source: Source,
+}
+
+#[derive(Debug)]
+enum Source {
+    /// The host definitions, which are not registered into a store yet.
+    Host(Vec<(String, Definition)>),
+    /// An import object, which is registered into the store at the first instantiation.
+    Import {
+        object: Arc<ImportObject>,
+        registered: bool,
+    },
+    /// A module instance, which has been registered in the store.
+    Instance,
+}
+
+#[derive(Debug)]
+enum Definition {
+    Func(Function),
+    Table(Table),
+    Memory(Memory),
+    Global(Global),
+}
+
+/// The error code of the [Unreachable](wasmedge_types::error::CoreExecutionError::Unreachable) trap.
+const TRAP_UNREACHABLE: u8 = 0x89;
+
+/// Returns the zero value of a value type, or a null reference for a reference type.
+fn default_value(ty: ValType) -> WasmEdgeResult<WasmValue> {
+    match ty {
+        ValType::I32 => Ok(WasmValue::from_i32(0)),
+        ValType::I64 => Ok(WasmValue::from_i64(0)),
+        ValType::F32 => Ok(WasmValue::from_f32(0.0)),
+        ValType::F64 => Ok(WasmValue::from_f64(0.0)),
+        ValType::V128 => Ok(WasmValue::from_v128(0)),
+        ValType::FuncRef => Ok(WasmValue::from_null_ref(RefType::FuncRef)),
+        ValType::ExternRef => Ok(WasmValue::from_null_ref(RefType::ExternRef)),
+        ValType::None => Err(WasmEdgeError::Global(GlobalError::Type)),
+    }
+}
+
+/// Collects the types of all the exports of a module instance.
+fn exported_types(instance: &Instance) -> WasmEdgeResult<HashMap<String, ExternalInstanceType>> {
+    let mut types = HashMap::new();
+    for name in instance.func_names().unwrap_or_default() {
+        let ty = instance.get_func(&name)?.ty()?;
+        types.insert(name, ExternalInstanceType::Func(ty.into()));
+    }
+    for name in instance.table_names().unwrap_or_default() {
+        let ty = instance.get_table(&name)?.ty()?;
+        types.insert(name, ExternalInstanceType::Table(ty.into()));
+    }
+    for name in instance.mem_names().unwrap_or_default() {
+        let ty = instance.get_memory(&name)?.ty()?;
+        types.insert(name, ExternalInstanceType::Memory(ty.into()));
+    }
+    for name in instance.global_names().unwrap_or_default() {
+        let ty = instance.get_global(&name)?.ty()?;
+        types.insert(name, ExternalInstanceType::Global(ty.into()));
+    }
+    Ok(types)
+}
+
+/// Checks if an item of type `actual` can be imported as type `expected`.
+fn is_compatible(expected: &ExternalInstanceType, actual: &ExternalInstanceType) -> bool {
+    match (expected, actual) {
+        (ExternalInstanceType::Func(expected), ExternalInstanceType::Func(actual)) => {
+            expected.args().unwrap_or_default() == actual.args().unwrap_or_default()
+                && expected.returns().unwrap_or_default() == actual.returns().unwrap_or_default()
+        }
+        (ExternalInstanceType::Table(expected), ExternalInstanceType::Table(actual)) => {
+            expected.elem_ty() == actual.elem_ty()
+                && limits_match(
+                    (expected.minimum(), expected.maximum()),
+                    (actual.minimum(), actual.maximum()),
+                )
+        }
+        (ExternalInstanceType::Memory(expected), ExternalInstanceType::Memory(actual)) => {
+            expected.is_shared() == actual.is_shared()
+                && limits_match(
+                    (expected.minimum(), expected.maximum()),
+                    (actual.minimum(), actual.maximum()),
+                )
+        }
+        (ExternalInstanceType::Global(expected), ExternalInstanceType::Global(actual)) => {
+            expected == actual
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the `actual` limits of a table or a memory match the `expected` limits of an import, which requires the
+/// actual minimum size to be no less than the expected one. If the import declares a maximum size, the actual maximum
+/// size must be declared and be no greater than it.
+fn limits_match(expected: (u32, Option<u32>), actual: (u32, Option<u32>)) -> bool {
+    actual.0 >= expected.0
+        && match (expected.1, actual.1) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(expected), Some(actual)) => actual <= expected,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Engine, FuncType, GlobalType, Loader, MemType, TableType, Validator, WasmValue};
+    use wasmedge_types::{
+        error::{CoreError, CoreExecutionError},
+        wat2wasm, MemoryType, Mutability, RefType, TableType as TableTy, ValType,
+    };
+
+    #[test]
+    fn test_linker_check() {
+        let module = load(
+            r#"
+            (module
+                (import "env" "add" (func (param i32 i32) (result i32)))
+                (import "env" "global" (global (mut i32)))
+                (import "env" "memory" (memory 2))
+                (import "env" "table" (table 10 funcref))
+                (import "other" "func" (func))
+            )
+            "#,
+        );
+
+        let mut linker = Linker::new();
+        let result = linker.check(&module);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Linker(LinkerError::UnresolvedImports(errors)) => {
+                assert_eq!(errors.len(), 5);
+                assert!(errors
+                    .iter()
+                    .all(|err| matches!(err, LinkError::Unknown { .. })));
+            }
+            err => panic!("unexpected error: {:?}", err),
+        }
+
+        // define the items with mis-matched types
+        let result = linker.define_func("env", "add", host_func([ValType::I64; 2]));
+        assert!(result.is_ok());
+        let result = GlobalType::create(ValType::I32, Mutability::Const);
+        assert!(result.is_ok());
+        let result = Global::create(&result.unwrap(), WasmValue::from_i32(0));
+        assert!(result.is_ok());
+        let result = linker.define_global("env", "global", result.unwrap());
+        assert!(result.is_ok());
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let result = Memory::create(&result.unwrap());
+        assert!(result.is_ok());
+        let result = linker.define_memory("env", "memory", result.unwrap());
+        assert!(result.is_ok());
+        let result = TableType::create(RefType::FuncRef, 10..=20);
+        assert!(result.is_ok());
+        let result = Table::create(&result.unwrap());
+        assert!(result.is_ok());
+        let result = linker.define_table("env", "table", result.unwrap());
+        assert!(result.is_ok());
+
+        let result = linker.check(&module);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::UnresolvedImports(vec![
+                LinkError::IncompatibleType {
+                    module: "env".into(),
+                    name: "add".into(),
+                    expected: ExternalInstanceType::Func(wasmedge_types::FuncType::new(
+                        Some(vec![ValType::I32; 2]),
+                        Some(vec![ValType::I32])
+                    )),
+                    actual: ExternalInstanceType::Func(wasmedge_types::FuncType::new(
+                        Some(vec![ValType::I64; 2]),
+                        Some(vec![ValType::I32])
+                    )),
+                },
+                LinkError::IncompatibleType {
+                    module: "env".into(),
+                    name: "global".into(),
+                    expected: ExternalInstanceType::Global(wasmedge_types::GlobalType::new(
+                        ValType::I32,
+                        Mutability::Var
+                    )),
+                    actual: ExternalInstanceType::Global(wasmedge_types::GlobalType::new(
+                        ValType::I32,
+                        Mutability::Const
+                    )),
+                },
+                LinkError::IncompatibleType {
+                    module: "env".into(),
+                    name: "memory".into(),
+                    expected: ExternalInstanceType::Memory(MemoryType::new(2, None)),
+                    actual: ExternalInstanceType::Memory(MemoryType::new(1, Some(2))),
+                },
+                LinkError::Unknown {
+                    module: "other".into(),
+                    name: "func".into(),
+                    ty: ExternalInstanceType::Func(wasmedge_types::FuncType::new(
+                        Some(vec![]),
+                        Some(vec![])
+                    )),
+                },
+            ]))
+        );
+
+        // the table with a larger limit is compatible
+        assert!(is_compatible(
+            &ExternalInstanceType::Table(TableTy::new(RefType::FuncRef, 10, None)),
+            &ExternalInstanceType::Table(TableTy::new(RefType::FuncRef, 10, Some(20))),
+        ));
+        assert!(!is_compatible(
+            &ExternalInstanceType::Table(TableTy::new(RefType::FuncRef, 10, Some(20))),
+            &ExternalInstanceType::Table(TableTy::new(RefType::FuncRef, 10, None)),
+        ));
+        assert!(!is_compatible(
+            &ExternalInstanceType::Table(TableTy::new(RefType::FuncRef, 10, None)),
+            &ExternalInstanceType::Table(TableTy::new(RefType::ExternRef, 10, None)),
+        ));
+
+        // the memory without a maximum size only matches the import without a maximum size
+        assert!(is_compatible(
+            &ExternalInstanceType::Memory(MemoryType::new(1, None)),
+            &ExternalInstanceType::Memory(MemoryType::new(2, None)),
+        ));
+        assert!(is_compatible(
+            &ExternalInstanceType::Memory(MemoryType::new(1, Some(3))),
+            &ExternalInstanceType::Memory(MemoryType::new(2, Some(2))),
+        ));
+        assert!(!is_compatible(
+            &ExternalInstanceType::Memory(MemoryType::new(1, Some(2))),
+            &ExternalInstanceType::Memory(MemoryType::new(1, Some(3))),
+        ));
+        assert!(!is_compatible(
+            &ExternalInstanceType::Memory(MemoryType::new(1, Some(u32::MAX))),
+            &ExternalInstanceType::Memory(MemoryType::new(1, None)),
+        ));
+
+        // a global of the `None` type has no default value
+        assert_eq!(
+            default_value(ValType::None),
+            Err(WasmEdgeError::Global(GlobalError::Type))
+        );
+
+        // duplicate definition
+        let result = linker.define_func("env", "add", host_func([ValType::I32; 2]));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::DuplicateDefinition {
+                module: "env".into(),
+                name: "add".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_linker_instantiate() {
+        let lib = load(
+            r#"
+            (module
+                (import "host" "add" (func $add (param i32 i32) (result i32)))
+                (func (export "add_one") (param i32) (result i32)
+                    (call $add (local.get 0) (i32.const 1)))
+            )
+            "#,
+        );
+        let app = load(
+            r#"
+            (module
+                (import "lib" "add_one" (func $add_one (param i32) (result i32)))
+                (import "host" "add" (func $add (param i32 i32) (result i32)))
+                (func (export "add_three") (param i32) (result i32)
+                    (call $add (call $add_one (local.get 0)) (i32.const 2)))
+            )
+            "#,
+        );
+
+        let mut linker = Linker::new();
+        let result = linker.define_func("host", "add", host_func([ValType::I32; 2]));
+        assert!(result.is_ok());
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // the app module cannot be instantiated before the lib module
+        let result = linker.instantiate(&mut executor, &mut store, &app);
+        assert!(result.is_err());
+
+        // instantiate the lib module as a named module
+        let result = linker.instantiate_named(&mut executor, &mut store, &lib, "lib");
+        assert!(result.is_ok());
+        let lib_instance = result.unwrap();
+        let result = linker.instantiate_named(&mut executor, &mut store, &lib, "lib");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::DuplicateModule("lib".into()))
+        );
+
+        // instantiate the app module
+        let result = linker.instantiate(&mut executor, &mut store, &app);
+        assert!(result.is_ok());
+        let app_instance = result.unwrap();
+        let result = app_instance.get_func("add_three");
+        assert!(result.is_ok());
+        let add_three = result.unwrap();
+        let result = executor.run_func(&add_three, [WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 4);
+
+        // no more definitions can be added to the registered modules
+        let result = linker.define_func("host", "sub", host_func([ValType::I32; 2]));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::ModuleRegistered("host".into()))
+        );
+
+        // the registered definitions cannot be used with another store
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut other_store = result.unwrap();
+        let result = linker.instantiate(&mut executor, &mut other_store, &lib);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::StoreMismatch)
+        );
+
+        drop(app_instance);
+        drop(lib_instance);
+        drop(store);
+    }
+
+    #[test]
+    fn test_linker_stubs() {
+        let module = load(
+            r#"
+            (module
+                (import "env" "add" (func $add (param i32 i32) (result i32)))
+                (import "env" "unused" (func $unused (param i64)))
+                (import "env" "global" (global $global i64))
+                (import "env" "memory" (memory 1 2))
+                (import "wasi" "table" (table 2 funcref))
+                (import "wasi" "table2" (table 1 3 externref))
+                (func (export "pure") (param i32) (result i32)
+                    (i32.mul (local.get 0) (i32.const 2)))
+                (func (export "call_add") (result i32)
+                    (call $add (i32.const 1) (i32.const 2)))
+                (func (export "call_unused")
+                    (call $unused (global.get $global)))
+                (func (export "memory_size") (result i32)
+                    (memory.size))
+            )
+            "#,
+        );
+
+        let mut linker = Linker::new();
+        let result = linker.define_func("env", "add", host_func([ValType::I32; 2]));
+        assert!(result.is_ok());
+        let result = linker.define_unknown_imports_as_stubs(&module);
+        assert!(result.is_ok());
+        let result = linker.check(&module);
+        assert!(result.is_ok());
+
+        // the stubs keep the declared maximum sizes, or the lack of them
+        let other = load(r#"(module (import "mem" "memory" (memory 1)))"#);
+        let result = linker.define_unknown_imports_as_stubs(&other);
+        assert!(result.is_ok());
+        let result = linker.check(&other);
+        assert!(result.is_ok());
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = linker.instantiate(&mut executor, &mut store, &module);
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+
+        // the pure function and the defined import work as usual
+        let result = instance.get_func("pure");
+        assert!(result.is_ok());
+        let result = executor.run_func(&result.unwrap(), [WasmValue::from_i32(21)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 42);
+        let result = instance.get_func("call_add");
+        assert!(result.is_ok());
+        let result = executor.run_func(&result.unwrap(), []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+
+        // the stubbed memory has the minimum size
+        let result = instance.get_func("memory_size");
+        assert!(result.is_ok());
+        let result = executor.run_func(&result.unwrap(), []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // the stubbed function traps
+        let result = instance.get_func("call_unused");
+        assert!(result.is_ok());
+        let result = executor.run_func(&result.unwrap(), []);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::Unreachable))
+        );
+
+        // no stub can be added to a registered module
+        let result = linker
+            .define_unknown_imports_as_stubs(&load(r#"(module (import "env" "other" (func)))"#));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::ModuleRegistered("env".into()))
+        );
+    }
+
+    #[test]
+    fn test_linker_instance_pre() {
+        let wat = r#"
+            (module
+                (import "host" "add" (func $add (param i32 i32) (result i32)))
+                (global $counter (mut i32) (i32.const 0))
+                (func (export "count") (result i32)
+                    (global.set $counter (call $add (global.get $counter) (i32.const 1)))
+                    (global.get $counter))
+            )
+            "#;
+
+        let mut linker = Linker::new();
+        let result = linker.define_func("host", "add", host_func([ValType::I32; 2]));
+        assert!(result.is_ok());
+        let result = linker.instantiate_pre(load(wat), None);
+        assert!(result.is_ok());
+        let instance_pre = std::sync::Arc::new(result.unwrap());
+        assert_eq!(instance_pre.module().count_of_imports(), 1);
+
+        // the host definitions are sealed
+        let result = linker.define_func("host", "sub", host_func([ValType::I32; 2]));
+        assert!(result.is_err());
+
+        // every instance has its own state
+        let handles = (0..4)
+            .map(|_| {
+                let instance_pre = std::sync::Arc::clone(&instance_pre);
+                std::thread::spawn(move || {
+                    let result = Executor::create(None, None);
+                    assert!(result.is_ok());
+                    let mut executor = result.unwrap();
+                    let result = Store::create();
+                    assert!(result.is_ok());
+                    let mut store = result.unwrap();
+
+                    for _ in 0..2 {
+                        let result = instance_pre.instantiate(&mut executor, &mut store);
+                        assert!(result.is_ok());
+                        let instance = result.unwrap();
+                        let result = instance.get_func("count");
+                        assert!(result.is_ok());
+                        let count = result.unwrap();
+                        let result = executor.run_func(&count, []);
+                        assert!(result.is_ok());
+                        assert_eq!(result.unwrap()[0].to_i32(), 1);
+                        let result = executor.run_func(&count, []);
+                        assert!(result.is_ok());
+                        assert_eq!(result.unwrap()[0].to_i32(), 2);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert!(handle.join().is_ok());
+        }
+
+        // an import from a module instance is bound to its store
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = linker.instantiate_named(&mut executor, &mut store, &load(wat), "counter");
+        assert!(result.is_ok());
+        let _counter = result.unwrap();
+        let result = linker.instantiate_pre(
+            load(r#"(module (import "counter" "count" (func (result i32))))"#),
+            None,
+        );
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::StoreBoundModule("counter".into()))
+        );
+    }
+
+    #[test]
+    fn test_linker_drop_before_store() {
+        let wat = r#"
+            (module
+                (import "host" "add" (func $add (param i32 i32) (result i32)))
+                (func (export "add_one") (param i32) (result i32)
+                    (call $add (local.get 0) (i32.const 1)))
+            )
+            "#;
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // the store keeps the import objects registered by a linker alive
+        let mut linker = Linker::new();
+        let result = linker.define_func("host", "add", host_func([ValType::I32; 2]));
+        assert!(result.is_ok());
+        let result = linker.instantiate(&mut executor, &mut store, &load(wat));
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        drop(linker);
+        let result = instance.get_func("add_one");
+        assert!(result.is_ok());
+        let add_one = result.unwrap();
+        let result = executor.run_func(&add_one, [WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+
+        // the store keeps the import objects registered by an instance pre alive
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let mut linker = Linker::new();
+        let result = linker.define_func("host", "add", host_func([ValType::I32; 2]));
+        assert!(result.is_ok());
+        let result = linker.instantiate_pre(load(wat), None);
+        assert!(result.is_ok());
+        let instance_pre = result.unwrap();
+        drop(linker);
+        let result = instance_pre.instantiate(&mut executor, &mut store);
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        drop(instance_pre);
+        let result = instance.get_func("add_one");
+        assert!(result.is_ok());
+        let add_one = result.unwrap();
+        let result = executor.run_func(&add_one, [WasmValue::from_i32(2)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+    }
+
+    fn load(wat: &str) -> Module {
+        let result = wat2wasm(wat.as_bytes());
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_bytes(wasm_bytes);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+        module
+    }
+
+    fn host_func(params: [ValType; 2]) -> Function {
+        let result = FuncType::create(params, [ValType::I32]);
+        assert!(result.is_ok());
+        let result = Function::create(&result.unwrap(), Box::new(real_add), 0);
+        assert!(result.is_ok());
+        result.unwrap()
+    }
+
+    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+        Ok(vec![WasmValue::from_i32(
+            inputs[0].to_i32() + inputs[1].to_i32(),
+        )])
+    }
+}
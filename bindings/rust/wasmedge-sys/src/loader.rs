@@ -2,18 +2,19 @@
 
 use crate::{
     ast_module::{InnerModule, Module},
-    error::WasmEdgeError,
-    ffi, utils,
-    utils::check,
-    Config, WasmEdgeResult,
+    diagnostic::diagnosed,
+    error::{CoreError, CoreLoadError, WasmEdgeError},
+    ffi, utils, Config, WasmEdgeResult,
 };
-use std::path::Path;
+use std::{io::Read, path::Path, sync::Mutex};
 
-/// [Loader](crate::Loader) is used to load WASM modules from the given WASM files or buffers.
+/// [Loader](crate::Loader) is used to load WASM modules from the given WASM files or buffers, in either the binary
+/// or the text format.
 #[derive(Debug)]
 pub struct Loader {
     pub(crate) inner: InnerLoader,
     pub(crate) registered: bool,
+    lock: Mutex<()>,
 }
 impl Loader {
     /// Create a new [Loader](crate::Loader) to be associated with the given global configuration.
@@ -40,19 +41,24 @@ impl Loader {
             false => Ok(Self {
                 inner: InnerLoader(ctx),
                 registered: false,
+                lock: Mutex::new(()),
             }),
         }
     }
 
-    /// Loads a WASM module from a WASM file with the suffix `.wasm`.
+    /// Loads a WASM module from a WASM file with the suffix `.wasm`, or a WAT file with the suffix `.wat`.
     ///
     /// # Arguments
     ///
-    /// * `file` - The path to the target WASM file.
+    /// * `file` - The path to the target WASM or WAT file.
     ///
     /// # Error
     ///
-    /// If fail to load, then an error is returned.
+    /// If fail to load, then an error is returned, which is a [Diagnosed](crate::error::WasmEdgeError::Diagnosed) error
+    /// if the location of the failure is known. If fail to read a WAT file, then a
+    /// [ReadFile](crate::error::WasmEdgeError::ReadFile) error with the kind of the I/O error, such as `NotFound` or
+    /// `PermissionDenied`, is returned. If fail to parse a WAT file, then a [WatError](crate::error::WatError) with
+    /// the location of the error is returned.
     ///
     /// # Example
     ///
@@ -61,16 +67,21 @@ impl Loader {
     /// let module = loader.from_file(file)?;
     /// ```
     pub fn from_file(&self, file: impl AsRef<Path>) -> WasmEdgeResult<Module> {
-        let c_path = utils::path_to_cstring(file.as_ref())?;
-        let mut mod_ctx = std::ptr::null_mut();
-        unsafe {
-            check(ffi::WasmEdge_LoaderParseFromFile(
-                self.inner.0,
-                &mut mod_ctx,
-                c_path.as_ptr(),
-            ))?;
+        let file = file.as_ref();
+        if matches!(file.extension(), Some(ext) if ext == "wat") {
+            let bytes = std::fs::read(file).map_err(|err| WasmEdgeError::ReadFile {
+                path: file.display().to_string(),
+                kind: err.kind(),
+            })?;
+            return self.from_wat(std::str::from_utf8(&bytes)?);
         }
 
+        let c_path = utils::path_to_cstring(file)?;
+        let mut mod_ctx = std::ptr::null_mut();
+        self.parse(|| unsafe {
+            ffi::WasmEdge_LoaderParseFromFile(self.inner.0, &mut mod_ctx, c_path.as_ptr())
+        })?;
+
         match mod_ctx.is_null() {
             true => Err(WasmEdgeError::ModuleCreate),
             false => Ok(Module {
@@ -81,30 +92,34 @@ impl Loader {
 
     /// Loads a WASM module from a in-memory bytes.
     ///
+    /// If the bytes are the text of a module in the WebAssembly text format, they are parsed as by
+    /// [from_wat](crate::Loader::from_wat).
+    ///
     /// # Arguments
     ///
-    /// * `bytes` - A in-memory WASM bytes.
+    /// * `bytes` - A in-memory WASM bytes, or the text of a WAT module.
     ///
     /// # Error
     ///
-    /// If fail to load, then an error is returned.
+    /// If fail to load, then an error is returned, which is a [Diagnosed](crate::error::WasmEdgeError::Diagnosed) error
+    /// if the location of the failure is known.
     ///
     /// # Example
     ///
     /// ```ignore
     /// let bytes = b"\0asm\x01\0\0\0";
     /// let module = loader.from_bytes(&bytes)?;
-    /// ```
-    ///
-    /// Note that the text format is not accepted:
     ///
-    /// ```ignore
-    /// assert!(loader.from_bytes(b"(module)").is_err());
+    /// let module = loader.from_bytes(b"(module)")?;
     /// ```
     pub fn from_bytes(&self, bytes: impl AsRef<[u8]>) -> WasmEdgeResult<Module> {
+        if let Some(wat) = as_wat(bytes.as_ref()) {
+            return self.from_wat(wat);
+        }
+
         let mut mod_ctx: *mut ffi::WasmEdge_ASTModuleContext = std::ptr::null_mut();
 
-        unsafe {
+        let result = unsafe {
             let ptr = libc::malloc(bytes.as_ref().len());
             let dst = ::core::slice::from_raw_parts_mut(
                 ptr.cast::<std::mem::MaybeUninit<u8>>(),
@@ -116,15 +131,19 @@ impl Loader {
             );
             dst.copy_from_slice(src);
 
-            check(ffi::WasmEdge_LoaderParseFromBuffer(
-                self.inner.0,
-                &mut mod_ctx,
-                ptr as *const u8,
-                bytes.as_ref().len() as u32,
-            ))?;
+            let result = self.parse(|| {
+                ffi::WasmEdge_LoaderParseFromBuffer(
+                    self.inner.0,
+                    &mut mod_ctx,
+                    ptr as *const u8,
+                    bytes.as_ref().len() as u32,
+                )
+            });
 
             libc::free(ptr as *mut libc::c_void);
-        }
+            result
+        };
+        result?;
 
         match mod_ctx.is_null() {
             true => Err(WasmEdgeError::ModuleCreate),
@@ -133,6 +152,59 @@ impl Loader {
             }),
         }
     }
+
+    /// Loads a WASM module from a reader, such as a network stream, an archive entry or a decompressor.
+    ///
+    /// The section headers of a binary module are checked while the bytes arrive, so a malformed stream is rejected
+    /// as soon as the offending header is read, without consuming the rest of the stream. The whole module is still
+    /// buffered in memory before being parsed. A module in the WebAssembly text format is also accepted.
+    ///
+    /// # Argument
+    ///
+    /// * `reader` - The source of the WASM bytes or the WAT text.
+    ///
+    /// # Error
+    ///
+    /// If fail to read from `reader`, then a [CoreLoadError::ReadError](crate::error::CoreLoadError::ReadError) is
+    /// returned. If fail to load, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let file = std::fs::File::open("path/to/foo.wasm.gz")?;
+    /// let module = loader.from_reader(flate2::read::GzDecoder::new(file))?;
+    /// ```
+    pub fn from_reader(&self, reader: impl Read) -> WasmEdgeResult<Module> {
+        let bytes = read_module(reader)?;
+        self.from_bytes(bytes)
+    }
+
+    /// Loads a WASM module from the WebAssembly text format.
+    ///
+    /// # Argument
+    ///
+    /// * `wat` - The text of a WAT module.
+    ///
+    /// # Error
+    ///
+    /// If fail to parse the text, then a [WatError](crate::error::WatError) with the 1-based line and column of the
+    /// error is returned. If fail to load the parsed module, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let module = loader.from_wat(r#"(module (func (export "nop")))"#)?;
+    /// ```
+    pub fn from_wat(&self, wat: impl AsRef<str>) -> WasmEdgeResult<Module> {
+        let bytes = wasmedge_types::parse_wat(wat).map_err(WasmEdgeError::Wat)?;
+        self.from_bytes(bytes)
+    }
+
+    fn parse(&self, call: impl FnOnce() -> ffi::WasmEdge_Result) -> WasmEdgeResult<()> {
+        diagnosed(&self.lock, call, || unsafe {
+            ffi::WasmEdge_LoaderGetDiagnostic(self.inner.0)
+        })
+    }
 }
 impl Drop for Loader {
     fn drop(&mut self) {
@@ -147,13 +219,107 @@ pub(crate) struct InnerLoader(pub(crate) *mut ffi::WasmEdge_LoaderContext);
 unsafe impl Send for InnerLoader {}
 unsafe impl Sync for InnerLoader {}
 
+/// Reads a module from the reader, checking the preamble and the section headers of a binary module on the fly.
+fn read_module(mut reader: impl Read) -> WasmEdgeResult<Vec<u8>> {
+    let load_error = |err: CoreLoadError| WasmEdgeError::Core(CoreError::Load(err));
+    let read_error = |_: std::io::Error| load_error(CoreLoadError::ReadError);
+
+    let mut bytes = Vec::new();
+    reader
+        .by_ref()
+        .take(8)
+        .read_to_end(&mut bytes)
+        .map_err(read_error)?;
+    if bytes.len() < 8 || !bytes.starts_with(b"\0asm") {
+        // not a binary module, so leave the detection and the errors to `from_bytes`
+        reader.read_to_end(&mut bytes).map_err(read_error)?;
+        return Ok(bytes);
+    }
+    if bytes[4..8] != [0x01, 0x00, 0x00, 0x00] {
+        return Err(load_error(CoreLoadError::MalformedVersion));
+    }
+
+    let mut loaded = [false; 13];
+    while let Some(id) = read_byte(&mut reader).map_err(read_error)? {
+        bytes.push(id);
+        match loaded.get_mut(id as usize) {
+            Some(loaded) if id != 0 => {
+                if *loaded {
+                    return Err(load_error(CoreLoadError::JunkSection));
+                }
+                *loaded = true;
+            }
+            Some(_) => {}
+            None => return Err(load_error(CoreLoadError::MalformedSection)),
+        }
+
+        // the section size in unsigned LEB128
+        let mut size = 0u32;
+        for shift in (0..35).step_by(7) {
+            let byte = read_byte(&mut reader)
+                .map_err(read_error)?
+                .ok_or_else(|| load_error(CoreLoadError::UnexpectedEnd))?;
+            bytes.push(byte);
+            if shift == 28 && byte & 0xF0 != 0 {
+                return Err(load_error(match byte & 0x80 {
+                    0 => CoreLoadError::IntegerTooLarge,
+                    _ => CoreLoadError::IntegerTooLong,
+                }));
+            }
+            size |= ((byte & 0x7F) as u32) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        let read = reader
+            .by_ref()
+            .take(size as u64)
+            .read_to_end(&mut bytes)
+            .map_err(read_error)?;
+        if read < size as usize {
+            return Err(load_error(CoreLoadError::UnexpectedEnd));
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Reads a single byte, or returns `None` at the end of the reader.
+fn read_byte(reader: &mut impl Read) -> std::io::Result<Option<u8>> {
+    let mut byte = [0u8];
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => return Ok(None),
+            Ok(_) => return Ok(Some(byte[0])),
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Returns the text if the bytes look like a module in the WebAssembly text format, that is, the text starts with
+/// a `(` or a comment after the leading whitespaces.
+fn as_wat(bytes: &[u8]) -> Option<&str> {
+    if bytes.starts_with(b"\0asm") {
+        return None;
+    }
+    let text = std::str::from_utf8(bytes).ok()?;
+    let trimmed = text.trim_start();
+    match trimmed.starts_with('(') || trimmed.starts_with(";;") {
+        true => Some(text),
+        false => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Loader;
     use crate::{
-        error::{CoreError, CoreLoadError, WasmEdgeError},
+        error::{CoreError, CoreLoadError, WasmEdgeError, WatError},
         Config,
     };
+    use std::io::Read;
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -184,14 +350,28 @@ mod tests {
             let module = result.unwrap();
             assert!(!module.inner.0.is_null());
 
-            // Not support .wat file
+            // load .wat file
             let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
                 .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wat");
             let result = loader.from_file(path);
+            assert!(result.is_ok());
+            let module = result.unwrap();
+            assert!(!module.inner.0.is_null());
+            assert_eq!(module.count_of_exports(), 1);
+
+            let result = loader.from_file("not_exist_file.wat");
             assert!(result.is_err());
+            let err = result.unwrap_err();
             assert_eq!(
-                result.unwrap_err(),
-                WasmEdgeError::Core(CoreError::Load(CoreLoadError::MalformedMagic))
+                err,
+                WasmEdgeError::ReadFile {
+                    path: "not_exist_file.wat".into(),
+                    kind: std::io::ErrorKind::NotFound,
+                }
+            );
+            assert_eq!(
+                err.to_string(),
+                "Fail to read the file (not_exist_file.wat): entity not found"
             );
 
             let result = loader.from_file("not_exist_file");
@@ -210,22 +390,140 @@ mod tests {
             let module = result.unwrap();
             assert!(!module.inner.0.is_null());
 
-            // the text format is not accepted
-            let result = loader.from_bytes(b"(module)");
+            // the text format is detected
+            let result = loader.from_bytes(b"  ;; empty module\n(module)");
+            assert!(result.is_ok());
+
+            // neither a binary nor a text
+            let result = loader.from_bytes(b"module");
             assert!(result.is_err());
-            assert_eq!(
+            assert!(matches!(
                 result.unwrap_err(),
-                WasmEdgeError::Core(CoreError::Load(CoreLoadError::MalformedMagic))
-            );
+                WasmEdgeError::Diagnosed {
+                    error: CoreError::Load(CoreLoadError::MalformedMagic),
+                    ..
+                }
+            ));
 
             // empty is not accepted
             let result = loader.from_bytes(&[]);
             assert!(result.is_err());
-            assert_eq!(
+            assert!(matches!(
                 result.unwrap_err(),
-                WasmEdgeError::Core(CoreError::Load(CoreLoadError::UnexpectedEnd))
-            );
+                WasmEdgeError::Diagnosed {
+                    error: CoreError::Load(CoreLoadError::UnexpectedEnd),
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_loader_from_wat() {
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+
+        let result = loader.from_wat(
+            r#"
+            (module
+                (func (export "add") (param i32 i32) (result i32)
+                    local.get 0
+                    local.get 1
+                    i32.add)
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert_eq!(module.count_of_exports(), 1);
+        assert_eq!(module.exports()[0].name(), "add");
+
+        // a parse error reports the location
+        let result = loader.from_wat("(module\n  (func (result i32)\n    i32.const))");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Wat(WatError::Parse { line, column, .. }) => {
+                assert_eq!(line, 3);
+                assert_eq!(column, 14);
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+
+        // the same error is returned for a text buffer
+        let result = loader.from_bytes(b"(module (func $f) (func $f))");
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::Wat(WatError::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_loader_from_reader() {
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+
+        // load from a file
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = std::fs::File::open(&path);
+        assert!(result.is_ok());
+        let file = result.unwrap();
+        let result = loader.from_reader(file);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert_eq!(module.count_of_exports(), 1);
+
+        // load from a reader yielding one byte at a time
+        struct ByteReader(std::io::Cursor<Vec<u8>>);
+        impl Read for ByteReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = buf.len().min(1);
+                self.0.read(&mut buf[..len])
+            }
+        }
+        let result = std::fs::read(&path);
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+        let result = loader.from_reader(ByteReader(std::io::Cursor::new(wasm_bytes.clone())));
+        assert!(result.is_ok());
+
+        // load the text format
+        let result = loader.from_reader(&b"(module)"[..]);
+        assert!(result.is_ok());
+
+        // a malformed section id is rejected without draining the endless stream
+        let reader = (&b"\0asm\x01\0\0\0\x0E"[..]).chain(std::io::repeat(0));
+        let result = loader.from_reader(reader);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Load(CoreLoadError::MalformedSection))
+        );
+
+        // a truncated section
+        let result = loader.from_reader(&wasm_bytes[..wasm_bytes.len() - 1]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Load(CoreLoadError::UnexpectedEnd))
+        );
+
+        // a failing reader
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::ConnectionReset.into())
+            }
         }
+        let result = loader.from_reader(FailingReader);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Load(CoreLoadError::ReadError))
+        );
     }
 
     #[test]
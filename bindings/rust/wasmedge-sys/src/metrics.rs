@@ -0,0 +1,438 @@
+//! Defines the runtime metrics of WasmEdge, which are recorded through the [metrics](https://docs.rs/metrics/0.21) facade.
+//!
+//! The metrics are recorded only if the `metrics` feature is enabled, and are dropped until a recorder is installed by
+//! the application, for example, the [Prometheus exporter](https://docs.rs/metrics-exporter-prometheus).
+//!
+//! | Name | Type | Labels | Description |
+//! | ---- | ---- | ------ | ----------- |
+//! | `wasmedge_instantiations_total` | counter | `status` | The number of the module instantiations. |
+//! | `wasmedge_instantiation_duration_seconds` | histogram | `status` | The latency of the module instantiations. |
+//! | `wasmedge_executions_total` | counter | `status` | The number of the WASM function executions. |
+//! | `wasmedge_traps_total` | counter | `kind` | The number of the traps, by the kind of the [CoreExecutionError](crate::error::CoreExecutionError). |
+//! | `wasmedge_instructions_total` | counter | | The number of the executed instructions. |
+//! | `wasmedge_memory_pages` | gauge | | The pages of the exported memories of the live module instances. |
+//! | `wasmedge_host_calls_total` | counter | | The number of the host function calls. |
+//!
+//! The `status` label is one of `ok`, `trap` and `error`. The executed instructions are counted only if the instruction
+//! counting is turned on in the [Config](crate::Config).
+//!
+//! The memory pages are process-wide: the pages of a module instance are added to the gauge when it is instantiated by
+//! a [Vm](crate::Vm) or an [Executor](crate::Executor), and subtracted when it is destroyed, for example, when the
+//! [Instance](crate::Instance) or the [Vm](crate::Vm) is dropped, or the [Vm](crate::Vm) is reset. They are sampled
+//! again at most once per second after the executions in a [Vm](crate::Vm), so the gauge may lag behind the growth of
+//! a memory.
+//!
+//! The instantiations and the executions by an [Executor](crate::Executor) are recorded as well, but the executed
+//! instructions are only counted, and the memory pages are only sampled after the executions, in a [Vm](crate::Vm),
+//! since an [Executor](crate::Executor) does not own its [Statistics](crate::Statistics) and module instances.
+
+use crate::{
+    error::{CoreError, WasmEdgeError},
+    ffi, Instance, Vm, WasmEdgeResult,
+};
+use std::{
+    cmp::Ordering as CmpOrdering,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// The number of the module instantiations.
+pub const INSTANTIATIONS: &str = "wasmedge_instantiations_total";
+/// The latency of the module instantiations.
+pub const INSTANTIATION_DURATION: &str = "wasmedge_instantiation_duration_seconds";
+/// The number of the WASM function executions.
+pub const EXECUTIONS: &str = "wasmedge_executions_total";
+/// The number of the traps.
+pub const TRAPS: &str = "wasmedge_traps_total";
+/// The number of the executed instructions.
+pub const INSTRUCTIONS: &str = "wasmedge_instructions_total";
+/// The pages of the exported memories of the live module instances.
+pub const MEMORY_PAGES: &str = "wasmedge_memory_pages";
+/// The number of the host function calls.
+pub const HOST_CALLS: &str = "wasmedge_host_calls_total";
+
+/// The interval of sampling the memory pages after the executions in a [Vm](crate::Vm).
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+lazy_static! {
+    /// The epoch of the sampling times of the memory pages.
+    static ref EPOCH: Instant = Instant::now();
+}
+
+/// Registers the descriptions and units of the WasmEdge metrics into the installed recorder.
+///
+/// Call this function after installing the recorder, so that the exporter outputs the help texts of the metrics.
+pub fn describe() {
+    ::metrics::describe_counter!(INSTANTIATIONS, "The number of the module instantiations.");
+    ::metrics::describe_histogram!(
+        INSTANTIATION_DURATION,
+        ::metrics::Unit::Seconds,
+        "The latency of the module instantiations."
+    );
+    ::metrics::describe_counter!(EXECUTIONS, "The number of the WASM function executions.");
+    ::metrics::describe_counter!(TRAPS, "The number of the traps.");
+    ::metrics::describe_counter!(
+        INSTRUCTIONS,
+        ::metrics::Unit::Count,
+        "The number of the executed instructions."
+    );
+    ::metrics::describe_gauge!(
+        MEMORY_PAGES,
+        ::metrics::Unit::Count,
+        "The pages of the exported memories of the live module instances."
+    );
+    ::metrics::describe_counter!(HOST_CALLS, "The number of the host function calls.");
+}
+
+/// Returns the `status` label of the result of an instantiation or an execution.
+fn status<T>(result: &WasmEdgeResult<T>) -> &'static str {
+    match result {
+        Ok(_) => "ok",
+        Err(err) if trap_kind(err).is_some() => "trap",
+        Err(_) => "error",
+    }
+}
+
+/// Returns the kind of the trap, which is the name of the [CoreExecutionError](crate::error::CoreExecutionError).
+fn trap_kind(err: &WasmEdgeError) -> Option<String> {
+    match err {
+        WasmEdgeError::Trap(trap) => match &trap.error {
+            CoreError::Execution(error) => Some(format!("{:?}", error)),
+            _ => None,
+        },
+        WasmEdgeError::Core(CoreError::Execution(error)) => Some(format!("{:?}", error)),
+        _ => None,
+    }
+}
+
+/// Records an instantiation started at `start`.
+pub(crate) fn record_instantiation(start: Instant, result: &WasmEdgeResult<()>) {
+    let status = status(result);
+    ::metrics::increment_counter!(INSTANTIATIONS, "status" => status);
+    ::metrics::histogram!(INSTANTIATION_DURATION, start.elapsed(), "status" => status);
+}
+
+/// Records a host function call.
+pub(crate) fn record_host_call() {
+    ::metrics::increment_counter!(HOST_CALLS);
+}
+
+/// Records the result of an execution.
+pub(crate) fn record_execution<T>(result: &WasmEdgeResult<T>) {
+    ::metrics::increment_counter!(EXECUTIONS, "status" => status(result));
+    if let Some(kind) = result.as_ref().err().and_then(trap_kind) {
+        ::metrics::increment_counter!(TRAPS, "kind" => kind);
+    }
+}
+
+/// The pages of the exported memories of a live module instance, which are accounted in the [MEMORY_PAGES] gauge until
+/// dropped.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryPages(AtomicU64);
+impl MemoryPages {
+    /// Updates the pages of the module instance, and adjusts the gauge by the difference.
+    fn update(&self, pages: u64) {
+        let last = self.0.swap(pages, Ordering::Relaxed);
+        match pages.cmp(&last) {
+            CmpOrdering::Greater => {
+                ::metrics::increment_gauge!(MEMORY_PAGES, (pages - last) as f64)
+            }
+            CmpOrdering::Less => ::metrics::decrement_gauge!(MEMORY_PAGES, (last - pages) as f64),
+            CmpOrdering::Equal => {}
+        }
+    }
+}
+impl Drop for MemoryPages {
+    fn drop(&mut self) {
+        self.update(0);
+    }
+}
+
+/// Returns the pages of the exported memories of a module instance.
+fn memory_pages(instance: &Instance) -> u64 {
+    instance
+        .mem_names()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|name| instance.get_memory(name).ok())
+        .map(|memory| memory.size() as u64)
+        .sum()
+}
+
+/// Records the pages of the exported memories of a module instance owned by the [Instance].
+pub(crate) fn record_memory_pages(instance: &Instance) {
+    instance.memory_pages.update(memory_pages(instance));
+}
+
+/// Records the pages of the exported memories of a module instance owned by a [Vm](crate::Vm), which is the active
+/// module instance if `mod_name` is `None`.
+pub(crate) fn record_vm_memory_pages(vm: &Vm, mod_name: Option<&str>) {
+    let instance = match mod_name {
+        Some(name) => vm.store_mut().and_then(|mut store| store.module(name)),
+        None => vm.active_module(),
+    };
+    if let Ok(instance) = instance {
+        vm.memory_pages
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .entry(mod_name.map(ToString::to_string))
+            .or_default()
+            .update(memory_pages(&instance));
+    }
+}
+
+/// Releases the pages of the exported memories of a module instance owned by a [Vm](crate::Vm), which is destroyed.
+pub(crate) fn release_vm_memory_pages(vm: &Vm, mod_name: Option<&str>) {
+    vm.memory_pages
+        .lock()
+        .expect("[wasmedge-sys] try lock failed.")
+        .remove(&mod_name.map(ToString::to_string));
+}
+
+/// Records the metrics of a WASM function execution in a [Vm](crate::Vm).
+pub(crate) struct ExecutionRecorder {
+    instr_count: u64,
+}
+impl ExecutionRecorder {
+    /// Starts recording an execution.
+    pub(crate) fn start(vm: &Vm) -> Self {
+        Self {
+            instr_count: instr_count(vm),
+        }
+    }
+
+    /// Finishes recording an execution of a function hosted by the given module, which is the anonymous module if
+    /// `mod_name` is `None`.
+    ///
+    /// The memory pages are sampled only if the latest sampling in the [Vm](crate::Vm) is older than
+    /// [MEMORY_SAMPLE_INTERVAL], so that the store and the memories are not looked up for every execution.
+    pub(crate) fn finish<T>(self, vm: &Vm, mod_name: Option<&str>, result: &WasmEdgeResult<T>) {
+        record_execution(result);
+        ::metrics::counter!(
+            INSTRUCTIONS,
+            instr_count(vm).saturating_sub(self.instr_count)
+        );
+
+        if should_sample(&vm.memory_sampled_at) {
+            record_vm_memory_pages(vm, mod_name);
+        }
+    }
+}
+
+/// Returns `true` if the latest sampling time stored in `sampled_at` is older than [MEMORY_SAMPLE_INTERVAL], and
+/// stores the current time. `0` means never sampled.
+fn should_sample(sampled_at: &AtomicU64) -> bool {
+    let now = EPOCH.elapsed().as_nanos() as u64 + 1;
+    let last = sampled_at.load(Ordering::Relaxed);
+    if last != 0 && now - last < MEMORY_SAMPLE_INTERVAL.as_nanos() as u64 {
+        return false;
+    }
+    sampled_at
+        .compare_exchange(last, now, Ordering::Relaxed, Ordering::Relaxed)
+        .is_ok()
+}
+
+/// Returns the number of the instructions executed in the [Vm](crate::Vm).
+fn instr_count(vm: &Vm) -> u64 {
+    unsafe {
+        let stat_ctx = ffi::WasmEdge_VMGetStatisticsContext(vm.inner.0);
+        ffi::WasmEdge_StatisticsGetInstrCount(stat_ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{CoreExecutionError, CoreLoadError},
+        Config, Engine, Executor, Loader, Store, Validator, WasmValue,
+    };
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+    use std::collections::HashMap;
+
+    /// Returns the metrics recorded in this thread, keyed by the names and the labels.
+    fn snapshot() -> HashMap<String, DebugValue> {
+        let result = Snapshotter::current_thread_snapshot();
+        assert!(result.is_some());
+        result
+            .unwrap()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| {
+                let (_, key) = key.into_parts();
+                let labels = key
+                    .labels()
+                    .map(|label| format!("{}={}", label.key(), label.value()))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                (format!("{}{{{}}}", key.name(), labels), value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_metrics_trap_kind() {
+        let err = WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::DivideByZero));
+        assert_eq!(trap_kind(&err).as_deref(), Some("DivideByZero"));
+        assert_eq!(status::<()>(&Err(err)), "trap");
+
+        let err = WasmEdgeError::Core(CoreError::Load(CoreLoadError::MalformedMagic));
+        assert_eq!(trap_kind(&err), None);
+        assert_eq!(status::<()>(&Err(err)), "error");
+        assert_eq!(status(&Ok(())), "ok");
+    }
+
+    #[test]
+    fn test_metrics_vm() {
+        // the metrics of this thread are recorded separately from the other tests
+        let _ = DebuggingRecorder::per_thread().install();
+
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.count_instructions(true);
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let wasm = br#"
+            (module
+                (memory (export "memory") 2)
+                (func (export "div") (param i32) (result i32)
+                    i32.const 1
+                    local.get 0
+                    i32.div_s)
+            )
+        "#;
+        let result = vm.run_wasm_from_bytes(wasm, "div", [WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        let result = vm.run_function("div", [WasmValue::from_i32(0)]);
+        assert!(result.is_err());
+
+        let metrics = snapshot();
+        assert_eq!(
+            metrics.get("wasmedge_instantiations_total{status=ok}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert!(matches!(
+            metrics.get("wasmedge_instantiation_duration_seconds{status=ok}"),
+            Some(DebugValue::Histogram(values)) if values.len() == 1
+        ));
+        assert_eq!(
+            metrics.get("wasmedge_executions_total{status=ok}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert_eq!(
+            metrics.get("wasmedge_executions_total{status=trap}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert_eq!(
+            metrics.get("wasmedge_traps_total{kind=DivideByZero}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert!(matches!(
+            metrics.get("wasmedge_instructions_total{}"),
+            Some(DebugValue::Counter(count)) if *count > 0
+        ));
+        assert_eq!(
+            metrics.get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(2.0.into()))
+        );
+
+        // the pages of the module instances are accounted across the vms
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut other = result.unwrap();
+        let result =
+            other.register_wasm_from_bytes("mem", br#"(module (memory (export "memory") 3))"#);
+        assert!(result.is_ok());
+        assert_eq!(
+            snapshot().get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(5.0.into()))
+        );
+        let result = other.unregister_module("mem");
+        assert!(result.is_ok());
+        assert_eq!(
+            snapshot().get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(2.0.into()))
+        );
+        let result = other.run_wasm_from_bytes(wasm, "div", [WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        assert_eq!(
+            snapshot().get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(4.0.into()))
+        );
+        vm.reset();
+        drop(other);
+        assert_eq!(
+            snapshot().get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(0.0.into()))
+        );
+    }
+
+    #[test]
+    fn test_metrics_executor() {
+        // the metrics of this thread are recorded separately from the other tests
+        let _ = DebuggingRecorder::per_thread().install();
+
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_wat(
+            r#"
+            (module
+                (memory (export "memory") 3)
+                (func (export "div") (param i32) (result i32)
+                    i32.const 1
+                    local.get 0
+                    i32.div_s)
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        assert!(result.unwrap().validate(&module).is_ok());
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_named_module(&mut store, &module, "math");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.get_func("div");
+        assert!(result.is_ok());
+        let func = result.unwrap();
+        assert!(executor.run_func(&func, [WasmValue::from_i32(1)]).is_ok());
+        assert!(executor.run_func(&func, [WasmValue::from_i32(0)]).is_err());
+
+        let metrics = snapshot();
+        assert_eq!(
+            metrics.get("wasmedge_instantiations_total{status=ok}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert_eq!(
+            metrics.get("wasmedge_executions_total{status=ok}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert_eq!(
+            metrics.get("wasmedge_traps_total{kind=DivideByZero}"),
+            Some(&DebugValue::Counter(1))
+        );
+        assert_eq!(
+            metrics.get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(3.0.into()))
+        );
+
+        // the pages are released when the instance is dropped
+        drop(instance);
+        assert_eq!(
+            snapshot().get("wasmedge_memory_pages{}"),
+            Some(&DebugValue::Gauge(0.0.into()))
+        );
+    }
+}
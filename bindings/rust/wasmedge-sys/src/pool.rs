@@ -0,0 +1,801 @@
+//! Defines WasmEdge VmPool struct.
+
+use crate::{
+    error::{PoolError, WasmEdgeError},
+    Config, Module, Vm, WasmEdgeResult, WasmValue,
+};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{atomic::Ordering, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+use wasmedge_types::Mutability;
+
+/// Defines the strategies to reset a [Vm](crate::Vm) when it is returned to a [VmPool].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetPolicy {
+    /// Instantiates the module again from the validated module kept in the [Vm](crate::Vm), without loading or validating it again. All the state of the module instance is reset.
+    Reinstantiate,
+    /// Restores the contents of the exported memories and the values of the exported mutable globals to the ones right after the instantiation. If an exported memory has grown, the [Vm](crate::Vm) is re-instantiated instead.
+    ///
+    /// This is much cheaper than re-instantiation, but the state which is not exported, for example, an internal global or a table, is not reset.
+    RestoreMemory,
+}
+
+/// Defines the metrics of a [VmPool].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolMetrics {
+    /// The maximum number of [Vms](crate::Vm) in the pool.
+    pub capacity: usize,
+    /// The number of [Vms](crate::Vm) which are ready to lease.
+    pub idle: usize,
+    /// The number of [Vms](crate::Vm) which are leased.
+    pub in_use: usize,
+    /// The total number of [Vms](crate::Vm) which have been created.
+    pub created: u64,
+    /// The total number of leases.
+    pub leases: u64,
+    /// The number of leases which have waited for a [Vm](crate::Vm) to be returned.
+    pub waits: u64,
+    /// The total time the leases have waited for a [Vm](crate::Vm) to be returned.
+    pub wait_time: Duration,
+    /// The number of times the exported memories and globals are restored.
+    pub restores: u64,
+    /// The number of times a [Vm](crate::Vm) is re-instantiated.
+    pub reinstantiations: u64,
+    /// The number of [Vms](crate::Vm) which are discarded since they fail to reset.
+    pub discarded: u64,
+}
+
+/// A [VmPool] keeps a bounded number of [Vms](crate::Vm), in which a [module](crate::Module) is loaded and instantiated, ready to lease.
+///
+/// A [Vm](crate::Vm) is leased with [get](crate::VmPool::get) or [try_get](crate::VmPool::try_get) as a [PooledVm], and is returned to the pool when the [PooledVm] is dropped. Before a returned [Vm](crate::Vm) is leased again, its state is reset according to the [ResetPolicy], and the WASI module is always initialized again if it is enabled in the [Config](crate::Config). Regardless of the [ResetPolicy], the [Statistics](crate::Statistics) are cleared, the cost limit is restored to the [one of the pool](crate::VmPool::set_cost_limit), and the out-of-fuel handler is removed. A [Vm](crate::Vm) which fails to reset is discarded, and a new one is created on demand.
+///
+/// A [VmPool] is `Send + Sync`, so it can be shared by worker threads.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sys::{Loader, ResetPolicy, VmPool, WasmValue};
+/// use wasmedge_types::wat2wasm;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let wasm_bytes = wat2wasm(
+///         br#"
+///         (module
+///             (global $count (export "count") (mut i32) (i32.const 0))
+///             (func (export "inc") (result i32)
+///                 (global.set $count (i32.add (global.get $count) (i32.const 1)))
+///                 (global.get $count))
+///         )
+///         "#,
+///     )?;
+///     let module = Loader::create(None)?.from_bytes(&wasm_bytes)?;
+///
+///     let mut pool = VmPool::create(None, module, 2)?;
+///     pool.set_reset_policy(ResetPolicy::RestoreMemory)?;
+///
+///     for _ in 0..3 {
+///         let vm = pool.get()?;
+///         let returns = vm.run_function("inc", [])?;
+///         assert_eq!(returns[0].to_i32(), 1);
+///     }
+///
+///     let metrics = pool.metrics();
+///     assert_eq!(metrics.created, 1);
+///     assert_eq!(metrics.leases, 3);
+///     assert_eq!(metrics.restores, 3);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct VmPool {
+    config: Option<Config>,
+    module: Module,
+    capacity: usize,
+    policy: ResetPolicy,
+    wasi: Option<WasiParams>,
+    cost_limit: u64,
+    state: Mutex<PoolState>,
+    available: Condvar,
+}
+impl VmPool {
+    /// Creates a new [VmPool], and creates the first [Vm](crate::Vm) in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The optional [Config](crate::Config) used to create the [Vms](crate::Vm).
+    ///
+    /// * `module` - The [module](crate::Module) to load and instantiate in the [Vms](crate::Vm).
+    ///
+    /// * `capacity` - The maximum number of [Vms](crate::Vm) in the pool.
+    ///
+    /// # Error
+    ///
+    /// If the capacity is zero, or fail to create the first [Vm](crate::Vm), then an error is returned.
+    pub fn create(config: Option<Config>, module: Module, capacity: usize) -> WasmEdgeResult<Self> {
+        if capacity == 0 {
+            return Err(WasmEdgeError::Pool(PoolError::ZeroCapacity));
+        }
+
+        let pool = Self {
+            config,
+            module,
+            capacity,
+            policy: ResetPolicy::Reinstantiate,
+            wasi: None,
+            cost_limit: u64::MAX,
+            state: Mutex::new(PoolState::default()),
+            available: Condvar::new(),
+        };
+
+        // create the first vm to fail early
+        let entry = pool.create_entry()?;
+        let mut state = pool.state.lock().expect("[wasmedge-sys] try lock failed.");
+        state.created += 1;
+        state.idle.push(entry);
+        drop(state);
+
+        Ok(pool)
+    }
+
+    /// Sets the [ResetPolicy] of the pool. The default is [ResetPolicy::Reinstantiate].
+    ///
+    /// # Error
+    ///
+    /// If fail to take the snapshots of the idle [Vms](crate::Vm) for [ResetPolicy::RestoreMemory], then an error is returned.
+    pub fn set_reset_policy(&mut self, policy: ResetPolicy) -> WasmEdgeResult<()> {
+        self.policy = policy;
+        if policy == ResetPolicy::RestoreMemory {
+            let state = self
+                .state
+                .get_mut()
+                .expect("[wasmedge-sys] try lock failed.");
+            for entry in state.idle.iter_mut() {
+                if entry.snapshot.is_none() {
+                    entry.snapshot = Some(Snapshot::take(&entry.vm)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the parameters with which the WASI module of every [Vm](crate::Vm) is initialized and re-initialized between leases.
+    ///
+    /// Notice that the parameters are effective only if the [wasi](crate::Config::wasi) option is enabled in the [Config](crate::Config) of the pool. If they are not set, the WASI module is initialized without any arguments, environment variables or pre-opened directories.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The commandline arguments. The first argument is the program name.
+    ///
+    /// * `envs` - The environment variables in the format `ENV_VAR_NAME=VALUE`.
+    ///
+    /// * `preopens` - The directories to pre-open. The required format is `DIR1:DIR2`.
+    pub fn set_wasi(
+        &mut self,
+        args: Option<Vec<&str>>,
+        envs: Option<Vec<&str>>,
+        preopens: Option<Vec<&str>>,
+    ) -> WasmEdgeResult<()> {
+        let to_owned = |v: Option<Vec<&str>>| {
+            v.unwrap_or_default()
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        self.wasi = Some(WasiParams {
+            args: to_owned(args),
+            envs: to_owned(envs),
+            preopens: to_owned(preopens),
+        });
+
+        // re-initialize the idle vms with the new parameters
+        let mut state = self.state.lock().expect("[wasmedge-sys] try lock failed.");
+        for entry in state.idle.iter_mut() {
+            self.init_wasi(&mut entry.vm)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the cost limit with which every lease starts. The default is `u64::MAX`, which means no limit.
+    ///
+    /// Notice that the cost is measured only if the [cost measuring](crate::Config::measure_cost) option is enabled in the [Config](crate::Config) of the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The cost limit.
+    ///
+    /// # Error
+    ///
+    /// If fail to get the [Statistics](crate::Statistics) of an idle [Vm](crate::Vm), then an error is returned.
+    pub fn set_cost_limit(&mut self, limit: u64) -> WasmEdgeResult<()> {
+        self.cost_limit = limit;
+        let state = self
+            .state
+            .get_mut()
+            .expect("[wasmedge-sys] try lock failed.");
+        for entry in state.idle.iter_mut() {
+            entry.vm.statistics_mut()?.set_cost_limit(limit);
+        }
+        Ok(())
+    }
+
+    /// Returns the cost limit with which every lease starts.
+    pub fn cost_limit(&self) -> u64 {
+        self.cost_limit
+    }
+
+    /// Returns the [module](crate::Module) instantiated in the [Vms](crate::Vm).
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Returns the maximum number of [Vms](crate::Vm) in the pool.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Leases a [Vm](crate::Vm) from the pool. If all the [Vms](crate::Vm) are leased and the pool is full, it blocks until one is returned.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a new [Vm](crate::Vm), then an error is returned.
+    pub fn get(&self) -> WasmEdgeResult<PooledVm<'_>> {
+        let mut state = self.state.lock().expect("[wasmedge-sys] try lock failed.");
+        let mut waited_since = None;
+        while state.idle.is_empty() && state.created_alive() >= self.capacity {
+            waited_since.get_or_insert_with(Instant::now);
+            state = self
+                .available
+                .wait(state)
+                .expect("[wasmedge-sys] try lock failed.");
+        }
+        if let Some(since) = waited_since {
+            state.waits += 1;
+            state.wait_time += since.elapsed();
+        }
+
+        match state.idle.pop() {
+            Some(entry) => Ok(self.lease(state, entry)),
+            None => self.lease_new(state),
+        }
+    }
+
+    /// Leases a [Vm](crate::Vm) from the pool without blocking. If all the [Vms](crate::Vm) are leased and the pool is full, `None` is returned.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a new [Vm](crate::Vm), then an error is returned.
+    pub fn try_get(&self) -> WasmEdgeResult<Option<PooledVm<'_>>> {
+        let mut state = self.state.lock().expect("[wasmedge-sys] try lock failed.");
+        if let Some(entry) = state.idle.pop() {
+            return Ok(Some(self.lease(state, entry)));
+        }
+
+        match state.created_alive() < self.capacity {
+            true => self.lease_new(state).map(Some),
+            false => Ok(None),
+        }
+    }
+
+    /// Returns the current metrics of the pool.
+    pub fn metrics(&self) -> PoolMetrics {
+        let state = self.state.lock().expect("[wasmedge-sys] try lock failed.");
+        PoolMetrics {
+            capacity: self.capacity,
+            idle: state.idle.len(),
+            in_use: state.in_use,
+            created: state.created,
+            leases: state.leases,
+            waits: state.waits,
+            wait_time: state.wait_time,
+            restores: state.restores,
+            reinstantiations: state.reinstantiations,
+            discarded: state.discarded,
+        }
+    }
+
+    fn lease(&self, mut state: MutexGuard<'_, PoolState>, entry: PoolEntry) -> PooledVm<'_> {
+        state.leases += 1;
+        state.in_use += 1;
+        PooledVm {
+            pool: self,
+            entry: Some(entry),
+        }
+    }
+
+    /// Creates a new vm for a lease. The slot is reserved before the lock is released, so that the pool never exceeds its capacity.
+    fn lease_new(&self, mut state: MutexGuard<'_, PoolState>) -> WasmEdgeResult<PooledVm<'_>> {
+        state.in_use += 1;
+        drop(state);
+
+        let result = self.create_entry();
+        let mut state = self.state.lock().expect("[wasmedge-sys] try lock failed.");
+        state.in_use -= 1;
+        match result {
+            Ok(entry) => {
+                state.created += 1;
+                Ok(self.lease(state, entry))
+            }
+            Err(err) => {
+                drop(state);
+                self.available.notify_one();
+                Err(err)
+            }
+        }
+    }
+
+    fn create_entry(&self) -> WasmEdgeResult<PoolEntry> {
+        let config = match &self.config {
+            Some(config) => Some(Config::copy_from(config)?),
+            None => None,
+        };
+        let mut vm = Vm::create(config, None)?;
+        self.instantiate(&mut vm)?;
+        self.init_wasi(&mut vm)?;
+        vm.statistics_mut()?.set_cost_limit(self.cost_limit);
+        let snapshot = match self.policy {
+            ResetPolicy::Reinstantiate => None,
+            ResetPolicy::RestoreMemory => Some(Snapshot::take(&vm)?),
+        };
+        Ok(PoolEntry { vm, snapshot })
+    }
+
+    fn instantiate(&self, vm: &mut Vm) -> WasmEdgeResult<()> {
+        vm.load_wasm_from_module(&self.module)?;
+        vm.validate()?;
+        vm.instantiate()
+    }
+
+    fn init_wasi(&self, vm: &mut Vm) -> WasmEdgeResult<()> {
+        let wasi_enabled = matches!(&self.config, Some(config) if config.wasi_enabled());
+        if wasi_enabled {
+            // the WASI state of the previous lease, such as the opened files, must not leak into the next one.
+            let default = WasiParams::default();
+            let params = self.wasi.as_ref().unwrap_or(&default);
+            vm.wasi_module_mut()?.init_wasi(
+                Some(as_strs(&params.args)),
+                Some(as_strs(&params.envs)),
+                Some(as_strs(&params.preopens)),
+            );
+        }
+        Ok(())
+    }
+
+    /// Resets a returned vm. Returns if the vm is re-instantiated.
+    fn reset(&self, entry: &mut PoolEntry) -> WasmEdgeResult<bool> {
+        let restored = match (self.policy, &entry.snapshot) {
+            (ResetPolicy::RestoreMemory, Some(snapshot)) => snapshot.restore(&entry.vm)?,
+            _ => false,
+        };
+        if !restored {
+            // the vm keeps the validated module, so it is only instantiated again.
+            entry.vm.instantiate()?;
+        }
+        self.init_wasi(&mut entry.vm)?;
+        self.reset_statistics(&entry.vm)?;
+        Ok(!restored)
+    }
+
+    /// Resets the counters, the cost limit and the out-of-fuel handler of a returned vm, so that the billing state of
+    /// a lease never leaks into the next one.
+    fn reset_statistics(&self, vm: &Vm) -> WasmEdgeResult<()> {
+        let mut stat = vm.statistics_mut()?;
+        stat.clear();
+        stat.set_cost_limit(self.cost_limit);
+        stat.clear_out_of_fuel();
+        vm.fuel_consumed.store(0, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn release(&self, mut entry: PoolEntry) {
+        let result = self.reset(&mut entry);
+
+        let mut state = self.state.lock().expect("[wasmedge-sys] try lock failed.");
+        state.in_use -= 1;
+        match result {
+            Ok(true) => {
+                state.reinstantiations += 1;
+                state.idle.push(entry);
+            }
+            Ok(false) => {
+                state.restores += 1;
+                state.idle.push(entry);
+            }
+            Err(_) => state.discarded += 1,
+        }
+        drop(state);
+        self.available.notify_one();
+    }
+}
+
+/// Defines a [Vm](crate::Vm) leased from a [VmPool], which is returned to the pool when dropped.
+#[derive(Debug)]
+pub struct PooledVm<'pool> {
+    pool: &'pool VmPool,
+    entry: Option<PoolEntry>,
+}
+impl<'pool> Deref for PooledVm<'pool> {
+    type Target = Vm;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entry.as_ref().expect("the vm should be there").vm
+    }
+}
+impl<'pool> DerefMut for PooledVm<'pool> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entry.as_mut().expect("the vm should be there").vm
+    }
+}
+impl<'pool> Drop for PooledVm<'pool> {
+    fn drop(&mut self) {
+        if let Some(entry) = self.entry.take() {
+            self.pool.release(entry);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PoolState {
+    idle: Vec<PoolEntry>,
+    in_use: usize,
+    created: u64,
+    leases: u64,
+    waits: u64,
+    wait_time: Duration,
+    restores: u64,
+    reinstantiations: u64,
+    discarded: u64,
+}
+impl PoolState {
+    /// Returns the number of vms which are not discarded.
+    fn created_alive(&self) -> usize {
+        self.idle.len() + self.in_use
+    }
+}
+
+#[derive(Debug)]
+struct PoolEntry {
+    vm: Vm,
+    snapshot: Option<Snapshot>,
+}
+
+#[derive(Debug, Default)]
+struct WasiParams {
+    args: Vec<String>,
+    envs: Vec<String>,
+    preopens: Vec<String>,
+}
+
+fn as_strs(v: &[String]) -> Vec<&str> {
+    v.iter().map(String::as_str).collect()
+}
+
+/// The size of a WebAssembly page in bytes.
+const PAGE_SIZE: u32 = 65536;
+/// The maximum number of pages copied by a single call, so that the length in bytes never overflows.
+const CHUNK_PAGES: u32 = 256;
+
+/// The contents of the exported memories and the values of the exported mutable globals right after the instantiation.
+#[derive(Debug, Default)]
+struct Snapshot {
+    memories: Vec<(String, u32, Vec<u8>)>,
+    globals: Vec<(String, WasmValue)>,
+}
+impl Snapshot {
+    fn take(vm: &Vm) -> WasmEdgeResult<Self> {
+        let instance = vm.active_module()?;
+        let mut snapshot = Self::default();
+        for name in instance.mem_names().unwrap_or_default() {
+            let memory = instance.get_memory(&name)?;
+            let pages = memory.size();
+            let mut data = Vec::with_capacity(pages as usize * PAGE_SIZE as usize);
+            for page in (0..pages).step_by(CHUNK_PAGES as usize) {
+                let len = (pages - page).min(CHUNK_PAGES) * PAGE_SIZE;
+                data.extend(memory.get_data(page * PAGE_SIZE, len)?);
+            }
+            snapshot.memories.push((name, pages, data));
+        }
+        for name in instance.global_names().unwrap_or_default() {
+            let global = instance.get_global(&name)?;
+            if global.ty()?.mutability() == Mutability::Var {
+                snapshot.globals.push((name, global.get_value()));
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Restores the snapshot into the active module of the vm. Returns `false` if any memory has grown, in which case nothing is restored.
+    fn restore(&self, vm: &Vm) -> WasmEdgeResult<bool> {
+        let instance = vm.active_module()?;
+        let mut memories = Vec::with_capacity(self.memories.len());
+        for (name, pages, _) in self.memories.iter() {
+            let memory = instance.get_memory(name)?;
+            if memory.size() != *pages {
+                return Ok(false);
+            }
+            memories.push(memory);
+        }
+
+        let chunk_len = (CHUNK_PAGES * PAGE_SIZE) as usize;
+        for (mut memory, (_, _, data)) in memories.into_iter().zip(self.memories.iter()) {
+            for (chunk, offset) in data.chunks(chunk_len).zip((0..).step_by(chunk_len)) {
+                memory.set_data(chunk.iter().copied(), offset)?;
+            }
+        }
+        for (name, value) in self.globals.iter() {
+            instance.get_global(name)?.set_value(value.clone())?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{CoreCommonError, CoreError},
+        Loader,
+    };
+    use std::{
+        sync::{atomic::AtomicU32, mpsc, Arc},
+        thread,
+    };
+    use wasmedge_types::wat2wasm;
+
+    const WAT: &str = r#"
+        (module
+            (memory (export "memory") 1 2)
+            (data (i32.const 0) "\01")
+            (global $count (export "count") (mut i32) (i32.const 0))
+            (func (export "inc") (result i32)
+                (i32.store8 (i32.const 0)
+                    (i32.add (i32.load8_u (i32.const 0)) (i32.const 1)))
+                (global.set $count (i32.add (global.get $count) (i32.const 1)))
+                (i32.add (global.get $count) (i32.load8_u (i32.const 0))))
+            (func (export "grow") (result i32)
+                (memory.grow (i32.const 1)))
+        )
+    "#;
+
+    #[test]
+    fn test_pool_lease() {
+        let result = VmPool::create(None, load(), 0);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Pool(PoolError::ZeroCapacity)
+        );
+
+        let result = VmPool::create(None, load(), 2);
+        assert!(result.is_ok());
+        let pool = result.unwrap();
+        assert_eq!(pool.capacity(), 2);
+        assert_eq!(pool.metrics().created, 1);
+        assert_eq!(pool.metrics().idle, 1);
+
+        {
+            let result = pool.get();
+            assert!(result.is_ok());
+            let vm1 = result.unwrap();
+            let result = pool.try_get();
+            assert!(result.is_ok());
+            let vm2 = result.unwrap();
+            assert!(vm2.is_some());
+            let vm2 = vm2.unwrap();
+
+            // the pool is full
+            let result = pool.try_get();
+            assert!(result.is_ok());
+            assert!(result.unwrap().is_none());
+
+            let metrics = pool.metrics();
+            assert_eq!(metrics.created, 2);
+            assert_eq!(metrics.idle, 0);
+            assert_eq!(metrics.in_use, 2);
+
+            // the vms have their own state
+            let result = vm1.run_function("inc", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 3);
+            let result = vm1.run_function("inc", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 5);
+            let result = vm2.run_function("inc", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 3);
+        }
+
+        // the state is reset
+        for _ in 0..2 {
+            let result = pool.get();
+            assert!(result.is_ok());
+            let vm = result.unwrap();
+            let result = vm.run_function("inc", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 3);
+        }
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.created, 2);
+        assert_eq!(metrics.idle, 2);
+        assert_eq!(metrics.in_use, 0);
+        assert_eq!(metrics.leases, 4);
+        assert_eq!(metrics.reinstantiations, 4);
+        assert_eq!(metrics.restores, 0);
+        assert_eq!(metrics.discarded, 0);
+    }
+
+    #[test]
+    fn test_pool_restore_memory() {
+        let result = VmPool::create(None, load(), 1);
+        assert!(result.is_ok());
+        let mut pool = result.unwrap();
+        let result = pool.set_reset_policy(ResetPolicy::RestoreMemory);
+        assert!(result.is_ok());
+
+        for _ in 0..3 {
+            let result = pool.get();
+            assert!(result.is_ok());
+            let vm = result.unwrap();
+            let result = vm.run_function("inc", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 3);
+        }
+        assert_eq!(pool.metrics().restores, 3);
+        assert_eq!(pool.metrics().reinstantiations, 0);
+
+        // a grown memory is reset by re-instantiation
+        {
+            let result = pool.get();
+            assert!(result.is_ok());
+            let vm = result.unwrap();
+            let result = vm.run_function("grow", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 1);
+        }
+        assert_eq!(pool.metrics().reinstantiations, 1);
+
+        let result = pool.get();
+        assert!(result.is_ok());
+        let vm = result.unwrap();
+        let result = vm.active_module();
+        assert!(result.is_ok());
+        let result = result.unwrap().get_memory("memory");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().size(), 1);
+    }
+
+    #[test]
+    fn test_pool_reset_statistics() {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.measure_cost(true);
+        let result = VmPool::create(Some(config), load(), 1);
+        assert!(result.is_ok());
+        let mut pool = result.unwrap();
+        let result = pool.set_cost_limit(1_000_000);
+        assert!(result.is_ok());
+        assert_eq!(pool.cost_limit(), 1_000_000);
+
+        for policy in [ResetPolicy::Reinstantiate, ResetPolicy::RestoreMemory] {
+            let result = pool.set_reset_policy(policy);
+            assert!(result.is_ok());
+            let refuels = Arc::new(AtomicU32::new(0));
+
+            // a lease sets its own limit and handler
+            {
+                let result = pool.get();
+                assert!(result.is_ok());
+                let vm = result.unwrap();
+                let result = vm.statistics_mut();
+                assert!(result.is_ok());
+                let mut stat = result.unwrap();
+                assert_eq!(stat.cost_limit(), 1_000_000);
+                stat.set_cost_limit(1);
+                let refuels_cloned = Arc::clone(&refuels);
+                stat.on_out_of_fuel(move |_| {
+                    refuels_cloned.fetch_add(1, Ordering::Relaxed);
+                    100
+                });
+                let result = vm.run_function("inc", []);
+                assert!(result.is_ok());
+                assert!(refuels.load(Ordering::Relaxed) > 0);
+                assert!(vm.fuel_consumed() > 0);
+            }
+
+            // the next lease sees a clean state
+            let result = pool.get();
+            assert!(result.is_ok());
+            let vm = result.unwrap();
+            assert_eq!(vm.fuel_consumed(), 0);
+            let result = vm.statistics_mut();
+            assert!(result.is_ok());
+            let mut stat = result.unwrap();
+            assert_eq!(stat.instr_count(), 0);
+            assert_eq!(stat.cost_in_total(), 0);
+            assert_eq!(stat.cost_limit(), 1_000_000);
+
+            // the handler of the previous lease is not called
+            let refueled = refuels.load(Ordering::Relaxed);
+            stat.set_cost_limit(1);
+            let result = vm.run_function("inc", []);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err(),
+                WasmEdgeError::Core(CoreError::Common(CoreCommonError::CostLimitExceeded))
+            );
+            assert_eq!(refuels.load(Ordering::Relaxed), refueled);
+        }
+    }
+
+    #[test]
+    fn test_pool_wait() {
+        let result = VmPool::create(None, load(), 1);
+        assert!(result.is_ok());
+        let pool = Arc::new(result.unwrap());
+
+        let handles = (0..4)
+            .map(|_| {
+                let pool = Arc::clone(&pool);
+                thread::spawn(move || {
+                    for _ in 0..5 {
+                        let result = pool.get();
+                        assert!(result.is_ok());
+                        let vm = result.unwrap();
+                        let result = vm.run_function("inc", []);
+                        assert!(result.is_ok());
+                        assert_eq!(result.unwrap()[0].to_i32(), 3);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert!(handle.join().is_ok());
+        }
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.created, 1);
+        assert_eq!(metrics.leases, 20);
+        assert_eq!(metrics.in_use, 0);
+
+        // a lease waits until the only vm is returned
+        let waits = metrics.waits;
+        let wait_time = metrics.wait_time;
+        let result = pool.get();
+        assert!(result.is_ok());
+        let vm = result.unwrap();
+        let (tx, rx) = mpsc::channel();
+        let handle = {
+            let pool = Arc::clone(&pool);
+            thread::spawn(move || {
+                tx.send(()).unwrap();
+                let result = pool.get();
+                assert!(result.is_ok());
+            })
+        };
+        assert!(rx.recv().is_ok());
+        thread::sleep(Duration::from_millis(100));
+        drop(vm);
+        assert!(handle.join().is_ok());
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.leases, 22);
+        assert_eq!(metrics.waits, waits + 1);
+        assert!(metrics.wait_time > wait_time);
+    }
+
+    fn load() -> Module {
+        let result = wat2wasm(WAT.as_bytes());
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_bytes(wasm_bytes);
+        assert!(result.is_ok());
+        result.unwrap()
+    }
+}
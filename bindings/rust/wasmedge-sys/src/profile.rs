@@ -0,0 +1,217 @@
+//! Defines WasmEdge Profile struct.
+
+use std::{collections::HashMap, fmt::Write, time::Duration};
+
+/// Defines the metrics recorded in a [Profile].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileMetric {
+    /// The number of the executed instructions.
+    Instructions,
+    /// The cost of the executed instructions.
+    Cost,
+    /// The execution time in nanoseconds.
+    Time,
+}
+
+/// A function in a profiled call path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEntry {
+    /// The index of the entry of the caller. `None` for the outermost functions.
+    pub parent: Option<usize>,
+    /// The name of the module instance. `None` for the anonymous module instance.
+    pub module_name: Option<String>,
+    /// The index of the function in the module instance, including the imported functions.
+    pub func_index: u32,
+    /// The name of the function recorded in the name section of the module.
+    pub func_name: Option<String>,
+    /// Whether the function is a host function.
+    pub is_host: bool,
+    /// The number of the instructions executed in the function itself. Always `0` for host functions and AOT compiled
+    /// functions.
+    pub instr_count: u64,
+    /// The cost of the instructions executed in the function itself.
+    pub cost: u64,
+    /// The execution time of the function itself, excluding its callees.
+    pub time: Duration,
+}
+impl ProfileEntry {
+    fn metric(&self, metric: ProfileMetric) -> u64 {
+        match metric {
+            ProfileMetric::Instructions => self.instr_count,
+            ProfileMetric::Cost => self.cost,
+            ProfileMetric::Time => self.time.as_nanos() as u64,
+        }
+    }
+}
+impl std::fmt::Display for ProfileEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}!", self.module_name.as_deref().unwrap_or("<module>"))?;
+        match &self.func_name {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "<func {}>", self.func_index),
+        }
+    }
+}
+
+/// The metrics of a function summed over all the call paths in a [Profile].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionProfile {
+    /// The name of the module instance. `None` for the anonymous module instance.
+    pub module_name: Option<String>,
+    /// The index of the function in the module instance, including the imported functions.
+    pub func_index: u32,
+    /// The name of the function recorded in the name section of the module.
+    pub func_name: Option<String>,
+    /// Whether the function is a host function.
+    pub is_host: bool,
+    /// The number of the instructions executed in the function itself.
+    pub instr_count: u64,
+    /// The cost of the instructions executed in the function itself.
+    pub cost: u64,
+    /// The execution time of the function itself, excluding its callees.
+    pub time: Duration,
+}
+
+/// Struct of WasmEdge Profile, which attributes the executed instructions, the cost and the execution time to the
+/// functions in each call path.
+///
+/// A [Profile] is taken from a [Statistics](crate::Statistics) with the profiling mode turned on, or from a
+/// [Vm](crate::Vm) with [Vm::profile](crate::Vm::profile), which also resolves the function names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub(crate) entries: Vec<ProfileEntry>,
+}
+impl Profile {
+    /// Returns the profiled functions of all the call paths. The entry of a caller is always before the entries of its
+    /// callees.
+    pub fn entries(&self) -> &[ProfileEntry] {
+        &self.entries
+    }
+
+    /// Returns `true` if no function has been profiled.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the flat profile, which sums the metrics of each function over all its call paths. The functions are
+    /// sorted by the execution time in descending order.
+    pub fn flat(&self) -> Vec<FunctionProfile> {
+        let mut indices: HashMap<(Option<&str>, u32), usize> = HashMap::new();
+        let mut functions: Vec<FunctionProfile> = Vec::new();
+        for entry in self.entries.iter() {
+            let key = (entry.module_name.as_deref(), entry.func_index);
+            let index = *indices.entry(key).or_insert_with(|| {
+                functions.push(FunctionProfile {
+                    module_name: entry.module_name.clone(),
+                    func_index: entry.func_index,
+                    func_name: entry.func_name.clone(),
+                    is_host: entry.is_host,
+                    instr_count: 0,
+                    cost: 0,
+                    time: Duration::ZERO,
+                });
+                functions.len() - 1
+            });
+            let function = &mut functions[index];
+            function.instr_count += entry.instr_count;
+            function.cost += entry.cost;
+            function.time += entry.time;
+        }
+        functions.sort_by(|a, b| {
+            b.time
+                .cmp(&a.time)
+                .then_with(|| b.instr_count.cmp(&a.instr_count))
+        });
+        functions
+    }
+
+    /// Returns the profile in the folded-stack format, which is accepted by the flamegraph tools.
+    ///
+    /// Each line is a call path of which the functions are separated by `;` from the outermost one, followed by the
+    /// value of the given metric of the innermost function. The call paths with a zero value are omitted.
+    ///
+    /// # Argument
+    ///
+    /// * `metric` - The metric to output.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let profile = vm.profile()?;
+    /// std::fs::write("wasm.folded", profile.folded(ProfileMetric::Time))?;
+    /// ```
+    pub fn folded(&self, metric: ProfileMetric) -> String {
+        let mut paths: Vec<String> = Vec::with_capacity(self.entries.len());
+        let mut folded = String::new();
+        for entry in self.entries.iter() {
+            let path = match entry.parent.and_then(|parent| paths.get(parent)) {
+                Some(parent) => format!("{};{}", parent, entry),
+                None => entry.to_string(),
+            };
+            let value = entry.metric(metric);
+            if value > 0 {
+                writeln!(folded, "{} {}", path, value).unwrap();
+            }
+            paths.push(path);
+        }
+        folded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(parent: Option<usize>, func_index: u32, func_name: &str, time: u64) -> ProfileEntry {
+        ProfileEntry {
+            parent,
+            module_name: None,
+            func_index,
+            func_name: Some(func_name.to_string()),
+            is_host: false,
+            instr_count: time * 2,
+            cost: time * 3,
+            time: Duration::from_nanos(time),
+        }
+    }
+
+    #[test]
+    fn test_profile_export() {
+        // main -> fib -> fib, main -> log
+        let mut profile = Profile {
+            entries: vec![
+                entry(None, 2, "main", 10),
+                entry(Some(0), 1, "fib", 20),
+                entry(Some(1), 1, "fib", 30),
+                entry(Some(0), 0, "log", 0),
+            ],
+        };
+        profile.entries[3].is_host = true;
+        profile.entries[3].module_name = Some(String::from("env"));
+        profile.entries[3].func_name = None;
+
+        let flat = profile.flat();
+        assert_eq!(flat.len(), 3);
+        assert_eq!(flat[0].func_name.as_deref(), Some("fib"));
+        assert_eq!(flat[0].time, Duration::from_nanos(50));
+        assert_eq!(flat[0].instr_count, 100);
+        assert_eq!(flat[0].cost, 150);
+        assert_eq!(flat[1].func_name.as_deref(), Some("main"));
+        assert_eq!(flat[2].module_name.as_deref(), Some("env"));
+        assert!(flat[2].is_host);
+
+        assert_eq!(
+            profile.folded(ProfileMetric::Time),
+            "<module>!main 10\n<module>!main;<module>!fib 20\n<module>!main;<module>!fib;<module>!fib 30\n"
+        );
+        assert_eq!(
+            profile.folded(ProfileMetric::Instructions).lines().last(),
+            Some("<module>!main;<module>!fib;<module>!fib 60")
+        );
+
+        profile.entries[3].cost = 15;
+        assert!(profile
+            .folded(ProfileMetric::Cost)
+            .contains("<module>!main;env!<func 0> 15\n"));
+    }
+}
@@ -1,6 +1,62 @@
 //! Defines WasmEdge Statistics struct.
 
-use crate::{error::WasmEdgeError, ffi, WasmEdgeResult};
+use crate::{
+    error::WasmEdgeError,
+    ffi,
+    profile::{Profile, ProfileEntry},
+    WasmEdgeResult,
+};
+use core::ffi::c_void;
+use std::{
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+/// Type alias for the handler of the exhausted fuel, which receives the fuel consumed so far and returns the fuel to add.
+///
+/// The handler has its own lock, so that it is called without holding the lock of the map of the handlers.
+type OutOfFuelFn = Arc<Mutex<dyn FnMut(u64) -> u64 + Send>>;
+
+lazy_static! {
+    /// The handlers of the exhausted fuel, keyed by the addresses of the statistics contexts.
+    static ref FUEL_HANDLERS: Mutex<HashMap<usize, OutOfFuelFn>> = Mutex::new(HashMap::new());
+}
+
+extern "C" fn refuel(data: *mut c_void, ctx: *mut ffi::WasmEdge_StatisticsContext) -> bool {
+    let key = data as usize;
+    // a panic must not unwind into the C++ frames of WasmEdge, so the execution is aborted instead
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let handler = match FUEL_HANDLERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            Some(handler) => Arc::clone(handler),
+            None => return false,
+        };
+        let mut handler = handler.lock().unwrap_or_else(PoisonError::into_inner);
+        let fuel = (*handler)(unsafe { ffi::WasmEdge_StatisticsGetTotalCost(ctx) });
+        if fuel == 0 {
+            return false;
+        }
+        unsafe {
+            let limit = ffi::WasmEdge_StatisticsGetCostLimit(ctx);
+            ffi::WasmEdge_StatisticsSetCostLimit(ctx, limit.saturating_add(fuel));
+        }
+        true
+    }))
+    .unwrap_or(false)
+}
+
+/// Removes the handler of the exhausted fuel registered for the given statistics context.
+pub(crate) fn remove_fuel_handler(ctx: *mut ffi::WasmEdge_StatisticsContext) {
+    FUEL_HANDLERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&(ctx as usize));
+}
 
 #[derive(Debug)]
 /// Struct of WasmEdge Statistics.
@@ -64,7 +120,17 @@ impl Statistics {
     ///
     /// # Arguments
     ///
-    /// * `cost_table` - The slice of cost table.
+    /// * `cost_table` - The slice of cost table, which is indexed by the internal opcode numbers of WasmEdge. Use a
+    ///   [CostTable](wasmedge_types::cost::CostTable) to build it by [Opcode](wasmedge_types::cost::Opcode) or by mnemonic.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use wasmedge_types::cost::{CostTable, Opcode};
+    ///
+    /// let table = CostTable::memory_expensive(1, 10).cost(Opcode::Call, 5);
+    /// stat.set_cost_table(&table);
+    /// ```
     pub fn set_cost_table(&mut self, cost_table: impl AsRef<[u64]>) {
         unsafe {
             ffi::WasmEdge_StatisticsSetCostTable(
@@ -84,7 +150,134 @@ impl Statistics {
         unsafe { ffi::WasmEdge_StatisticsSetCostLimit(self.inner.0, limit) }
     }
 
-    /// Clears the data in this statistics.
+    /// Returns the cost limit in execution. `u64::MAX` if no limit is set.
+    pub fn cost_limit(&self) -> u64 {
+        unsafe { ffi::WasmEdge_StatisticsGetCostLimit(self.inner.0) }
+    }
+
+    /// Returns the remaining fuel, which is the cost limit minus the total cost in execution.
+    pub fn remaining_fuel(&self) -> u64 {
+        self.cost_limit().saturating_sub(self.cost_in_total())
+    }
+
+    /// Adds fuel by raising the cost limit.
+    ///
+    /// The fuel is unlimited until a cost limit is set by [set_cost_limit](crate::Statistics::set_cost_limit).
+    ///
+    /// # Argument
+    ///
+    /// * `fuel` - The fuel to add.
+    pub fn add_fuel(&mut self, fuel: u64) {
+        let limit = self.cost_limit().saturating_add(fuel);
+        self.set_cost_limit(limit);
+    }
+
+    /// Sets the handler called when the fuel is exhausted.
+    ///
+    /// Instead of aborting the execution, the execution pauses and calls the handler with the fuel consumed so far.
+    /// The handler returns the fuel to add, then the execution resumes if the added fuel covers the cost of the current
+    /// instruction. If the handler returns `0`, then the execution is aborted with the `CostLimitExceeded` error.
+    ///
+    /// The handler is not called for the AOT compiled functions.
+    ///
+    /// # Argument
+    ///
+    /// * `handler` - The handler of the exhausted fuel.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // refuel twice, then abort the execution
+    /// let mut refills = 2;
+    /// stat.on_out_of_fuel(move |_consumed| match refills {
+    ///     0 => 0,
+    ///     _ => {
+    ///         refills -= 1;
+    ///         1000
+    ///     }
+    /// });
+    /// ```
+    pub fn on_out_of_fuel(&mut self, handler: impl FnMut(u64) -> u64 + Send + 'static) {
+        let key = self.inner.0 as usize;
+        FUEL_HANDLERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, Arc::new(Mutex::new(handler)));
+        unsafe {
+            ffi::WasmEdge_StatisticsSetCostLimitHandler(
+                self.inner.0,
+                Some(refuel),
+                key as *mut c_void,
+            )
+        }
+    }
+
+    /// Removes the handler of the exhausted fuel, so that the execution is aborted when the fuel is exhausted.
+    pub fn clear_out_of_fuel(&mut self) {
+        unsafe {
+            ffi::WasmEdge_StatisticsSetCostLimitHandler(self.inner.0, None, std::ptr::null_mut())
+        }
+        remove_fuel_handler(self.inner.0);
+    }
+
+    /// Turns on or off the profiling mode.
+    ///
+    /// In the profiling mode, the executed instructions, the cost and the execution time are attributed to the
+    /// functions in each call path, including the time spent in the host functions. The instructions of the AOT
+    /// compiled functions are not profiled.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether to turn on the profiling mode.
+    pub fn set_profiling(&mut self, enable: bool) {
+        unsafe { ffi::WasmEdge_StatisticsSetProfiling(self.inner.0, enable) }
+    }
+
+    /// Returns the profile recorded in the profiling mode.
+    ///
+    /// The function names are not resolved, use [Vm::profile](crate::Vm::profile) to resolve them from the name
+    /// sections of the modules.
+    pub fn profile(&self) -> Profile {
+        let len = unsafe { ffi::WasmEdge_StatisticsGetProfileLength(self.inner.0) };
+        let mut raw_entries = Vec::with_capacity(len as usize);
+        unsafe {
+            let len =
+                ffi::WasmEdge_StatisticsGetProfile(self.inner.0, raw_entries.as_mut_ptr(), len)
+                    .min(len);
+            raw_entries.set_len(len as usize);
+        }
+
+        let entries = raw_entries
+            .iter()
+            .map(|entry: &ffi::WasmEdge_ProfileEntry| {
+                let module_name = match entry.ModuleName.Length {
+                    0 => None,
+                    len => {
+                        let bytes = unsafe {
+                            std::slice::from_raw_parts(
+                                entry.ModuleName.Buf as *const u8,
+                                len as usize,
+                            )
+                        };
+                        Some(String::from_utf8_lossy(bytes).into_owned())
+                    }
+                };
+                ProfileEntry {
+                    parent: (entry.Parent != u32::MAX).then_some(entry.Parent as usize),
+                    module_name,
+                    func_index: entry.FunctionIndex,
+                    func_name: None,
+                    is_host: entry.IsHost,
+                    instr_count: entry.InstrCount,
+                    cost: entry.Cost,
+                    time: Duration::from_nanos(entry.Time),
+                }
+            })
+            .collect();
+        Profile { entries }
+    }
+
+    /// Clears the data in this statistics, including the profile.
     pub fn clear(&mut self) {
         unsafe { ffi::WasmEdge_StatisticsClear(self.inner.0) }
     }
@@ -92,6 +285,7 @@ impl Statistics {
 impl Drop for Statistics {
     fn drop(&mut self) {
         if !self.registered && !self.inner.0.is_null() {
+            remove_fuel_handler(self.inner.0);
             unsafe { ffi::WasmEdge_StatisticsDelete(self.inner.0) }
         }
     }
@@ -110,6 +304,31 @@ mod tests {
         thread,
     };
 
+    #[test]
+    fn test_stat_fuel() {
+        let result = Statistics::create();
+        assert!(result.is_ok());
+        let mut stat = result.unwrap();
+
+        // the fuel is unlimited by default
+        assert_eq!(stat.cost_limit(), u64::MAX);
+        assert_eq!(stat.remaining_fuel(), u64::MAX);
+        stat.add_fuel(100);
+        assert_eq!(stat.cost_limit(), u64::MAX);
+
+        stat.set_cost_limit(100);
+        assert_eq!(stat.remaining_fuel(), 100);
+        stat.add_fuel(50);
+        assert_eq!(stat.cost_limit(), 150);
+        assert_eq!(stat.remaining_fuel(), 150);
+
+        stat.on_out_of_fuel(|_| 10);
+        let key = stat.inner.0 as usize;
+        assert!(FUEL_HANDLERS.lock().unwrap().contains_key(&key));
+        stat.clear_out_of_fuel();
+        assert!(!FUEL_HANDLERS.lock().unwrap().contains_key(&key));
+    }
+
     #[test]
     fn test_stat_send() {
         let result = Statistics::create();
@@ -1,18 +1,37 @@
 //! Defines WasmEdge Store struct.
 
 use crate::{
-    error::{StoreError, WasmEdgeError},
+    error::{CoreError, CoreInstantiationError, StoreError, WasmEdgeError},
     ffi,
     instance::module::{InnerInstance, Instance},
     types::WasmEdgeString,
-    WasmEdgeResult,
+    utils::check,
+    ImportObject, WasmEdgeResult,
 };
+use std::sync::{Arc, Mutex};
+
+/// The [import objects](crate::ImportObject) registered into a store by [Linkers](crate::Linker) and [InstancePres](crate::InstancePre), which the store shares the ownership of.
+pub(crate) type SharedImports = Arc<Mutex<Vec<Arc<ImportObject>>>>;
+
+/// Converts the result of unregistering the named module from a store.
+pub(crate) fn check_unregister(result: ffi::WasmEdge_Result, name: &str) -> WasmEdgeResult<()> {
+    check(result).map_err(|err| match err {
+        WasmEdgeError::Core(CoreError::Instantiation(CoreInstantiationError::UnknownModule)) => {
+            WasmEdgeError::Store(StoreError::NotFoundModule(name.to_string()))
+        }
+        WasmEdgeError::Core(CoreError::Instantiation(CoreInstantiationError::ModuleInUse)) => {
+            WasmEdgeError::Store(StoreError::ModuleInUse(name.to_string()))
+        }
+        err => err,
+    })
+}
 
 /// A [Store] represents all global state that can be manipulated by WebAssembly programs. It consists of the runtime representation of all instances of [functions](crate::Function), [tables](crate::Table), [memories](crate::Memory), and [globals](crate::Global) that have been allocated during the life time of the [Vm](crate::Vm).
 #[derive(Debug)]
 pub struct Store {
     pub(crate) inner: InnerStore,
     pub(crate) registered: bool,
+    pub(crate) imports: SharedImports,
 }
 impl Store {
     /// Creates a new [Store].
@@ -27,6 +46,7 @@ impl Store {
             false => Ok(Store {
                 inner: InnerStore(ctx),
                 registered: false,
+                imports: SharedImports::default(),
             }),
         }
     }
@@ -80,6 +100,8 @@ impl Store {
             false => Ok(Instance {
                 inner: InnerInstance(ctx as *mut _),
                 registered: true,
+                #[cfg(feature = "metrics")]
+                memory_pages: Default::default(),
             }),
         }
     }
@@ -112,6 +134,62 @@ impl Store {
         }
         Ok(())
     }
+
+    /// Unregisters the named [module instance](crate::Instance) from the [Store], after which another module instance can be registered with the same name.
+    ///
+    /// The module instance itself is not destroyed, and it is still owned by the one which registers it.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the module instance to unregister.
+    ///
+    /// # Error
+    ///
+    /// If the target module instance is not found, or it is imported by any live module instance instantiated into the [Store], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{Executor, Loader, Store, Validator};
+    /// use wasmedge_types::wat2wasm;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut executor = Executor::create(None, None)?;
+    ///     let mut store = Store::create()?;
+    ///
+    ///     let wasm_bytes = wat2wasm(br#"(module (func (export "version") (result i32) (i32.const 1)))"#)?;
+    ///     let module = Loader::create(None)?.from_bytes(&wasm_bytes)?;
+    ///     Validator::create(None)?.validate(&module)?;
+    ///     let _v1 = executor.register_named_module(&mut store, &module, "plugin")?;
+    ///
+    ///     // replace the module with a new version
+    ///     store.unregister("plugin")?;
+    ///     let wasm_bytes = wat2wasm(br#"(module (func (export "version") (result i32) (i32.const 2)))"#)?;
+    ///     let module = Loader::create(None)?.from_bytes(&wasm_bytes)?;
+    ///     Validator::create(None)?.validate(&module)?;
+    ///     let _v2 = executor.register_named_module(&mut store, &module, "plugin")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn unregister(&mut self, name: impl AsRef<str>) -> WasmEdgeResult<()> {
+        let mod_name: WasmEdgeString = name.as_ref().into();
+        check_unregister(
+            unsafe { ffi::WasmEdge_StoreUnregisterModule(self.inner.0, mod_name.as_raw()) },
+            name.as_ref(),
+        )
+    }
+
+    /// Shares the ownership of an [import object](crate::ImportObject) registered into the [Store], so that it lives as long as the [Store].
+    pub(crate) fn keep_import(&self, import: &Arc<ImportObject>) {
+        let mut imports = self
+            .imports
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.");
+        if !imports.iter().any(|x| Arc::ptr_eq(x, import)) {
+            imports.push(Arc::clone(import));
+        }
+    }
 }
 impl Drop for Store {
     fn drop(&mut self) {
@@ -130,15 +208,17 @@ unsafe impl Sync for InnerStore {}
 mod tests {
     use super::Store;
     use crate::{
+        error::{StoreError, WasmEdgeError},
         instance::{Function, Global, GlobalType, MemType, Memory, Table, TableType},
         types::WasmValue,
-        Config, Engine, Executor, FuncType, ImportInstance, ImportModule, ImportObject, Vm,
+        Config, Engine, Executor, FuncType, ImportInstance, ImportModule, ImportObject, Loader,
+        Module, Validator, Vm,
     };
     use std::{
         sync::{Arc, Mutex},
         thread,
     };
-    use wasmedge_types::{Mutability, RefType, ValType};
+    use wasmedge_types::{wat2wasm, Mutability, RefType, ValType};
 
     #[test]
     fn test_store_basic() {
@@ -347,6 +427,88 @@ mod tests {
         assert_eq!(return_types, [ValType::I32]);
     }
 
+    #[test]
+    fn test_store_unregister() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // register the first version of the plugin
+        let plugin = load(r#"(module (func (export "version") (result i32) (i32.const 1)))"#);
+        let result = executor.register_named_module(&mut store, &plugin, "plugin");
+        assert!(result.is_ok());
+        let plugin_v1 = result.unwrap();
+
+        // instantiate a module importing from the plugin
+        let app = load(
+            r#"
+            (module
+                (import "plugin" "version" (func $version (result i32)))
+                (func (export "run") (result i32) (call $version))
+            )
+            "#,
+        );
+        let result = executor.register_active_module(&mut store, &app);
+        assert!(result.is_ok());
+        let app_instance = result.unwrap();
+
+        // the plugin is in use
+        let result = store.unregister("plugin");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::ModuleInUse("plugin".into()))
+        );
+        let result = store.unregister("no-such-module");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::NotFoundModule("no-such-module".into()))
+        );
+
+        // replace the plugin after the importing instance is dropped
+        drop(app_instance);
+        let result = store.unregister("plugin");
+        assert!(result.is_ok());
+        assert!(store.contains("plugin").is_err());
+        drop(plugin_v1);
+
+        let plugin = load(r#"(module (func (export "version") (result i32) (i32.const 2)))"#);
+        let result = executor.register_named_module(&mut store, &plugin, "plugin");
+        assert!(result.is_ok());
+        let _plugin_v2 = result.unwrap();
+
+        let result = executor.register_active_module(&mut store, &app);
+        assert!(result.is_ok());
+        let app_instance = result.unwrap();
+        let result = app_instance.get_func("run");
+        assert!(result.is_ok());
+        let result = executor.run_func(&result.unwrap(), []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+    }
+
+    fn load(wat: &str) -> Module {
+        let result = wat2wasm(wat.as_bytes());
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_bytes(wasm_bytes);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+        module
+    }
+
     fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
@@ -1,8 +1,23 @@
 //! Defines the WebAssembly primitive types.
 
-use crate::{ffi, instance::function::InnerFuncRef, FuncRef};
+use crate::{
+    error::{ValueError, WasmEdgeError},
+    ffi,
+    instance::function::InnerFuncRef,
+    FuncRef,
+};
 use core::ffi::c_void;
-use std::{ffi::CString, str::FromStr};
+use std::{
+    any::Any,
+    collections::HashMap,
+    ffi::CString,
+    fmt,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, Weak,
+    },
+};
 use wasmedge_types::{RefType, ValType};
 
 impl From<std::ops::RangeInclusive<u32>> for ffi::WasmEdge_Limit {
@@ -13,12 +28,14 @@ impl From<std::ops::RangeInclusive<u32>> for ffi::WasmEdge_Limit {
                 Min: start,
                 Max: end,
                 HasMax: false,
+                Shared: false,
             }
         } else {
             Self {
                 Min: start,
                 Max: end,
                 HasMax: true,
+                Shared: false,
             }
         }
     }
@@ -34,6 +51,35 @@ impl From<ffi::WasmEdge_Limit> for std::ops::RangeInclusive<u32> {
     }
 }
 
+impl From<ffi::WasmEdge_Limit> for wasmedge_types::MemoryType {
+    fn from(limit: ffi::WasmEdge_Limit) -> Self {
+        match limit.Shared {
+            true => Self::new_shared(limit.Min, limit.Max),
+            false => Self::new(limit.Min, limit.HasMax.then_some(limit.Max)),
+        }
+    }
+}
+impl From<&wasmedge_types::MemoryType> for ffi::WasmEdge_Limit {
+    fn from(ty: &wasmedge_types::MemoryType) -> Self {
+        Self {
+            HasMax: ty.maximum().is_some(),
+            Min: ty.minimum(),
+            Max: ty.maximum().unwrap_or(ty.minimum()),
+            Shared: ty.is_shared(),
+        }
+    }
+}
+impl From<&wasmedge_types::TableType> for ffi::WasmEdge_Limit {
+    fn from(ty: &wasmedge_types::TableType) -> Self {
+        Self {
+            HasMax: ty.maximum().is_some(),
+            Min: ty.minimum(),
+            Max: ty.maximum().unwrap_or(ty.minimum()),
+            Shared: false,
+        }
+    }
+}
+
 /// Struct of WasmEdge String.
 #[derive(Debug)]
 pub(crate) struct WasmEdgeString {
@@ -96,11 +142,234 @@ pub(crate) struct InnerWasmEdgeString(pub(crate) ffi::WasmEdge_String);
 unsafe impl Send for InnerWasmEdgeString {}
 unsafe impl Sync for InnerWasmEdgeString {}
 
+lazy_static! {
+    // Maps the identifiers handed to the runtime to the live host objects.
+    static ref EXTERN_REFS: Mutex<HashMap<usize, Weak<InnerExternRef>>> =
+        Mutex::new(HashMap::new());
+    // Keeps the host objects stored in table slots and globals alive, keyed by the address of the module instance owning
+    // the table or global, or by the address of the table or global itself if it is not owned by any module instance.
+    static ref EXTERN_REF_ROOTS: Mutex<HashMap<usize, ExternRefRoots>> = Mutex::new(HashMap::new());
+}
+// The host objects rooted by a module instance, keyed by the address of the table or global and the slot.
+type ExternRefRoots = HashMap<(usize, u32), ExternRef>;
+// `0` is reserved, since a null pointer denotes a null reference in the runtime.
+static NEXT_EXTERN_REF_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Defines a reference to a host object, which can be passed to WebAssembly as an `externref` value.
+///
+/// An [ExternRef] owns the host object and is reference counted: cloning an [ExternRef] is cheap, and the host object is dropped once no [ExternRef] points to it anymore. Besides the [ExternRef]s held by the host, the [WasmValue]s created by [WasmValue::from_extern_ref], and the [Table](crate::Table) slots and [Global](crate::Global)s set through this crate keep the host object alive.
+///
+/// The runtime never sees the host object itself, but an opaque identifier which is never reused. Therefore, an `externref` value coming back from WebAssembly whose host object has already been dropped is recognized and converted into `None`, instead of a dangling reference. Notice that a reference stored by WebAssembly code itself, for example by a `table.set` instruction, does not keep the host object alive; the host should hold a clone as long as the WebAssembly code may use it.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sys::{ExternRef, WasmValue};
+///
+/// let value = WasmValue::from_extern_ref(ExternRef::new(String::from("hello")));
+///
+/// let extern_ref = value.extern_ref().expect("fail to get the ExternRef");
+/// assert_eq!(extern_ref.downcast_ref::<String>().unwrap(), "hello");
+/// assert!(extern_ref.downcast_ref::<i32>().is_none());
+/// ```
+#[derive(Clone)]
+pub struct ExternRef {
+    inner: Arc<InnerExternRef>,
+}
+impl ExternRef {
+    /// Creates a new [ExternRef] which takes the ownership of the given host object.
+    ///
+    /// # Argument
+    ///
+    /// * `data` - The host object.
+    pub fn new<T: Any + Send + Sync>(data: T) -> Self {
+        let inner = Arc::new(InnerExternRef {
+            id: NEXT_EXTERN_REF_ID.fetch_add(1, Ordering::Relaxed),
+            data: Box::new(data),
+        });
+        EXTERN_REFS
+            .lock()
+            .expect("[wasmedge-sys] EXTERN_REFS is poisoned")
+            .insert(inner.id, Arc::downgrade(&inner));
+        Self { inner }
+    }
+
+    /// Checks if the host object is of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.inner.data.is::<T>()
+    }
+
+    /// Returns a reference to the host object if it is of type `T`; otherwise, `None` is returned.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.inner.data.downcast_ref::<T>()
+    }
+
+    /// Returns the number of the references to the host object, including the ones held by [WasmValue]s, [Table](crate::Table) slots and [Global](crate::Global)s.
+    pub fn ref_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    /// Checks if two [ExternRef]s point to the same host object.
+    pub fn ptr_eq(&self, other: &ExternRef) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Returns the opaque pointer handed to the runtime.
+    pub(crate) fn as_ptr(&self) -> *mut c_void {
+        self.inner.id as *mut c_void
+    }
+
+    /// Recovers the [ExternRef] from an opaque pointer generated by [ExternRef::as_ptr].
+    ///
+    /// If the pointer is unknown or the host object has been dropped, then `None` is returned.
+    pub(crate) fn from_ptr(ptr: *mut c_void) -> Option<Self> {
+        let weak = EXTERN_REFS
+            .lock()
+            .expect("[wasmedge-sys] EXTERN_REFS is poisoned")
+            .get(&(ptr as usize))
+            .cloned()?;
+        weak.upgrade().map(|inner| Self { inner })
+    }
+}
+impl fmt::Debug for ExternRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternRef")
+            .field("id", &self.inner.id)
+            .finish()
+    }
+}
+
+struct InnerExternRef {
+    id: usize,
+    data: Box<dyn Any + Send + Sync>,
+}
+impl Drop for InnerExternRef {
+    fn drop(&mut self) {
+        if let Ok(mut refs) = EXTERN_REFS.lock() {
+            refs.remove(&self.id);
+        }
+    }
+}
+
+/// Keeps the [ExternRef] held by `val` alive as long as the slot `slot` of the table or global `item` stores it, or
+/// until the roots of `owner` are released.
+///
+/// The [ExternRef] previously stored in the slot, if any, is released.
+pub(crate) fn root_extern_ref(owner: usize, item: usize, slot: u32, val: &WasmValue) {
+    if val.ty() != ValType::ExternRef {
+        return;
+    }
+    let released = {
+        let mut roots = EXTERN_REF_ROOTS
+            .lock()
+            .expect("[wasmedge-sys] EXTERN_REF_ROOTS is poisoned");
+        match val.extern_ref() {
+            Some(extern_ref) => roots
+                .entry(owner)
+                .or_default()
+                .insert((item, slot), extern_ref.clone()),
+            None => roots
+                .get_mut(&owner)
+                .and_then(|slots| slots.remove(&(item, slot))),
+        }
+    };
+    // the host object is dropped out of the lock, since its destructor may touch the roots as well.
+    drop(released);
+}
+
+/// Roots `val` in the slots `offset..offset + len` of the table `item` owned by `owner`, after the slots are filled.
+pub(crate) fn fill_extern_refs(owner: usize, item: usize, offset: u32, len: u32, val: &WasmValue) {
+    if val.ty() != ValType::ExternRef {
+        return;
+    }
+    let released = {
+        let mut roots = EXTERN_REF_ROOTS
+            .lock()
+            .expect("[wasmedge-sys] EXTERN_REF_ROOTS is poisoned");
+        let slots = roots.entry(owner).or_default();
+        (offset..offset + len)
+            .filter_map(|slot| match val.extern_ref() {
+                Some(extern_ref) => slots.insert((item, slot), extern_ref.clone()),
+                None => slots.remove(&(item, slot)),
+            })
+            .collect::<Vec<_>>()
+    };
+    drop(released);
+}
+
+/// Mirrors the roots of the slots `src..src + len` of the table `src_item` owned by `src_owner` onto the slots
+/// `dst..dst + len` of the table `dst_item` owned by `dst_owner`, after the slots are copied.
+pub(crate) fn copy_extern_refs(
+    (src_owner, src_item, src): (usize, usize, u32),
+    (dst_owner, dst_item, dst): (usize, usize, u32),
+    len: u32,
+) {
+    let released = {
+        let mut roots = EXTERN_REF_ROOTS
+            .lock()
+            .expect("[wasmedge-sys] EXTERN_REF_ROOTS is poisoned");
+        // take a snapshot of the source roots first, since the ranges may overlap.
+        let copied = (src..src + len)
+            .map(|slot| {
+                roots
+                    .get(&src_owner)
+                    .and_then(|slots| slots.get(&(src_item, slot)))
+                    .cloned()
+            })
+            .collect::<Vec<_>>();
+        let slots = roots.entry(dst_owner).or_default();
+        (dst..dst + len)
+            .zip(copied)
+            .filter_map(|(slot, extern_ref)| match extern_ref {
+                Some(extern_ref) => slots.insert((dst_item, slot), extern_ref),
+                None => slots.remove(&(dst_item, slot)),
+            })
+            .collect::<Vec<_>>()
+    };
+    drop(released);
+}
+
+/// Hands the roots of the table or global `item` over to the module instance `owner`, into which it is moved.
+pub(crate) fn transfer_extern_refs(item: usize, owner: usize) {
+    let mut roots = EXTERN_REF_ROOTS
+        .lock()
+        .expect("[wasmedge-sys] EXTERN_REF_ROOTS is poisoned");
+    if let Some(slots) = roots.remove(&item) {
+        roots.entry(owner).or_default().extend(slots);
+    }
+}
+
+/// Releases all the [ExternRef]s rooted by `owner`, which is a module instance, or a table or global not owned by any
+/// module instance, about to be destroyed.
+pub(crate) fn unroot_extern_refs(owner: usize) {
+    let released = EXTERN_REF_ROOTS
+        .lock()
+        .expect("[wasmedge-sys] EXTERN_REF_ROOTS is poisoned")
+        .remove(&owner);
+    drop(released);
+}
+
 /// Defines a WebAssembly value.
-#[derive(Debug, Clone, Copy)]
+///
+/// A [WasmValue] can be converted from and into the corresponding Rust types via [From] and [TryFrom]; the latter fails if the value type does not match. Notice that the `to_*` methods, for example [WasmValue::to_i32], do not check the value type.
+///
+/// Since a [WasmValue] may own an [ExternRef], it is not `Copy` anymore; call `clone` explicitly to duplicate a value.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sys::WasmValue;
+///
+/// let val = WasmValue::from(1314i32);
+/// assert_eq!(val.to_string(), "i32(1314)");
+/// assert_eq!(i32::try_from(val.clone()), Ok(1314));
+/// assert!(f32::try_from(val).is_err());
+/// ```
+#[derive(Clone)]
 pub struct WasmValue {
     ctx: ffi::WasmEdge_Value,
     ty: ValType,
+    extern_ref: Option<ExternRef>,
 }
 impl WasmValue {
     /// Returns the raw `WasmEdge_Value`.
@@ -122,6 +391,7 @@ impl WasmValue {
         Self {
             ctx: unsafe { ffi::WasmEdge_ValueGenI32(val) },
             ty: ValType::I32,
+            extern_ref: None,
         }
     }
 
@@ -139,6 +409,7 @@ impl WasmValue {
         Self {
             ctx: unsafe { ffi::WasmEdge_ValueGenI64(val) },
             ty: ValType::I64,
+            extern_ref: None,
         }
     }
 
@@ -156,6 +427,7 @@ impl WasmValue {
         Self {
             ctx: unsafe { ffi::WasmEdge_ValueGenF32(val) },
             ty: ValType::F32,
+            extern_ref: None,
         }
     }
 
@@ -173,6 +445,7 @@ impl WasmValue {
         Self {
             ctx: unsafe { ffi::WasmEdge_ValueGenF64(val) },
             ty: ValType::F64,
+            extern_ref: None,
         }
     }
 
@@ -190,6 +463,7 @@ impl WasmValue {
         Self {
             ctx: unsafe { ffi::WasmEdge_ValueGenV128(val) },
             ty: ValType::V128,
+            extern_ref: None,
         }
     }
 
@@ -210,6 +484,7 @@ impl WasmValue {
                 RefType::FuncRef => ValType::FuncRef,
                 RefType::ExternRef => ValType::ExternRef,
             },
+            extern_ref: None,
         }
     }
 
@@ -229,6 +504,7 @@ impl WasmValue {
         Self {
             ctx: unsafe { ffi::WasmEdge_ValueGenFuncRef(func_ref.inner.0) },
             ty: ValType::FuncRef,
+            extern_ref: None,
         }
     }
 
@@ -249,74 +525,323 @@ impl WasmValue {
         }
     }
 
-    /// Creates a [WasmValue] from a reference to an external object.
+    /// Creates a [WasmValue] from an [ExternRef].
     ///
     /// The [WasmValue]s generated by this function are only meaningful when the `reference_types` option is enabled in
     /// the [Config](crate::Config).
     ///
     /// # Argument
     ///
-    /// * `extern_obj` - The reference to an external object.
-    pub fn from_extern_ref<T>(extern_obj: &mut T) -> Self {
-        let ptr = extern_obj as *mut T as *mut c_void;
+    /// * `extern_ref` - The [ExternRef] to the host object. The [WasmValue] keeps the host object alive.
+    pub fn from_extern_ref(extern_ref: ExternRef) -> Self {
         Self {
-            ctx: unsafe { ffi::WasmEdge_ValueGenExternRef(ptr) },
+            ctx: unsafe { ffi::WasmEdge_ValueGenExternRef(extern_ref.as_ptr()) },
             ty: ValType::ExternRef,
+            extern_ref: Some(extern_ref),
         }
     }
 
-    /// Returns the reference to an external object.
+    /// Returns the [ExternRef] to the host object.
     ///
-    /// If the [WasmValue] is a `NullRef`, then `None` is returned.
-    pub fn extern_ref<T>(&self) -> Option<&T> {
+    /// If the [WasmValue] is a `NullRef`, is not an `externref` value, or refers to a host object that has been dropped, then `None` is returned.
+    pub fn extern_ref(&self) -> Option<&ExternRef> {
+        self.extern_ref.as_ref()
+    }
+
+    /// Returns the bit pattern of the value, which is independent of the representation used by the runtime.
+    pub(crate) fn bits(&self) -> u128 {
+        match self.ty {
+            ValType::I32 => self.to_i32() as u32 as u128,
+            ValType::I64 => self.to_i64() as u64 as u128,
+            ValType::F32 => self.to_f32().to_bits() as u128,
+            ValType::F64 => self.to_f64().to_bits() as u128,
+            ValType::V128 => self.to_v128() as u128,
+            ValType::FuncRef | ValType::ExternRef => self.ref_addr() as u128,
+            ValType::None => 0,
+        }
+    }
+
+    fn ref_addr(&self) -> usize {
         unsafe {
             match ffi::WasmEdge_ValueIsNullRef(self.ctx) {
-                true => None,
-                false => {
-                    let ptr = ffi::WasmEdge_ValueGetExternRef(self.ctx);
-                    let x = ptr as *mut T;
-                    Some(&*x)
+                true => 0,
+                false if self.ty == ValType::FuncRef => {
+                    ffi::WasmEdge_ValueGetFuncRef(self.ctx) as usize
                 }
+                false => ffi::WasmEdge_ValueGetExternRef(self.ctx) as usize,
             }
         }
     }
 }
+macro_rules! impl_v128_lanes {
+    ($($t:ty, $n:literal, $from:ident, $as:ident;)*) => {
+        impl WasmValue {
+            $(
+                #[doc = concat!("Creates a [WasmValue] of `v128` type from ", stringify!($n), " `", stringify!($t), "` lanes.")]
+                ///
+                /// The first lane is stored in the least significant bits, as in WebAssembly.
+                pub fn $from(lanes: [$t; $n]) -> Self {
+                    let mut bytes = [0u8; 16];
+                    for (chunk, lane) in bytes.chunks_exact_mut(16 / $n).zip(lanes.iter()) {
+                        chunk.copy_from_slice(&lane.to_le_bytes());
+                    }
+                    Self::from_v128(i128::from_le_bytes(bytes))
+                }
+
+                #[doc = concat!("Returns the `v128` value as ", stringify!($n), " `", stringify!($t), "` lanes.")]
+                ///
+                /// If the [WasmValue] is not of `v128` type, then `None` is returned.
+                pub fn $as(&self) -> Option<[$t; $n]> {
+                    if self.ty != ValType::V128 {
+                        return None;
+                    }
+                    let bytes = self.to_v128().to_le_bytes();
+                    let mut lanes = [<$t>::default(); $n];
+                    for (lane, chunk) in lanes.iter_mut().zip(bytes.chunks_exact(16 / $n)) {
+                        *lane = <$t>::from_le_bytes(chunk.try_into().unwrap());
+                    }
+                    Some(lanes)
+                }
+            )*
+        }
+    };
+}
+impl_v128_lanes! {
+    i8, 16, from_i8x16, as_i8x16;
+    u8, 16, from_u8x16, as_u8x16;
+    i16, 8, from_i16x8, as_i16x8;
+    u16, 8, from_u16x8, as_u16x8;
+    i32, 4, from_i32x4, as_i32x4;
+    u32, 4, from_u32x4, as_u32x4;
+    i64, 2, from_i64x2, as_i64x2;
+    u64, 2, from_u64x2, as_u64x2;
+    f32, 4, from_f32x4, as_f32x4;
+    f64, 2, from_f64x2, as_f64x2;
+}
+impl PartialEq for WasmValue {
+    /// Two [WasmValue]s are equal if they have the same type and value. The floating-point values follow the IEEE 754 comparison, and the references are compared by identity.
+    fn eq(&self, other: &Self) -> bool {
+        if self.ty != other.ty {
+            return false;
+        }
+        match self.ty {
+            ValType::F32 => self.to_f32() == other.to_f32(),
+            ValType::F64 => self.to_f64() == other.to_f64(),
+            _ => self.bits() == other.bits(),
+        }
+    }
+}
+impl fmt::Display for WasmValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ty {
+            ValType::I32 => write!(f, "i32({})", self.to_i32()),
+            ValType::I64 => write!(f, "i64({})", self.to_i64()),
+            ValType::F32 => write!(f, "f32({})", self.to_f32()),
+            ValType::F64 => write!(f, "f64({})", self.to_f64()),
+            ValType::V128 => write!(f, "v128({:#034x})", self.to_v128() as u128),
+            ValType::FuncRef | ValType::ExternRef => {
+                let name = match self.ty {
+                    ValType::FuncRef => "funcref",
+                    _ => "externref",
+                };
+                match self.is_null_ref() {
+                    true => write!(f, "{}(null)", name),
+                    false => write!(f, "{}({:#x})", name, self.ref_addr()),
+                }
+            }
+            ValType::None => write!(f, "none"),
+        }
+    }
+}
+impl fmt::Debug for WasmValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ty {
+            ValType::I32 => f.debug_tuple("I32").field(&self.to_i32()).finish(),
+            ValType::I64 => f.debug_tuple("I64").field(&self.to_i64()).finish(),
+            ValType::F32 => f.debug_tuple("F32").field(&self.to_f32()).finish(),
+            ValType::F64 => f.debug_tuple("F64").field(&self.to_f64()).finish(),
+            ValType::V128 => f
+                .debug_tuple("V128")
+                .field(&format_args!("{:#034x}", self.to_v128() as u128))
+                .finish(),
+            ValType::FuncRef if self.is_null_ref() => f
+                .debug_tuple("FuncRef")
+                .field(&format_args!("null"))
+                .finish(),
+            ValType::FuncRef => f
+                .debug_tuple("FuncRef")
+                .field(&format_args!("{:#x}", self.ref_addr()))
+                .finish(),
+            ValType::ExternRef if self.is_null_ref() => f
+                .debug_tuple("ExternRef")
+                .field(&format_args!("null"))
+                .finish(),
+            ValType::ExternRef => match &self.extern_ref {
+                Some(extern_ref) => f.debug_tuple("ExternRef").field(extern_ref).finish(),
+                None => f
+                    .debug_tuple("ExternRef")
+                    .field(&format_args!("dropped"))
+                    .finish(),
+            },
+            ValType::None => f.write_str("None"),
+        }
+    }
+}
+/// Defines the Rust types which correspond to the WebAssembly value types.
+///
+/// The trait is used by the typed accessors, such as [Global::get](crate::Global::get) and [Global::set](crate::Global::set), to check the value type on the Rust side.
+pub trait WasmValueType: Sized {
+    /// Returns the WebAssembly value type the Rust type corresponds to.
+    fn ty() -> ValType;
+
+    /// Converts a [WasmValue] into the Rust value.
+    ///
+    /// The caller should make sure that the type of `val` is [WasmValueType::ty].
+    fn from_wasm_value(val: &WasmValue) -> Self;
+
+    /// Converts the Rust value into a [WasmValue].
+    fn into_wasm_value(self) -> WasmValue;
+}
+macro_rules! impl_wasm_value_type {
+    ($t:ty, $val_ty:ident, $from:ident, $to:ident) => {
+        impl WasmValueType for $t {
+            fn ty() -> ValType {
+                ValType::$val_ty
+            }
+
+            fn from_wasm_value(val: &WasmValue) -> Self {
+                val.$to()
+            }
+
+            fn into_wasm_value(self) -> WasmValue {
+                WasmValue::$from(self)
+            }
+        }
+    };
+}
+impl_wasm_value_type!(i32, I32, from_i32, to_i32);
+impl_wasm_value_type!(i64, I64, from_i64, to_i64);
+impl_wasm_value_type!(f32, F32, from_f32, to_f32);
+impl_wasm_value_type!(f64, F64, from_f64, to_f64);
+impl_wasm_value_type!(i128, V128, from_v128, to_v128);
+impl WasmValueType for u128 {
+    fn ty() -> ValType {
+        ValType::V128
+    }
+
+    fn from_wasm_value(val: &WasmValue) -> Self {
+        val.to_v128() as u128
+    }
+
+    fn into_wasm_value(self) -> WasmValue {
+        WasmValue::from_v128(self as i128)
+    }
+}
+impl WasmValueType for Option<FuncRef> {
+    fn ty() -> ValType {
+        ValType::FuncRef
+    }
+
+    fn from_wasm_value(val: &WasmValue) -> Self {
+        val.func_ref()
+    }
+
+    fn into_wasm_value(self) -> WasmValue {
+        match self {
+            Some(func_ref) => WasmValue::from_func_ref(func_ref),
+            None => WasmValue::from_null_ref(RefType::FuncRef),
+        }
+    }
+}
+impl WasmValueType for Option<ExternRef> {
+    fn ty() -> ValType {
+        ValType::ExternRef
+    }
+
+    fn from_wasm_value(val: &WasmValue) -> Self {
+        val.extern_ref().cloned()
+    }
+
+    fn into_wasm_value(self) -> WasmValue {
+        match self {
+            Some(extern_ref) => WasmValue::from_extern_ref(extern_ref),
+            None => WasmValue::from_null_ref(RefType::ExternRef),
+        }
+    }
+}
+
+macro_rules! impl_wasm_value_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for WasmValue {
+                fn from(val: $t) -> Self {
+                    val.into_wasm_value()
+                }
+            }
+
+            impl TryFrom<WasmValue> for $t {
+                type Error = WasmEdgeError;
+
+                fn try_from(val: WasmValue) -> Result<Self, Self::Error> {
+                    match val.ty() == <$t>::ty() {
+                        true => Ok(<$t>::from_wasm_value(&val)),
+                        false => Err(WasmEdgeError::Value(ValueError::UnmatchedValType {
+                            expected: <$t>::ty(),
+                            actual: val.ty(),
+                        })),
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_wasm_value_conversions!(
+    i32,
+    i64,
+    f32,
+    f64,
+    i128,
+    u128,
+    Option<FuncRef>,
+    Option<ExternRef>
+);
+impl From<FuncRef> for WasmValue {
+    fn from(func_ref: FuncRef) -> Self {
+        WasmValue::from_func_ref(func_ref)
+    }
+}
+impl From<ExternRef> for WasmValue {
+    fn from(extern_ref: ExternRef) -> Self {
+        WasmValue::from_extern_ref(extern_ref)
+    }
+}
+
 impl From<ffi::WasmEdge_Value> for WasmValue {
     fn from(raw_val: ffi::WasmEdge_Value) -> Self {
-        match raw_val.Type {
-            ffi::WasmEdge_ValType_I32 => Self {
-                ctx: raw_val,
-                ty: ValType::I32,
-            },
-            ffi::WasmEdge_ValType_I64 => Self {
-                ctx: raw_val,
-                ty: ValType::I64,
-            },
-            ffi::WasmEdge_ValType_F32 => Self {
-                ctx: raw_val,
-                ty: ValType::F32,
-            },
-            ffi::WasmEdge_ValType_F64 => Self {
-                ctx: raw_val,
-                ty: ValType::F64,
-            },
-            ffi::WasmEdge_ValType_V128 => Self {
-                ctx: raw_val,
-                ty: ValType::V128,
-            },
-            ffi::WasmEdge_ValType_FuncRef => Self {
-                ctx: raw_val,
-                ty: ValType::FuncRef,
-            },
-            ffi::WasmEdge_ValType_ExternRef => Self {
-                ctx: raw_val,
-                ty: ValType::ExternRef,
-            },
-            ffi::WasmEdge_ValType_None => Self {
-                ctx: raw_val,
-                ty: ValType::None,
-            },
+        let ty = match raw_val.Type {
+            ffi::WasmEdge_ValType_I32 => ValType::I32,
+            ffi::WasmEdge_ValType_I64 => ValType::I64,
+            ffi::WasmEdge_ValType_F32 => ValType::F32,
+            ffi::WasmEdge_ValType_F64 => ValType::F64,
+            ffi::WasmEdge_ValType_V128 => ValType::V128,
+            ffi::WasmEdge_ValType_FuncRef => ValType::FuncRef,
+            ffi::WasmEdge_ValType_ExternRef => ValType::ExternRef,
+            ffi::WasmEdge_ValType_None => ValType::None,
             _ => panic!("unknown WasmEdge_ValType `{}`", raw_val.Type),
+        };
+        let extern_ref = match ty {
+            ValType::ExternRef => unsafe {
+                match ffi::WasmEdge_ValueIsNullRef(raw_val) {
+                    true => None,
+                    false => ExternRef::from_ptr(ffi::WasmEdge_ValueGetExternRef(raw_val)),
+                }
+            },
+            _ => None,
+        };
+        Self {
+            ctx: raw_val,
+            ty,
+            extern_ref,
         }
     }
 }
@@ -324,12 +849,12 @@ impl From<ffi::WasmEdge_Value> for WasmValue {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Table, TableType};
-    use std::{
-        sync::{Arc, Mutex},
-        thread,
+    use crate::{
+        Executor, Global, GlobalType, ImportInstance, ImportModule, Loader, Store, Table,
+        TableType, Validator,
     };
-    use wasmedge_types::RefType;
+    use std::thread;
+    use wasmedge_types::{wat2wasm, Mutability, RefType};
 
     #[test]
     fn test_types_value() {
@@ -354,15 +879,10 @@ mod tests {
         assert_eq!(val.ty(), ValType::V128);
 
         // ExternRef
-        let result = TableType::create(RefType::FuncRef, 10..=20);
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        let result = Table::create(&ty);
-        assert!(result.is_ok());
-        let mut table = result.unwrap();
-        let value = WasmValue::from_extern_ref(&mut table);
+        let value = WasmValue::from_extern_ref(ExternRef::new(String::from("hello")));
         assert_eq!(value.ty(), ValType::ExternRef);
-        assert!(value.extern_ref::<Table>().is_some());
+        assert!(!value.is_null_ref());
+        assert!(value.extern_ref().is_some());
 
         // NullRef(FuncRef)
         let val = WasmValue::from_null_ref(RefType::FuncRef);
@@ -395,13 +915,7 @@ mod tests {
         let val_v128 = WasmValue::from_v128(1314);
 
         // ExternRef
-        let result = TableType::create(RefType::FuncRef, 10..=20);
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        let result = Table::create(&ty);
-        assert!(result.is_ok());
-        let mut table = result.unwrap();
-        let val_extern_ref = WasmValue::from_extern_ref(&mut table);
+        let val_extern_ref = WasmValue::from_extern_ref(ExternRef::new(String::from("hello")));
 
         // NullRef(FuncRef)
         let val_null_func_ref = WasmValue::from_null_ref(RefType::FuncRef);
@@ -427,7 +941,7 @@ mod tests {
 
             let val_extern_ref_c = val_extern_ref;
             assert_eq!(val_extern_ref_c.ty(), ValType::ExternRef);
-            assert!(val_extern_ref_c.extern_ref::<Table>().is_some());
+            assert!(val_extern_ref_c.extern_ref().is_some());
 
             let val_null_func_ref_c = val_null_func_ref;
             assert_eq!(val_null_func_ref_c.ty(), ValType::FuncRef);
@@ -466,13 +980,9 @@ mod tests {
         let val_v128_cloned = Arc::clone(&val_v128);
 
         // ExternRef
-        let result = TableType::create(RefType::FuncRef, 10..=20);
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        let result = Table::create(&ty);
-        assert!(result.is_ok());
-        let mut table = result.unwrap();
-        let val_extern_ref = Arc::new(Mutex::new(WasmValue::from_extern_ref(&mut table)));
+        let val_extern_ref = Arc::new(Mutex::new(WasmValue::from_extern_ref(ExternRef::new(
+            String::from("hello"),
+        ))));
         let val_extern_ref_cloned = Arc::clone(&val_extern_ref);
 
         // NullRef(FuncRef)
@@ -514,7 +1024,7 @@ mod tests {
             assert!(result.is_ok());
             let val_extern_ref_c = result.unwrap();
             assert_eq!(val_extern_ref_c.ty(), ValType::ExternRef);
-            assert!(val_extern_ref_c.extern_ref::<Table>().is_some());
+            assert!(val_extern_ref_c.extern_ref().is_some());
 
             let result = val_null_func_ref_cloned.lock();
             assert!(result.is_ok());
@@ -534,6 +1044,328 @@ mod tests {
         handle.join().unwrap();
     }
 
+    #[test]
+    fn test_types_extern_ref() {
+        let extern_ref = ExternRef::new(String::from("hello"));
+        assert_eq!(extern_ref.ref_count(), 1);
+        assert!(extern_ref.is::<String>());
+        assert!(!extern_ref.is::<i32>());
+
+        // checked downcasts
+        assert_eq!(extern_ref.downcast_ref::<String>().unwrap(), "hello");
+        assert!(extern_ref.downcast_ref::<i32>().is_none());
+
+        // a WasmValue holds a reference to the host object
+        let val = WasmValue::from_extern_ref(extern_ref.clone());
+        assert_eq!(extern_ref.ref_count(), 2);
+        let val_cloned = val.clone();
+        assert_eq!(extern_ref.ref_count(), 3);
+        assert!(val_cloned.extern_ref().unwrap().ptr_eq(&extern_ref));
+        drop(val);
+        drop(val_cloned);
+        assert_eq!(extern_ref.ref_count(), 1);
+
+        // recover the ExternRef from the raw value
+        let raw = WasmValue::from_extern_ref(extern_ref.clone()).as_raw();
+        let val = WasmValue::from(raw);
+        assert!(val.extern_ref().unwrap().ptr_eq(&extern_ref));
+        drop(val);
+
+        // the host object has been dropped
+        drop(extern_ref);
+        let val = WasmValue::from(raw);
+        assert_eq!(val.ty(), ValType::ExternRef);
+        assert!(!val.is_null_ref());
+        assert!(val.extern_ref().is_none());
+
+        // a raw pointer not generated by ExternRef is not recognized
+        let mut data = 1314;
+        let raw = unsafe {
+            ffi::WasmEdge_ValueGenExternRef(&mut data as *mut i32 as *mut std::ffi::c_void)
+        };
+        assert!(WasmValue::from(raw).extern_ref().is_none());
+
+        // null reference
+        let val = WasmValue::from_null_ref(RefType::ExternRef);
+        assert!(val.extern_ref().is_none());
+        assert!(WasmValue::from(val.as_raw()).extern_ref().is_none());
+    }
+
+    #[test]
+    fn test_types_extern_ref_in_table_and_global() {
+        let extern_ref = ExternRef::new(vec![1u8, 2, 3]);
+
+        // create a Table instance of externref
+        let result = TableType::create(RefType::ExternRef, 10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+
+        // the table slot keeps the host object alive
+        let result = table.set_data(WasmValue::from_extern_ref(extern_ref.clone()), 3);
+        assert!(result.is_ok());
+        assert_eq!(extern_ref.ref_count(), 2);
+        let result = table.get_data(3);
+        assert!(result.is_ok());
+        let val = result.unwrap();
+        let data = val.extern_ref().unwrap().downcast_ref::<Vec<u8>>();
+        assert_eq!(data.unwrap(), &[1, 2, 3]);
+        drop(val);
+
+        // overwriting the slot releases the host object
+        let result = table.set_data(WasmValue::from_null_ref(RefType::ExternRef), 3);
+        assert!(result.is_ok());
+        assert_eq!(extern_ref.ref_count(), 1);
+
+        // dropping the table releases the host object
+        let result = table.set_data(WasmValue::from_extern_ref(extern_ref.clone()), 5);
+        assert!(result.is_ok());
+        assert_eq!(extern_ref.ref_count(), 2);
+        drop(table);
+        assert_eq!(extern_ref.ref_count(), 1);
+
+        // create a Global instance of externref
+        let result = GlobalType::create(ValType::ExternRef, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_extern_ref(extern_ref.clone()));
+        assert!(result.is_ok());
+        let mut global = result.unwrap();
+        assert_eq!(extern_ref.ref_count(), 2);
+        assert!(global.get_value().extern_ref().unwrap().ptr_eq(&extern_ref));
+
+        // overwriting the global releases the host object
+        let other = ExternRef::new(0u64);
+        let result = global.set_value(WasmValue::from_extern_ref(other.clone()));
+        assert!(result.is_ok());
+        assert_eq!(extern_ref.ref_count(), 1);
+        assert_eq!(other.ref_count(), 2);
+
+        // dropping the global releases the host object
+        drop(global);
+        assert_eq!(other.ref_count(), 1);
+    }
+
+    #[test]
+    fn test_types_extern_ref_in_module_instance() {
+        let extern_ref = ExternRef::new(String::from("hello"));
+
+        // the table and the global moved into an import module keep the host object alive
+        let result = TableType::create(RefType::ExternRef, 10..=20);
+        assert!(result.is_ok());
+        let result = Table::create(&result.unwrap());
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+        let result = table.set_data(WasmValue::from_extern_ref(extern_ref.clone()), 3);
+        assert!(result.is_ok());
+        let result = GlobalType::create(ValType::ExternRef, Mutability::Var);
+        assert!(result.is_ok());
+        let result = Global::create(
+            &result.unwrap(),
+            WasmValue::from_extern_ref(extern_ref.clone()),
+        );
+        assert!(result.is_ok());
+        let global = result.unwrap();
+        let result = ImportModule::create("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        import.add_table("table", table);
+        import.add_global("global", global);
+        assert_eq!(extern_ref.ref_count(), 3);
+
+        // dropping the import module releases the host object
+        drop(import);
+        assert_eq!(extern_ref.ref_count(), 1);
+
+        // the table obtained from a module instance keeps the host object alive as long as the module instance
+        let result = wat2wasm(br#"(module (table (export "table") 2 externref))"#);
+        assert!(result.is_ok());
+        let wasm = result.unwrap();
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let result = result.unwrap().from_bytes(&wasm);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let result = result.unwrap().validate(&module);
+        assert!(result.is_ok());
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+
+        let result = instance.get_table("table");
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+        let result = table.set_data(WasmValue::from_extern_ref(extern_ref.clone()), 1);
+        assert!(result.is_ok());
+        drop(table);
+        assert_eq!(extern_ref.ref_count(), 2);
+
+        // dropping the module instance releases the host object
+        drop(instance);
+        assert_eq!(extern_ref.ref_count(), 1);
+    }
+
+    #[test]
+    fn test_types_value_conversions() {
+        // From
+        assert_eq!(WasmValue::from(1314i32).ty(), ValType::I32);
+        assert_eq!(WasmValue::from(1314i64).ty(), ValType::I64);
+        assert_eq!(WasmValue::from(13.14f32).ty(), ValType::F32);
+        assert_eq!(WasmValue::from(13.14f64).ty(), ValType::F64);
+        assert_eq!(WasmValue::from(1314u128).ty(), ValType::V128);
+        assert_eq!(WasmValue::from(-1314i128).ty(), ValType::V128);
+        assert_eq!(
+            WasmValue::from(ExternRef::new(1314)).ty(),
+            ValType::ExternRef
+        );
+        assert!(WasmValue::from(None::<ExternRef>).is_null_ref());
+        assert!(WasmValue::from(None::<FuncRef>).is_null_ref());
+
+        // TryFrom
+        assert_eq!(i32::try_from(WasmValue::from(1314i32)), Ok(1314));
+        assert_eq!(i64::try_from(WasmValue::from(1314i64)), Ok(1314));
+        assert_eq!(f32::try_from(WasmValue::from(13.14f32)), Ok(13.14));
+        assert_eq!(f64::try_from(WasmValue::from(13.14f64)), Ok(13.14));
+        assert_eq!(u128::try_from(WasmValue::from(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(i128::try_from(WasmValue::from(u128::MAX)), Ok(-1));
+        let result = Option::<ExternRef>::try_from(WasmValue::from(ExternRef::new(1314)));
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().unwrap().downcast_ref::<i32>(), Some(&1314));
+
+        // mis-matched types
+        let result = i64::try_from(WasmValue::from(1314i32));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Value(ValueError::UnmatchedValType {
+                expected: ValType::I64,
+                actual: ValType::I32,
+            })
+        );
+        assert!(f64::try_from(WasmValue::from(13.14f32)).is_err());
+        assert!(i32::try_from(WasmValue::from_null_ref(RefType::ExternRef)).is_err());
+        assert!(Option::<FuncRef>::try_from(WasmValue::from_null_ref(RefType::ExternRef)).is_err());
+    }
+
+    #[test]
+    fn test_types_value_eq_and_fmt() {
+        // PartialEq
+        assert_eq!(WasmValue::from(1314i32), WasmValue::from_i32(1314));
+        assert_ne!(WasmValue::from(1314i32), WasmValue::from(1314i64));
+        assert_ne!(WasmValue::from(1314i32), WasmValue::from(1315i32));
+        assert_eq!(WasmValue::from(0.0f32), WasmValue::from(-0.0f32));
+        assert_ne!(WasmValue::from(f64::NAN), WasmValue::from(f64::NAN));
+        assert_eq!(
+            WasmValue::from_null_ref(RefType::ExternRef),
+            WasmValue::from_null_ref(RefType::ExternRef)
+        );
+        assert_ne!(
+            WasmValue::from_null_ref(RefType::ExternRef),
+            WasmValue::from_null_ref(RefType::FuncRef)
+        );
+        let extern_ref = ExternRef::new(1314);
+        assert_eq!(
+            WasmValue::from(extern_ref.clone()),
+            WasmValue::from(extern_ref)
+        );
+        assert_ne!(
+            WasmValue::from(ExternRef::new(1314)),
+            WasmValue::from(ExternRef::new(1314))
+        );
+
+        // Display
+        assert_eq!(WasmValue::from(-1314i32).to_string(), "i32(-1314)");
+        assert_eq!(WasmValue::from(1314i64).to_string(), "i64(1314)");
+        assert_eq!(WasmValue::from(1.5f32).to_string(), "f32(1.5)");
+        assert_eq!(WasmValue::from(-2.25f64).to_string(), "f64(-2.25)");
+        assert_eq!(
+            WasmValue::from(0xffu128).to_string(),
+            "v128(0x000000000000000000000000000000ff)"
+        );
+        assert_eq!(
+            WasmValue::from_null_ref(RefType::FuncRef).to_string(),
+            "funcref(null)"
+        );
+        assert_eq!(
+            WasmValue::from_null_ref(RefType::ExternRef).to_string(),
+            "externref(null)"
+        );
+
+        // Debug
+        assert_eq!(format!("{:?}", WasmValue::from(-1314i32)), "I32(-1314)");
+        assert_eq!(format!("{:?}", WasmValue::from(1.5f64)), "F64(1.5)");
+        assert_eq!(
+            format!("{:?}", WasmValue::from(0xffu128)),
+            "V128(0x000000000000000000000000000000ff)"
+        );
+        assert_eq!(
+            format!("{:?}", WasmValue::from_null_ref(RefType::ExternRef)),
+            "ExternRef(null)"
+        );
+    }
+
+    #[test]
+    fn test_types_value_v128_lanes() {
+        let val = WasmValue::from_i32x4([1, -2, 3, -4]);
+        assert_eq!(val.ty(), ValType::V128);
+        assert_eq!(val.as_i32x4(), Some([1, -2, 3, -4]));
+        assert_eq!(val.as_u32x4(), Some([1, u32::MAX - 1, 3, u32::MAX - 3]));
+        // the first lane is stored in the least significant bits
+        assert_eq!(val.to_v128() as u128 & 0xffff_ffff, 1);
+        assert_eq!(
+            val.as_u8x16().unwrap()[..8],
+            [1, 0, 0, 0, 0xfe, 0xff, 0xff, 0xff]
+        );
+
+        let lanes = [
+            0,
+            1,
+            -1,
+            i8::MIN,
+            i8::MAX,
+            5,
+            6,
+            7,
+            8,
+            9,
+            10,
+            11,
+            12,
+            13,
+            14,
+            15,
+        ];
+        assert_eq!(WasmValue::from_i8x16(lanes).as_i8x16(), Some(lanes));
+        let lanes = [0, 1, u16::MAX, 3, 4, 5, 6, 7];
+        assert_eq!(WasmValue::from_u16x8(lanes).as_u16x8(), Some(lanes));
+        let lanes = [i16::MIN, -1, 0, 1, 2, 3, 4, i16::MAX];
+        assert_eq!(WasmValue::from_i16x8(lanes).as_i16x8(), Some(lanes));
+        let lanes = [i64::MIN, i64::MAX];
+        assert_eq!(WasmValue::from_i64x2(lanes).as_i64x2(), Some(lanes));
+        assert_eq!(
+            WasmValue::from_i64x2(lanes).as_u64x2(),
+            Some([1 << 63, u64::MAX >> 1])
+        );
+        let lanes = [1.5f32, -0.0, f32::INFINITY, f32::MIN_POSITIVE];
+        assert_eq!(WasmValue::from_f32x4(lanes).as_f32x4(), Some(lanes));
+        let lanes = [13.14f64, -13.14];
+        assert_eq!(WasmValue::from_f64x2(lanes).as_f64x2(), Some(lanes));
+
+        // not a v128 value
+        assert!(WasmValue::from(1314i32).as_i32x4().is_none());
+        assert!(WasmValue::from(1314.0f64).as_f64x2().is_none());
+    }
+
     #[test]
     fn test_types_string() {
         let s: WasmEdgeString = "hello".into();
@@ -70,6 +70,87 @@ pub fn log_error_info() {
     unsafe { ffi::WasmEdge_LogSetErrorLevel() }
 }
 
+/// Forwards the log messages of WasmEdge to the [log](https://docs.rs/log) crate instead of the console.
+///
+/// The log messages are filtered by the current [max level](https://docs.rs/log/0.4/log/fn.max_level.html) of the
+/// `log` crate, so call this function again after changing the max level. The target of the log messages is
+/// `wasmedge`, or `wasmedge::<logger name>` for the named loggers of WasmEdge, such as the ones of the plugins.
+///
+/// To collect the log messages with [tracing](https://docs.rs/tracing), install a `LogTracer` of
+/// [tracing-log](https://docs.rs/tracing-log), which converts the log messages into tracing events.
+///
+/// # Example
+///
+/// ```ignore
+/// env_logger::init();
+/// wasmedge_sys::utils::log_to_rust();
+/// ```
+#[cfg(feature = "log")]
+pub fn log_to_rust() {
+    let level = match log::max_level() {
+        log::LevelFilter::Off => ffi::WasmEdge_LogLevel_Off,
+        log::LevelFilter::Error => ffi::WasmEdge_LogLevel_Error,
+        log::LevelFilter::Warn => ffi::WasmEdge_LogLevel_Warn,
+        log::LevelFilter::Info => ffi::WasmEdge_LogLevel_Info,
+        log::LevelFilter::Debug => ffi::WasmEdge_LogLevel_Debug,
+        log::LevelFilter::Trace => ffi::WasmEdge_LogLevel_Trace,
+    };
+    unsafe {
+        ffi::WasmEdge_LogSetLevel(level);
+        ffi::WasmEdge_LogSetCallback(Some(forward_log), std::ptr::null_mut());
+    }
+}
+
+/// Restores the output of the log messages of WasmEdge to the console.
+#[cfg(feature = "log")]
+pub fn log_to_console() {
+    unsafe { ffi::WasmEdge_LogSetCallback(None, std::ptr::null_mut()) }
+}
+
+#[cfg(feature = "log")]
+extern "C" fn forward_log(
+    _data: *mut std::os::raw::c_void,
+    level: ffi::WasmEdge_LogLevel,
+    logger_name: ffi::WasmEdge_String,
+    message: ffi::WasmEdge_String,
+) {
+    let level = match level {
+        ffi::WasmEdge_LogLevel_Trace => log::Level::Trace,
+        ffi::WasmEdge_LogLevel_Debug => log::Level::Debug,
+        ffi::WasmEdge_LogLevel_Info => log::Level::Info,
+        ffi::WasmEdge_LogLevel_Warn => log::Level::Warn,
+        ffi::WasmEdge_LogLevel_Error | ffi::WasmEdge_LogLevel_Critical => log::Level::Error,
+        _ => return,
+    };
+    let to_str = |s: ffi::WasmEdge_String| match s.Length {
+        0 => std::borrow::Cow::Borrowed(""),
+        len => String::from_utf8_lossy(unsafe {
+            std::slice::from_raw_parts(s.Buf as *const u8, len as usize)
+        }),
+    };
+
+    // a panic of the logger must not unwind into the C++ frames of WasmEdge
+    let _ = std::panic::catch_unwind(|| {
+        let target = match to_str(logger_name) {
+            name if name.is_empty() => std::borrow::Cow::Borrowed("wasmedge"),
+            name => std::borrow::Cow::Owned(format!("wasmedge::{}", name)),
+        };
+        let metadata = log::Metadata::builder()
+            .level(level)
+            .target(&target)
+            .build();
+        let logger = log::logger();
+        if logger.enabled(&metadata) {
+            logger.log(
+                &log::Record::builder()
+                    .metadata(metadata)
+                    .args(format_args!("{}", to_str(message)))
+                    .build(),
+            );
+        }
+    });
+}
+
 // Checks the result of a `FFI` function.
 pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
     let code = unsafe {
@@ -179,6 +260,9 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x38 => Err(WasmEdgeError::Core(CoreError::Load(
             CoreLoadError::IllegalGrammar,
         ))),
+        0x3A => Err(WasmEdgeError::Core(CoreError::Load(
+            CoreLoadError::SharedTable,
+        ))),
 
         // Validation phase
         0x40 => Err(WasmEdgeError::Core(CoreError::Validation(
@@ -247,6 +331,9 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x55 => Err(WasmEdgeError::Core(CoreError::Validation(
             CoreValidationError::InvalidLaneIdx,
         ))),
+        0x56 => Err(WasmEdgeError::Core(CoreError::Validation(
+            CoreValidationError::SharedMemoryNoMax,
+        ))),
 
         // Instantiation phase
         0x60 => Err(WasmEdgeError::Core(CoreError::Instantiation(
@@ -264,6 +351,12 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x64 => Err(WasmEdgeError::Core(CoreError::Instantiation(
             CoreInstantiationError::ElemSegDoesNotFit,
         ))),
+        0x65 => Err(WasmEdgeError::Core(CoreError::Instantiation(
+            CoreInstantiationError::UnknownModule,
+        ))),
+        0x66 => Err(WasmEdgeError::Core(CoreError::Instantiation(
+            CoreInstantiationError::ModuleInUse,
+        ))),
 
         // Execution phase
         0x80 => Err(WasmEdgeError::Core(CoreError::Execution(
@@ -311,6 +404,12 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x8E => Err(WasmEdgeError::Core(CoreError::Execution(
             CoreExecutionError::RefTypeMismatch,
         ))),
+        0x8F => Err(WasmEdgeError::Core(CoreError::Execution(
+            CoreExecutionError::UnalignedAtomicAccess,
+        ))),
+        0x90 => Err(WasmEdgeError::Core(CoreError::Execution(
+            CoreExecutionError::ExpectSharedMemory,
+        ))),
 
         _ => panic!("unknown error code: {}", code),
     }
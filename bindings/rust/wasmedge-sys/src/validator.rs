@@ -1,12 +1,14 @@
 //! Defines WasmEdge Validator struct.
 
-use crate::{error::WasmEdgeError, ffi, utils::check, Config, Module, WasmEdgeResult};
+use crate::{diagnostic::diagnosed, error::WasmEdgeError, ffi, Config, Module, WasmEdgeResult};
+use std::sync::Mutex;
 
 /// Struct of WasmEdge Validator.
 #[derive(Debug)]
 pub struct Validator {
     pub(crate) inner: InnerValidator,
     pub(crate) registered: bool,
+    lock: Mutex<()>,
 }
 impl Validator {
     /// Creates a new [Validator] to be associated with the given global configuration.
@@ -32,6 +34,7 @@ impl Validator {
             false => Ok(Self {
                 inner: InnerValidator(ctx),
                 registered: false,
+                lock: Mutex::new(()),
             }),
         }
     }
@@ -46,14 +49,24 @@ impl Validator {
     ///
     /// # Error
     ///
-    /// If the validation fails, then an error is returned.
+    /// If the validation fails, then an error is returned, which is a
+    /// [Diagnosed](crate::error::WasmEdgeError::Diagnosed) error if the location of the failure is known. The
+    /// [Diagnostic](crate::Diagnostic) reports the section, the function index, the byte offset of the failed
+    /// instruction, and the expected and actual types of a type mismatch.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Err(WasmEdgeError::Diagnosed { error, diagnostic }) = validator.validate(&module) {
+    ///     eprintln!("{} in {}", error, diagnostic);
+    /// }
+    /// ```
     pub fn validate(&self, module: &Module) -> WasmEdgeResult<()> {
-        unsafe {
-            check(ffi::WasmEdge_ValidatorValidate(
-                self.inner.0,
-                module.inner.0,
-            ))
-        }
+        diagnosed(
+            &self.lock,
+            || unsafe { ffi::WasmEdge_ValidatorValidate(self.inner.0, module.inner.0) },
+            || unsafe { ffi::WasmEdge_ValidatorGetDiagnostic(self.inner.0) },
+        )
     }
 }
 impl Drop for Validator {
@@ -72,11 +85,15 @@ unsafe impl Sync for InnerValidator {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Config, Loader};
+    use crate::{
+        error::{CoreError, CoreValidationError},
+        Config, Loader,
+    };
     use std::{
         sync::{Arc, Mutex},
         thread,
     };
+    use wasmedge_types::ValType;
 
     #[test]
     fn test_validator() {
@@ -111,6 +128,68 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validator_diagnostic() {
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+
+        // a valid module leaves no diagnostic
+        let result = loader.from_wat(r#"(module (func (export "nop")))"#);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert!(validator.validate(&module).is_ok());
+
+        // a type mismatch in the second function
+        let result = loader.from_wat(
+            r#"
+            (module
+                (import "env" "f" (func))
+                (func (result i32)
+                    i32.const 1
+                    i64.const 2
+                    i32.add)
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_err());
+        let (error, diagnostic) = match result.unwrap_err() {
+            WasmEdgeError::Diagnosed { error, diagnostic } => (error, diagnostic),
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(
+            error,
+            CoreError::Validation(CoreValidationError::TypeCheckFailed)
+        );
+        assert_eq!(diagnostic.section.as_deref(), Some("code section"));
+        assert_eq!(diagnostic.func_index, Some(1));
+        assert_eq!(diagnostic.instruction.as_deref(), Some("i32.add"));
+        assert!(diagnostic.offset.is_some());
+        assert_eq!(diagnostic.expected, vec![ValType::I32]);
+        assert_eq!(diagnostic.actual, vec![ValType::I64]);
+
+        // a truncated module fails to load
+        let result = loader.from_bytes(b"\0asm\x01\0\0\0\x01\x05\x01");
+        assert!(result.is_err());
+        let diagnostic = match result.unwrap_err() {
+            WasmEdgeError::Diagnosed { diagnostic, .. } => diagnostic,
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(diagnostic.section.as_deref(), Some("type section"));
+        assert!(diagnostic.offset.is_some());
+
+        // a WAT module that fails to parse after it carries no stale diagnostic
+        let result = loader.from_wat("(module");
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), WasmEdgeError::Wat(_)));
+    }
+
     #[test]
     fn test_validator_send() {
         // create a Validator
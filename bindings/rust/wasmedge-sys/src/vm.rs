@@ -1,26 +1,57 @@
 //! Defines WasmEdge Vm struct.
 
 use crate::{
+    ast_module::NameSection,
     error::{VmError, WasmEdgeError},
     ffi::{self, WasmEdge_HostRegistration_Wasi, WasmEdge_HostRegistration_WasmEdge_Process},
     instance::{
-        function::{FuncType, InnerFuncType},
+        function::{take_host_failure, FuncType, InnerFuncType},
         module::InnerInstance,
     },
-    statistics::{InnerStat, Statistics},
-    store::{InnerStore, Store},
-    types::WasmEdgeString,
-    utils::{self, check},
-    Config, ImportObject, Instance, Module, WasiModule, WasmEdgeProcessModule, WasmEdgeResult,
-    WasmValue,
+    profile::Profile,
+    statistics::{self, InnerStat, Statistics},
+    store::{self, InnerStore, SharedImports, Store},
+    types::{unroot_extern_refs, WasmEdgeString},
+    utils::check,
+    Config, ImportObject, Instance, Loader, Module, WasiModule, WasmEdgeProcessModule,
+    WasmEdgeResult, WasmValue,
+};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
-use std::{collections::HashMap, path::Path};
 
 /// A [Vm] defines a virtual environment for managing WebAssembly programs.
 #[derive(Debug)]
 pub struct Vm {
     pub(crate) inner: InnerVm,
     imports: HashMap<String, ImportObject>,
+    /// The import objects shared with the store of the Vm.
+    store_imports: SharedImports,
+    loader: Loader,
+    /// The address of the active module instance.
+    active: Option<usize>,
+    /// The addresses of the named module instances registered from wasm modules, which are owned by the Vm.
+    modules: HashMap<String, usize>,
+    /// The name section of the loaded module.
+    loaded_names: Option<NameSection>,
+    /// The name section of the active module, used to resolve the function names of trap backtraces.
+    active_names: Option<NameSection>,
+    /// The name sections of the named modules registered from wasm modules.
+    names: HashMap<String, NameSection>,
+    /// The fuel consumed by the latest function call.
+    pub(crate) fuel_consumed: AtomicU64,
+    /// The time at which the memory pages were sampled for the metrics.
+    #[cfg(feature = "metrics")]
+    pub(crate) memory_sampled_at: AtomicU64,
+    /// The pages of the exported memories of the module instances owned by the Vm, which are accounted in the metrics,
+    /// keyed by the module names, or `None` for the active module instance.
+    #[cfg(feature = "metrics")]
+    pub(crate) memory_pages: std::sync::Mutex<HashMap<Option<String>, crate::metrics::MemoryPages>>,
 }
 impl Vm {
     /// Creates a new [Vm] to be associated with the given [configuration](crate::Config) and [store](crate::Store).
@@ -35,6 +66,14 @@ impl Vm {
     ///
     /// If fail to create, then an error is returned.
     pub fn create(config: Option<Config>, store: Option<&mut Store>) -> WasmEdgeResult<Self> {
+        let store_imports = store
+            .as_deref()
+            .map(|store| Arc::clone(&store.imports))
+            .unwrap_or_default();
+        let loader = match &config {
+            Some(config) => Loader::create(Some(Config::copy_from(config)?))?,
+            None => Loader::create(None)?,
+        };
         let ctx = match config {
             Some(mut config) => {
                 let vm_ctx = match store {
@@ -59,6 +98,18 @@ impl Vm {
             false => Ok(Self {
                 inner: InnerVm(ctx),
                 imports: HashMap::new(),
+                store_imports,
+                loader,
+                active: None,
+                modules: HashMap::new(),
+                loaded_names: None,
+                active_names: None,
+                names: HashMap::new(),
+                fuel_consumed: AtomicU64::new(0),
+                #[cfg(feature = "metrics")]
+                memory_sampled_at: AtomicU64::new(0),
+                #[cfg(feature = "metrics")]
+                memory_pages: Default::default(),
             }),
         }
     }
@@ -78,7 +129,7 @@ impl Vm {
     ///
     /// * `mod_name` - The name for the WASM module to be registered.
     ///
-    /// * `path` - The file path to the target WASM file.
+    /// * `path` - The file path to the target WASM file, or a WAT file with the suffix `.wat`.
     ///
     /// # Error
     ///
@@ -88,17 +139,8 @@ impl Vm {
         mod_name: impl AsRef<str>,
         path: impl AsRef<Path>,
     ) -> WasmEdgeResult<()> {
-        let path = utils::path_to_cstring(path.as_ref())?;
-        let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
-            check(ffi::WasmEdge_VMRegisterModuleFromFile(
-                self.inner.0,
-                mod_name.as_raw(),
-                path.as_ptr(),
-            ))?
-        };
-
-        Ok(())
+        let module = self.loader.from_file(path)?;
+        self.register_wasm_from_module(mod_name, module)
     }
 
     /// Registers a given WasmEdge module instance that implements the [ImportObject](crate::ImportObject) trait into the [Vm], and instantiates it.
@@ -169,7 +211,7 @@ impl Vm {
     ///
     /// * `mod_name` - The name of the WASM module to be registered.
     ///
-    /// * `bytes` - The in-memory wasm bytes.
+    /// * `bytes` - The in-memory wasm bytes, or the text of a WAT module.
     ///
     /// # Error
     ///
@@ -179,17 +221,8 @@ impl Vm {
         mod_name: impl AsRef<str>,
         bytes: &[u8],
     ) -> WasmEdgeResult<()> {
-        let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
-            check(ffi::WasmEdge_VMRegisterModuleFromBuffer(
-                self.inner.0,
-                mod_name.as_raw(),
-                bytes.as_ptr(),
-                bytes.len() as u32,
-            ))?;
-        }
-
-        Ok(())
+        let module = self.loader.from_bytes(bytes)?;
+        self.register_wasm_from_module(mod_name, module)
     }
 
     /// Registers a given WasmEdge AST [Module](crate::Module) into the [Vm], and instantiates it.
@@ -216,18 +249,62 @@ impl Vm {
         mod_name: impl AsRef<str>,
         mut module: Module,
     ) -> WasmEdgeResult<()> {
-        let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
+        let raw_name: WasmEdgeString = mod_name.as_ref().into();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMRegisterModuleFromASTModule(
                 self.inner.0,
-                mod_name.as_raw(),
+                raw_name.as_raw(),
                 module.inner.0,
-            ))?;
-        }
+            ))
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_instantiation(start, &result);
+        result?;
+
+        let store_ctx = unsafe { ffi::WasmEdge_VMGetStoreContext(self.inner.0) };
+        let instance_ctx = unsafe { ffi::WasmEdge_StoreFindModule(store_ctx, raw_name.as_raw()) };
+        self.modules
+            .insert(mod_name.as_ref().to_string(), instance_ctx as usize);
+        match module.name_section() {
+            Ok(Some(names)) => self.names.insert(mod_name.as_ref().to_string(), names),
+            _ => self.names.remove(mod_name.as_ref()),
+        };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_vm_memory_pages(self, Some(mod_name.as_ref()));
+
         module.inner.0 = std::ptr::null_mut();
         Ok(())
     }
 
+    /// Unregisters a named module from the [Vm], after which another module can be registered with the same name.
+    ///
+    /// The module instance registered from a wasm file, bytes or [module](crate::Module) is destroyed, and the [import object](crate::ImportObject) registered with [register_wasm_from_import](crate::Vm::register_wasm_from_import) is dropped.
+    ///
+    /// # Argument
+    ///
+    /// * `mod_name` - The name of the module to unregister.
+    ///
+    /// # Error
+    ///
+    /// If the target module is not found, or it is imported by any live module instance in the [Vm], then an error is returned.
+    pub fn unregister_module(&mut self, mod_name: impl AsRef<str>) -> WasmEdgeResult<()> {
+        let raw_name: WasmEdgeString = mod_name.as_ref().into();
+        store::check_unregister(
+            unsafe { ffi::WasmEdge_VMUnregisterModule(self.inner.0, raw_name.as_raw()) },
+            mod_name.as_ref(),
+        )?;
+        if let Some(addr) = self.modules.remove(mod_name.as_ref()) {
+            release_instance(addr);
+            #[cfg(feature = "metrics")]
+            crate::metrics::release_vm_memory_pages(self, Some(mod_name.as_ref()));
+        }
+        self.names.remove(mod_name.as_ref());
+        self.imports.remove(mod_name.as_ref());
+        Ok(())
+    }
+
     /// Runs a [function](crate::Function) defined in a WASM file.
     ///
     /// The workflow of the function can be summarized as the following steps:
@@ -240,7 +317,7 @@ impl Vm {
     ///
     /// # Arguments
     ///
-    /// * `path` - The file path to a WASM file.
+    /// * `path` - The file path to a WASM file, or a WAT file with the suffix `.wat`.
     ///
     /// * `func_name` - The name of the [function](crate::Function).
     ///
@@ -278,7 +355,7 @@ impl Vm {
     ///
     /// # Arguments
     ///
-    /// * `bytes` - The in-memory wasm bytes.
+    /// * `bytes` - The in-memory wasm bytes, or the text of a WAT module.
     ///
     /// * `func_name` - The name of the [function](crate::Function).
     ///
@@ -362,6 +439,7 @@ impl Vm {
                 module.inner.0 as *const _,
             ))?;
         }
+        self.loaded_names = module.name_section().ok().flatten();
         Ok(())
     }
 
@@ -371,20 +449,14 @@ impl Vm {
     ///
     /// # Argument
     ///
-    /// * `bytes` - The in-memory wasm bytes.
+    /// * `bytes` - The in-memory wasm bytes, or the text of a WAT module.
     ///
     /// # Error
     ///
     /// If fail to load, then an error is returned.
     pub fn load_wasm_from_bytes(&mut self, bytes: &[u8]) -> WasmEdgeResult<()> {
-        unsafe {
-            check(ffi::WasmEdge_VMLoadWasmFromBuffer(
-                self.inner.0,
-                bytes.as_ptr() as *const _,
-                bytes.len() as u32,
-            ))?;
-        }
-        Ok(())
+        let module = self.loader.from_bytes(bytes)?;
+        self.load_wasm_from_module(&module)
     }
 
     /// Loads a WASM module from a WASM file.
@@ -393,20 +465,14 @@ impl Vm {
     ///
     /// # Argument
     ///
-    /// * `path` - The path to a WASM file.
+    /// * `path` - The path to a WASM file, or a WAT file with the suffix `.wat`.
     ///
     /// # Error
     ///
     /// If fail to load, then an error is returned.
     pub fn load_wasm_from_file(&mut self, path: impl AsRef<Path>) -> WasmEdgeResult<()> {
-        let path = utils::path_to_cstring(path.as_ref())?;
-        unsafe {
-            check(ffi::WasmEdge_VMLoadWasmFromFile(
-                self.inner.0,
-                path.as_ptr(),
-            ))?;
-        }
-        Ok(())
+        let module = self.loader.from_file(path)?;
+        self.load_wasm_from_module(&module)
     }
 
     /// Validates a WASM module loaded into the [Vm].
@@ -431,9 +497,22 @@ impl Vm {
     ///
     /// If fail to instantiate, then an error is returned.
     pub fn instantiate(&mut self) -> WasmEdgeResult<()> {
-        unsafe {
-            check(ffi::WasmEdge_VMInstantiate(self.inner.0))?;
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let result = unsafe { check(ffi::WasmEdge_VMInstantiate(self.inner.0)) };
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_instantiation(start, &result);
+        result?;
+
+        // the old active module instance is replaced
+        if let Some(addr) = self.active.take() {
+            release_instance(addr);
         }
+        let instance_ctx = unsafe { ffi::WasmEdge_VMGetActiveModule(self.inner.0 as *const _) };
+        self.active = Some(instance_ctx as usize);
+        self.active_names = self.loaded_names.clone();
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_vm_memory_pages(self, None);
         Ok(())
     }
 
@@ -450,24 +529,34 @@ impl Vm {
     ///
     /// # Error
     ///
-    /// If fail to run the WASM function, then an error is returned.
+    /// If the arguments mismatch the parameters of the WASM function, or a host function returns values mismatching its
+    /// function type, then a [FuncTypeMismatch](crate::error::FuncError::FuncTypeMismatch) error is returned.
+    ///
+    /// If fail to run the WASM function, then an error is returned. If the WASM function traps or a host function panics,
+    /// then a [Trap](crate::error::Trap) is returned, which carries the backtrace of the WASM call stack and the panic payload.
     pub fn run_function(
         &self,
         func_name: impl AsRef<str>,
         params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
         // prepare parameters
-        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let params = params.into_iter().collect::<Vec<_>>();
+        let func_type = self.get_function_type(func_name.as_ref())?;
+        func_type.check_params(&params)?;
+        let raw_params = params.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
 
         // prepare returns
-        let func_type = self.get_function_type(func_name.as_ref())?;
 
         // get the info of the funtion return
         let returns_len = unsafe { ffi::WasmEdge_FunctionTypeGetReturnsLength(func_type.inner.0) };
         let mut returns = Vec::with_capacity(returns_len as usize);
 
         let func_name: WasmEdgeString = func_name.as_ref().into();
-        unsafe {
+        take_host_failure();
+        #[cfg(feature = "metrics")]
+        let recorder = crate::metrics::ExecutionRecorder::start(self);
+        let cost = self.total_cost();
+        let result = unsafe {
             check(ffi::WasmEdge_VMExecute(
                 self.inner.0,
                 func_name.as_raw(),
@@ -475,9 +564,14 @@ impl Vm {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
+            ))
+        };
+        self.record_fuel_consumed(cost);
+        let result = result.map_err(|err| self.trap(err));
+        #[cfg(feature = "metrics")]
+        recorder.finish(self, None, &result);
+        result?;
+        unsafe { returns.set_len(returns_len as usize) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
@@ -496,7 +590,11 @@ impl Vm {
     ///
     /// # Error
     ///
-    /// If fail to run the WASM function, then an error is returned.
+    /// If the arguments mismatch the parameters of the WASM function, or a host function returns values mismatching its
+    /// function type, then a [FuncTypeMismatch](crate::error::FuncError::FuncTypeMismatch) error is returned.
+    ///
+    /// If fail to run the WASM function, then an error is returned. If the WASM function traps or a host function panics,
+    /// then a [Trap](crate::error::Trap) is returned, which carries the backtrace of the WASM call stack and the panic payload.
     pub fn run_registered_function(
         &mut self,
         mod_name: impl AsRef<str>,
@@ -504,29 +602,40 @@ impl Vm {
         params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
         // prepare parameters
-        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let params = params.into_iter().collect::<Vec<_>>();
+        let func_type = self.get_registered_function_type(mod_name.as_ref(), func_name.as_ref())?;
+        func_type.check_params(&params)?;
+        let raw_params = params.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
 
         // prepare returns
-        let func_type = self.get_registered_function_type(mod_name.as_ref(), func_name.as_ref())?;
 
         // get the info of the funtion return
         let returns_len = unsafe { ffi::WasmEdge_FunctionTypeGetReturnsLength(func_type.inner.0) };
         let mut returns = Vec::with_capacity(returns_len as usize);
 
-        let mod_name: WasmEdgeString = mod_name.as_ref().into();
+        let raw_mod_name: WasmEdgeString = mod_name.as_ref().into();
         let func_name: WasmEdgeString = func_name.as_ref().into();
-        unsafe {
+        take_host_failure();
+        #[cfg(feature = "metrics")]
+        let recorder = crate::metrics::ExecutionRecorder::start(self);
+        let cost = self.total_cost();
+        let result = unsafe {
             check(ffi::WasmEdge_VMExecuteRegistered(
                 self.inner.0,
-                mod_name.as_raw(),
+                raw_mod_name.as_raw(),
                 func_name.as_raw(),
                 raw_params.as_ptr(),
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
+            ))
+        };
+        self.record_fuel_consumed(cost);
+        let result = result.map_err(|err| self.trap(err));
+        #[cfg(feature = "metrics")]
+        recorder.finish(self, Some(mod_name.as_ref()), &result);
+        result?;
+        unsafe { returns.set_len(returns_len as usize) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
@@ -569,6 +678,56 @@ impl Vm {
     /// Resets the [`Vm`].
     pub fn reset(&mut self) {
         unsafe { ffi::WasmEdge_VMCleanup(self.inner.0) }
+        if let Some(addr) = self.active.take() {
+            release_instance(addr);
+            #[cfg(feature = "metrics")]
+            crate::metrics::release_vm_memory_pages(self, None);
+        }
+        self.loaded_names = None;
+        self.active_names = None;
+    }
+
+    /// Returns the fuel consumed by the latest call of [run_function](crate::Vm::run_function) or
+    /// [run_registered_function](crate::Vm::run_registered_function), including the fuel consumed before the call
+    /// fails.
+    ///
+    /// The fuel is measured only if the cost measuring option of the [Config](crate::Config) is enabled.
+    pub fn fuel_consumed(&self) -> u64 {
+        self.fuel_consumed.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total cost recorded in the [Statistics] of the [Vm].
+    fn total_cost(&self) -> u64 {
+        unsafe {
+            let stat_ctx = ffi::WasmEdge_VMGetStatisticsContext(self.inner.0);
+            ffi::WasmEdge_StatisticsGetTotalCost(stat_ctx)
+        }
+    }
+
+    /// Records the fuel consumed since the total cost was `cost`.
+    fn record_fuel_consumed(&self, cost: u64) {
+        let consumed = self.total_cost().saturating_sub(cost);
+        self.fuel_consumed.store(consumed, Ordering::Relaxed);
+    }
+
+    /// Attaches the backtrace of the latest trap to the error of running a WASM function, of which the function names
+    /// are resolved from the name sections of the modules.
+    fn trap(&self, err: WasmEdgeError) -> WasmEdgeError {
+        crate::executor::trap(err, |module_name, func_index| {
+            self.func_name(module_name, func_index)
+        })
+    }
+
+    /// Resolves the name of a function from the name section of the module, which is the active module if the module
+    /// name is `None`.
+    fn func_name(&self, module_name: Option<&str>, func_index: u32) -> Option<String> {
+        let names = match module_name {
+            Some(name) => self.names.get(name),
+            None => self.active_names.as_ref(),
+        };
+        names
+            .and_then(|names| names.func_name(func_index))
+            .map(ToString::to_string)
     }
 
     /// Returns the length of the exported function list.
@@ -649,6 +808,7 @@ impl Vm {
             false => Ok(Store {
                 inner: InnerStore(store_ctx),
                 registered: true,
+                imports: Arc::clone(&self.store_imports),
             }),
         }
     }
@@ -665,6 +825,33 @@ impl Vm {
         }
     }
 
+    /// Returns the profile recorded by the [Statistics](crate::Statistics) of the [Vm], of which the function names
+    /// are resolved from the name sections of the modules.
+    ///
+    /// The profiling mode is turned on by [Statistics::set_profiling](crate::Statistics::set_profiling).
+    ///
+    /// # Error
+    ///
+    /// If fail to get the [Statistics](crate::Statistics) of the [Vm], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// vm.statistics_mut()?.set_profiling(true);
+    /// vm.run_wasm_from_file("fib.wasm", "fib", [WasmValue::from_i32(20)])?;
+    /// let profile = vm.profile()?;
+    /// for function in profile.flat() {
+    ///     println!("{:?}: {:?}", function.func_name, function.time);
+    /// }
+    /// ```
+    pub fn profile(&self) -> WasmEdgeResult<Profile> {
+        let mut profile = self.statistics_mut()?.profile();
+        for entry in profile.entries.iter_mut() {
+            entry.func_name = self.func_name(entry.module_name.as_deref(), entry.func_index);
+        }
+        Ok(profile)
+    }
+
     pub fn active_module(&self) -> WasmEdgeResult<Instance> {
         let ctx = unsafe { ffi::WasmEdge_VMGetActiveModule(self.inner.0 as *const _) };
         match ctx.is_null() {
@@ -672,6 +859,8 @@ impl Vm {
             false => Ok(Instance {
                 inner: InnerInstance(ctx as *mut _),
                 registered: true,
+                #[cfg(feature = "metrics")]
+                memory_pages: Default::default(),
             }),
         }
     }
@@ -690,12 +879,30 @@ impl Vm {
         store.contains(mod_name.as_ref())
     }
 }
+/// Releases the rooted host objects of a module instance owned by a vm, which is about to be destroyed.
+fn release_instance(addr: usize) {
+    unroot_extern_refs(addr);
+}
+
 impl Drop for Vm {
     fn drop(&mut self) {
         if !self.inner.0.is_null() {
+            statistics::remove_fuel_handler(unsafe {
+                ffi::WasmEdge_VMGetStatisticsContext(self.inner.0)
+            });
             unsafe { ffi::WasmEdge_VMDelete(self.inner.0) };
         }
 
+        // release the module instances owned by the vm
+        for addr in self
+            .active
+            .take()
+            .into_iter()
+            .chain(self.modules.drain().map(|(_, addr)| addr))
+        {
+            release_instance(addr);
+        }
+
         // drop imports
         self.imports.drain();
     }
@@ -712,16 +919,461 @@ mod tests {
     use crate::{
         error::{
             CoreCommonError, CoreError, CoreExecutionError, CoreInstantiationError, CoreLoadError,
-            InstanceError, StoreError, VmError, WasmEdgeError,
+            FuncError, FuncTypePosition, InstanceError, StoreError, VmError, WasmEdgeError,
         },
         utils, Config, FuncType, Function, ImportInstance, ImportModule, ImportObject, Loader,
-        Module, Store, WasiModule, WasmEdgeProcessModule, WasmValue,
+        Module, ProfileMetric, Store, WasiModule, WasmEdgeProcessModule, WasmValue,
     };
     use std::{
         sync::{Arc, Mutex},
         thread,
+        time::Duration,
     };
-    use wasmedge_types::ValType;
+    use wasmedge_types::{cost::CostTable, wat2wasm, ValType};
+
+    #[test]
+    fn test_vm_trap_backtrace() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let wasm = br#"
+            (module
+                (func $divide (param i32) (result i32)
+                    i32.const 1
+                    local.get 0
+                    i32.div_s)
+                (func $entry (export "entry") (param i32) (result i32)
+                    local.get 0
+                    call $divide)
+            )
+        "#;
+
+        // trap in the active module
+        let result = vm.run_wasm_from_bytes(wasm, "entry", [WasmValue::from_i32(0)]);
+        assert!(result.is_err());
+        let trap = match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => trap,
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(
+            trap.error,
+            CoreError::Execution(CoreExecutionError::DivideByZero)
+        );
+        assert_eq!(trap.frames.len(), 2);
+        assert_eq!(trap.frames[0].module_name, None);
+        assert_eq!(trap.frames[0].func_index, 0);
+        assert_eq!(trap.frames[0].func_name.as_deref(), Some("divide"));
+        assert!(trap.frames[0].offset.is_some());
+        assert_eq!(trap.frames[1].func_index, 1);
+        assert_eq!(trap.frames[1].func_name.as_deref(), Some("entry"));
+        assert!(trap.frames[1].offset.is_some());
+        assert!(trap.frames[0].offset < trap.frames[1].offset);
+        let message = trap.to_string();
+        assert!(message.contains("wasm backtrace:"));
+        assert!(message.contains("<module>!divide @ 0x"));
+        assert!(message.contains("<module>!entry @ 0x"));
+
+        // the same function runs without trapping
+        let result = vm.run_function("entry", [WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // trap in a registered module
+        let result = vm.register_wasm_from_bytes("math", wasm);
+        assert!(result.is_ok());
+        let result = vm.run_registered_function("math", "entry", [WasmValue::from_i32(0)]);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => {
+                assert_eq!(trap.frames.len(), 2);
+                assert_eq!(trap.frames[0].module_name.as_deref(), Some("math"));
+                assert_eq!(trap.frames[0].func_name.as_deref(), Some("divide"));
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_vm_fuel() {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.measure_cost(true);
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let wasm = br#"
+            (module
+                (func (export "count") (param i32) (result i32)
+                    (block
+                        (loop
+                            local.get 0
+                            i32.eqz
+                            br_if 1
+                            local.get 0
+                            i32.const 1
+                            i32.sub
+                            local.set 0
+                            br 0))
+                    local.get 0)
+            )
+        "#;
+
+        // the fuel is unlimited by default
+        let result = vm.run_wasm_from_bytes(wasm, "count", [WasmValue::from_i32(10)]);
+        assert!(result.is_ok());
+        let consumed = vm.fuel_consumed();
+        assert!(consumed > 0);
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let mut stat = result.unwrap();
+        assert_eq!(stat.cost_in_total(), consumed);
+
+        // abort the execution if the fuel is exhausted
+        stat.set_cost_limit(consumed + consumed / 2);
+        assert_eq!(stat.remaining_fuel(), consumed / 2);
+        let result = vm.run_function("count", [WasmValue::from_i32(10)]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Common(CoreCommonError::CostLimitExceeded))
+        );
+        assert_eq!(vm.fuel_consumed(), consumed / 2);
+        assert_eq!(stat.remaining_fuel(), 0);
+
+        // refuel and resume the execution
+        let refills = Arc::new(Mutex::new(0));
+        let refills_cloned = Arc::clone(&refills);
+        stat.on_out_of_fuel(move |_| {
+            *refills_cloned.lock().unwrap() += 1;
+            10
+        });
+        let result = vm.run_function("count", [WasmValue::from_i32(10)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 0);
+        assert_eq!(vm.fuel_consumed(), consumed);
+        assert!(*refills.lock().unwrap() > 0);
+
+        // stop refueling
+        stat.on_out_of_fuel(|_| 0);
+        let result = vm.run_function("count", [WasmValue::from_i32(10)]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Common(CoreCommonError::CostLimitExceeded))
+        );
+    }
+
+    #[test]
+    fn test_vm_cost_table() {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.measure_cost(true);
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // only i32.sub costs
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let mut stat = result.unwrap();
+        let result = CostTable::uniform(0).cost_by_name("i32.sub", 5);
+        assert!(result.is_ok());
+        stat.set_cost_table(result.unwrap());
+
+        let wasm = br#"
+            (module
+                (func (export "count") (param i32) (result i32)
+                    (block
+                        (loop
+                            (br_if 1 (i32.eqz (local.get 0)))
+                            (local.set 0 (i32.sub (local.get 0) (i32.const 1)))
+                            (br 0)))
+                    local.get 0)
+            )
+        "#;
+        let result = vm.run_wasm_from_bytes(wasm, "count", [WasmValue::from_i32(10)]);
+        assert!(result.is_ok());
+        assert_eq!(vm.fuel_consumed(), 50);
+        assert_eq!(stat.cost_in_total(), 50);
+    }
+
+    #[test]
+    fn test_vm_profile() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // create an import module with a slow host function
+        let result = ImportModule::create("host");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = FuncType::create(vec![], vec![]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_| {
+                thread::sleep(Duration::from_millis(10));
+                Ok(vec![])
+            }),
+            0,
+        );
+        assert!(result.is_ok());
+        import.add_func("wait", result.unwrap());
+        let result = vm.register_wasm_from_import(ImportObject::Import(import));
+        assert!(result.is_ok());
+
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let mut stat = result.unwrap();
+        stat.set_profiling(true);
+
+        let wasm = br#"
+            (module
+                (import "host" "wait" (func $wait))
+                (func $square (param i32) (result i32)
+                    local.get 0
+                    local.get 0
+                    i32.mul)
+                (func $main (export "main") (param i32) (result i32)
+                    call $wait
+                    local.get 0
+                    call $square
+                    call $square)
+            )
+        "#;
+        let result = vm.run_wasm_from_bytes(wasm, "main", [WasmValue::from_i32(3)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 81);
+
+        let result = vm.profile();
+        assert!(result.is_ok());
+        let profile = result.unwrap();
+        assert_eq!(profile.entries().len(), 3);
+        let main = &profile.entries()[0];
+        assert_eq!(main.parent, None);
+        assert_eq!(main.func_name.as_deref(), Some("main"));
+        assert!(main.instr_count > 0);
+        let wait = profile
+            .entries()
+            .iter()
+            .find(|entry| entry.is_host)
+            .unwrap();
+        assert_eq!(wait.parent, Some(0));
+        assert_eq!(wait.module_name.as_deref(), Some("host"));
+        assert_eq!(wait.func_index, 0);
+        assert!(wait.time >= Duration::from_millis(10));
+        let square = profile
+            .entries()
+            .iter()
+            .find(|entry| entry.func_name.as_deref() == Some("square"))
+            .unwrap();
+        assert_eq!(square.parent, Some(0));
+        assert_eq!(square.module_name, None);
+        // both calls of `square` run `local.get`, `local.get`, `i32.mul` and `end`
+        assert_eq!(square.instr_count, 8);
+        assert_eq!(square.cost, 8);
+
+        let flat = profile.flat();
+        assert_eq!(flat.len(), 3);
+        assert!(flat[0].is_host);
+        let folded = profile.folded(ProfileMetric::Instructions);
+        assert!(folded.contains("<module>!main;<module>!square 8\n"));
+        let folded = profile.folded(ProfileMetric::Time);
+        assert!(folded.contains("<module>!main;host!<func 0> "));
+
+        // the profile is cleared with the statistics
+        stat.clear();
+        let result = vm.profile();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_vm_host_function_panic() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // create an import module with a panicking host function
+        let result = ImportModule::create("host");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = FuncType::create(vec![ValType::I32], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|inputs: Vec<WasmValue>| {
+                if inputs[0].to_i32() == 0 {
+                    panic!("unexpected zero");
+                }
+                Ok(inputs)
+            }),
+            0,
+        );
+        assert!(result.is_ok());
+        import.add_func("check", result.unwrap());
+        let result = vm.register_wasm_from_import(ImportObject::Import(import));
+        assert!(result.is_ok());
+
+        let wasm = br#"
+            (module
+                (import "host" "check" (func $check (param i32) (result i32)))
+                (func (export "run") (param i32) (result i32)
+                    local.get 0
+                    call $check)
+            )
+        "#;
+
+        // the panic is reported as a trap carrying the panic payload
+        let result = vm.run_wasm_from_bytes(wasm, "run", [WasmValue::from_i32(0)]);
+        assert!(result.is_err());
+        let trap = match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => trap,
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(trap.host_panic.as_deref(), Some("unexpected zero"));
+        assert!(trap
+            .to_string()
+            .contains("host function panicked: unexpected zero"));
+
+        // the host function is still usable after the panic
+        let result = vm.run_function("run", [WasmValue::from_i32(3)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+    }
+
+    #[test]
+    fn test_vm_host_function_return_mismatch() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // create an import module with a host function returning a value of the wrong type
+        let result = ImportModule::create("host");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = FuncType::create(vec![], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_: Vec<WasmValue>| Ok(vec![WasmValue::from_f64(1.0)])),
+            0,
+        );
+        assert!(result.is_ok());
+        import.add_func("value", result.unwrap());
+        let result = vm.register_wasm_from_import(ImportObject::Import(import));
+        assert!(result.is_ok());
+
+        let wasm = br#"
+            (module
+                (import "host" "value" (func $value (result i32)))
+                (func (export "run") (result i32)
+                    call $value)
+            )
+        "#;
+        let result = vm.run_wasm_from_bytes(wasm, "run", []);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Return(0),
+                expected: Some(ValType::I32),
+                actual: Some(ValType::F64),
+            })
+        );
+
+        // too many arguments
+        let result = vm.run_function("run", [WasmValue::from_i32(1)]);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(
+            err,
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: None,
+                actual: Some(ValType::I32),
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "Function type mismatch at parameter 0: expected nothing, found I32"
+        );
+    }
+
+    #[test]
+    fn test_vm_unregister_module() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let plugin_v1 =
+            wat2wasm(br#"(module (func (export "version") (result i32) (i32.const 1)))"#);
+        assert!(plugin_v1.is_ok());
+        let result = vm.register_wasm_from_bytes("plugin", &plugin_v1.unwrap());
+        assert!(result.is_ok());
+
+        let app = wat2wasm(
+            br#"
+            (module
+                (import "plugin" "version" (func $version (result i32)))
+                (func (export "run") (result i32) (call $version))
+            )
+            "#,
+        );
+        assert!(app.is_ok());
+        let app = app.unwrap();
+        let result = vm.run_wasm_from_bytes(&app, "run", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // the plugin is imported by the active module
+        let result = vm.unregister_module("plugin");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::ModuleInUse("plugin".into()))
+        );
+
+        // replace the plugin
+        vm.reset();
+        let result = vm.unregister_module("plugin");
+        assert!(result.is_ok());
+        assert!(vm.contains_mod_name("plugin").is_err());
+        let result = vm.unregister_module("plugin");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::NotFoundModule("plugin".into()))
+        );
+
+        let plugin_v2 =
+            wat2wasm(br#"(module (func (export "version") (result i32) (i32.const 2)))"#);
+        assert!(plugin_v2.is_ok());
+        let result = vm.register_wasm_from_bytes("plugin", &plugin_v2.unwrap());
+        assert!(result.is_ok());
+        let result = vm.run_wasm_from_bytes(&app, "run", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+
+        // an import object can be unregistered and registered again
+        let result = ImportModule::create("extern");
+        assert!(result.is_ok());
+        let result = vm.register_wasm_from_import(ImportObject::Import(result.unwrap()));
+        assert!(result.is_ok());
+        let result = vm.unregister_module("extern");
+        assert!(result.is_ok());
+        let result = ImportModule::create("extern");
+        assert!(result.is_ok());
+        let result = vm.register_wasm_from_import(ImportObject::Import(result.unwrap()));
+        assert!(result.is_ok());
+    }
 
     #[test]
     fn test_vm_create() {
@@ -872,10 +1524,13 @@ mod tests {
         let empty_buffer: Vec<u8> = vec![];
         let result = vm.load_wasm_from_bytes(&empty_buffer);
         assert!(result.is_err());
-        assert_eq!(
+        assert!(matches!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Load(CoreLoadError::UnexpectedEnd))
-        );
+            WasmEdgeError::Diagnosed {
+                error: CoreError::Load(CoreLoadError::UnexpectedEnd),
+                ..
+            }
+        ));
     }
 
     #[test]
@@ -1060,7 +1715,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: None,
+            })
         );
 
         // run a function with the parameters of wrong type
@@ -1068,7 +1727,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: Some(ValType::I64),
+            })
         );
 
         // run a function: the specified function name is non-existant
@@ -1215,7 +1878,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: None,
+            })
         );
 
         // run a registered function with the parameters of wrong type
@@ -1223,7 +1890,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: Some(ValType::I64),
+            })
         );
 
         // run a registered function but give a wrong function name.
@@ -1364,7 +2035,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: None,
+            })
         );
 
         // run a function from a WASM file with the parameters of wrong type
@@ -1372,7 +2047,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: Some(ValType::I64),
+            })
         );
 
         // fun a function: the specified function name is non-existant
@@ -1418,17 +2097,24 @@ mod tests {
         let empty_buffer: Vec<u8> = Vec::new();
         let result = vm.run_wasm_from_bytes(&empty_buffer, "fib", [WasmValue::from_i32(5)]);
         assert!(result.is_err());
-        assert_eq!(
+        assert!(matches!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Load(CoreLoadError::UnexpectedEnd))
-        );
+            WasmEdgeError::Diagnosed {
+                error: CoreError::Load(CoreLoadError::UnexpectedEnd),
+                ..
+            }
+        ));
 
         // run a function with the empty parameters
         let result = vm.run_wasm_from_bytes(&buffer, "fib", []);
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: None,
+            })
         );
 
         // run a function with the parameters of wrong type
@@ -1436,7 +2122,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: Some(ValType::I64),
+            })
         );
 
         // fun a function: the specified function name is non-existant
@@ -1446,6 +2136,13 @@ mod tests {
             result.unwrap_err(),
             WasmEdgeError::Instance(InstanceError::NotFoundFunc("fib2".into()))
         );
+
+        // run a function from a WAT buffer
+        let wat = br#"(module (func (export "answer") (result i32) i32.const 42))"#;
+        let result = vm.run_wasm_from_bytes(wat, "answer", []);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 42);
     }
 
     #[test]
@@ -1480,7 +2177,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: None,
+            })
         );
 
         // run a function with the parameters of wrong type
@@ -1489,7 +2190,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+            WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+                position: FuncTypePosition::Param(0),
+                expected: Some(ValType::I32),
+                actual: Some(ValType::I64),
+            })
         );
 
         // fun a function: the specified function name is non-existant
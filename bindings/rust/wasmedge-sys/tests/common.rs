@@ -1,3 +1,4 @@
+use std::sync::atomic::{AtomicI32, Ordering};
 use wasmedge_sys::{FuncType, Function, ImportInstance, ImportModule, WasmValue};
 use wasmedge_types::ValType;
 
@@ -60,13 +61,15 @@ pub fn create_extern_module(name: impl AsRef<str>) -> ImportModule {
 
 fn extern_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
-        inputs[0]
+        &inputs[0]
     } else {
         return Err(2);
     };
     let val1 = val1
-        .extern_ref::<i32>()
-        .expect("fail to get i32 from an ExternRef");
+        .extern_ref()
+        .and_then(|extern_ref| extern_ref.downcast_ref::<AtomicI32>())
+        .expect("fail to get i32 from an ExternRef")
+        .load(Ordering::SeqCst);
 
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
@@ -79,14 +82,16 @@ fn extern_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
 
 fn extern_sub(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
-        inputs[0]
+        &inputs[0]
     } else {
         return Err(2);
     };
 
     let val1 = val1
-        .extern_ref::<i32>()
-        .expect("fail to get i32 from an ExternRef");
+        .extern_ref()
+        .and_then(|extern_ref| extern_ref.downcast_ref::<AtomicI32>())
+        .expect("fail to get i32 from an ExternRef")
+        .load(Ordering::SeqCst);
 
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
@@ -99,13 +104,15 @@ fn extern_sub(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
 
 fn extern_mul(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
-        inputs[0]
+        &inputs[0]
     } else {
         return Err(2);
     };
     let val1 = val1
-        .extern_ref::<i32>()
-        .expect("fail to get i32 from an ExternRef");
+        .extern_ref()
+        .and_then(|extern_ref| extern_ref.downcast_ref::<AtomicI32>())
+        .expect("fail to get i32 from an ExternRef")
+        .load(Ordering::SeqCst);
 
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
@@ -118,13 +125,15 @@ fn extern_mul(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
 
 fn extern_div(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
-        inputs[0]
+        &inputs[0]
     } else {
         return Err(2);
     };
     let val1 = val1
-        .extern_ref::<i32>()
-        .expect("fail to get i32 from an ExternRef");
+        .extern_ref()
+        .and_then(|extern_ref| extern_ref.downcast_ref::<AtomicI32>())
+        .expect("fail to get i32 from an ExternRef")
+        .load(Ordering::SeqCst);
 
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
@@ -1,9 +1,15 @@
 mod common;
+use std::sync::atomic::{AtomicI32, Ordering};
 use wasmedge_sys::{
-    Config, Engine, Executor, ImportObject, Loader, Statistics, Store, Validator, WasmValue,
+    Config, Engine, Executor, ExternRef, ImportObject, Loader, Statistics, Store, Validator,
+    WasmValue,
 };
-use wasmedge_types::error::{
-    CoreError, CoreExecutionError, InstanceError, StoreError, WasmEdgeError,
+use wasmedge_types::{
+    error::{
+        CoreError, CoreExecutionError, FuncError, FuncTypePosition, InstanceError, StoreError,
+        WasmEdgeError,
+    },
+    ValType,
 };
 
 #[warn(unused_assignments)]
@@ -120,7 +126,11 @@ fn test_executor_with_statistics() {
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err(),
-        WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+        WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+            position: FuncTypePosition::Param(0),
+            expected: Some(ValType::I32),
+            actual: None,
+        })
     );
 
     // function type mismatched
@@ -131,7 +141,11 @@ fn test_executor_with_statistics() {
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err(),
-        WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+        WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+            position: FuncTypePosition::Param(0),
+            expected: Some(ValType::I32),
+            actual: Some(ValType::I64),
+        })
     );
 
     // try to get non-existent exported function
@@ -147,15 +161,20 @@ fn test_executor_with_statistics() {
     assert!(result.is_ok());
     let mut table = result.unwrap();
 
-    let mut test_value = 0u32;
-    let test_value_ref = &mut test_value;
+    let test_value = ExternRef::new(AtomicI32::new(0));
+    let set_test_value = |val: i32| {
+        test_value
+            .downcast_ref::<AtomicI32>()
+            .unwrap()
+            .store(val, Ordering::SeqCst)
+    };
 
-    let data = WasmValue::from_extern_ref(test_value_ref);
-    let result = table.set_data(data, 0);
+    let data = WasmValue::from_extern_ref(test_value.clone());
+    let result = table.set_data(data.clone(), 0);
     assert!(result.is_ok());
-    let result = table.set_data(data, 1);
+    let result = table.set_data(data.clone(), 1);
     assert!(result.is_ok());
-    let result = table.set_data(data, 2);
+    let result = table.set_data(data.clone(), 2);
     assert!(result.is_ok());
     let result = table.set_data(data, 3);
     assert!(result.is_ok());
@@ -165,7 +184,7 @@ fn test_executor_with_statistics() {
     assert!(result.is_ok());
     let func_host_add = result.unwrap();
     // Call add: (777) + (223)
-    test_value = 777;
+    set_test_value(777);
     let result = executor.run_func(&func_host_add, [WasmValue::from_i32(223)]);
     assert!(result.is_ok());
     let returns = result.unwrap();
@@ -176,7 +195,7 @@ fn test_executor_with_statistics() {
     assert!(result.is_ok());
     let func_host_sub = result.unwrap();
     // Call sub: (123) - (456)
-    test_value = 123;
+    set_test_value(123);
     let result = executor.run_func(&func_host_sub, [WasmValue::from_i32(456)]);
     assert!(result.is_ok());
     let returns = result.unwrap();
@@ -187,7 +206,7 @@ fn test_executor_with_statistics() {
     assert!(result.is_ok());
     let func_host_mul = result.unwrap();
     // Call mul: (-30) * (-66)
-    test_value = -30i32 as u32;
+    set_test_value(-30);
     let result = executor.run_func(&func_host_mul, [WasmValue::from_i32(-66)]);
     assert!(result.is_ok());
     let returns = result.unwrap();
@@ -198,7 +217,7 @@ fn test_executor_with_statistics() {
     assert!(result.is_ok());
     let func_host_div = result.unwrap();
     // Call div: (-9999) / (1234)
-    test_value = -9999i32 as u32;
+    set_test_value(-9999);
     let result = executor.run_func(&func_host_div, [WasmValue::from_i32(1234)]);
     assert!(result.is_ok());
     let returns = result.unwrap();
@@ -214,11 +233,11 @@ fn test_executor_with_statistics() {
     assert!(result.is_ok());
     let func_add = result.unwrap();
     // Invoke the functions in the registered module
-    test_value = 5000;
+    set_test_value(5000);
     let result = executor.run_func(
         &func_add,
         [
-            WasmValue::from_extern_ref(&mut test_value),
+            WasmValue::from_extern_ref(test_value.clone()),
             WasmValue::from_i32(1500),
         ],
     );
@@ -230,20 +249,28 @@ fn test_executor_with_statistics() {
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err(),
-        WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+        WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+            position: FuncTypePosition::Param(0),
+            expected: Some(ValType::ExternRef),
+            actual: None,
+        })
     );
     // Function type mismatch
     let result = executor.run_func(
         &func_add,
         [
-            WasmValue::from_extern_ref(&mut test_value),
+            WasmValue::from_extern_ref(test_value.clone()),
             WasmValue::from_i64(1500),
         ],
     );
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err(),
-        WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+        WasmEdgeError::Func(FuncError::FuncTypeMismatch {
+            position: FuncTypePosition::Param(1),
+            expected: Some(ValType::I32),
+            actual: Some(ValType::I64),
+        })
     );
     // Module not found
     let result = store.module("error-name");
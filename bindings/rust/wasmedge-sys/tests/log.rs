@@ -0,0 +1,53 @@
+#![cfg(feature = "log")]
+
+use std::sync::Mutex;
+use wasmedge_sys::{utils, Loader};
+
+/// Records the log messages.
+struct Recorder(Mutex<Vec<(log::Level, String, String)>>);
+impl log::Log for Recorder {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.0.lock().unwrap().push((
+            record.level(),
+            record.target().to_string(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+static RECORDER: Recorder = Recorder(Mutex::new(Vec::new()));
+
+#[test]
+fn test_log_to_rust() {
+    let result = log::set_logger(&RECORDER);
+    assert!(result.is_ok());
+    log::set_max_level(log::LevelFilter::Error);
+    utils::log_to_rust();
+
+    // the error of loading a malformed module is logged
+    let result = Loader::create(None);
+    assert!(result.is_ok());
+    let loader = result.unwrap();
+    let result = loader.from_bytes(b"\0asm\x02\0\0\0");
+    assert!(result.is_err());
+    let len = {
+        let records = RECORDER.0.lock().unwrap();
+        assert!(!records.is_empty());
+        assert!(records.iter().all(|(level, target, message)| {
+            *level == log::Level::Error && target == "wasmedge" && !message.is_empty()
+        }));
+        records.len()
+    };
+
+    // the log messages are output to the console again
+    utils::log_to_console();
+    let result = loader.from_bytes(b"\0asm\x02\0\0\0");
+    assert!(result.is_err());
+    assert_eq!(RECORDER.0.lock().unwrap().len(), len);
+}
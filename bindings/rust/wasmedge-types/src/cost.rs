@@ -0,0 +1,912 @@
+//! Defines WebAssembly opcodes and the cost tables of instructions.
+
+use crate::error::{CostTableError, WasmEdgeError};
+use crate::WasmEdgeResult;
+use std::{fmt, str::FromStr};
+
+/// The length of a cost table, which covers all the internal opcode numbers of WasmEdge.
+const COST_TABLE_LEN: usize = u16::MAX as usize + 1;
+
+macro_rules! opcodes {
+    ($($(#[$attr:meta])* $name:ident = $value:literal, $mnemonic:literal;)*) => {
+        /// Defines WebAssembly instructions.
+        ///
+        /// The discriminant of an [Opcode] is the internal opcode number used by WasmEdge, in which the opcodes of the
+        /// prefixed instructions are combined with their prefixes, for example, `memory.fill` is `0xFC0B`.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(u16)]
+        pub enum Opcode {
+            $(
+                #[doc = concat!("`", $mnemonic, "`")]
+                $name = $value,
+            )*
+        }
+        impl Opcode {
+            /// All the opcodes supported by WasmEdge.
+            pub const ALL: &'static [Opcode] = &[$(Opcode::$name,)*];
+
+            /// Returns the mnemonic of the instruction, such as `i32.add`.
+            pub fn mnemonic(&self) -> &'static str {
+                match self {
+                    $(Opcode::$name => $mnemonic,)*
+                }
+            }
+        }
+    };
+}
+
+opcodes! {
+    // Control instructions
+    Unreachable = 0x00, "unreachable";
+    Nop = 0x01, "nop";
+    Block = 0x02, "block";
+    Loop = 0x03, "loop";
+    If = 0x04, "if";
+    Else = 0x05, "else";
+    End = 0x0B, "end";
+    Br = 0x0C, "br";
+    BrIf = 0x0D, "br_if";
+    BrTable = 0x0E, "br_table";
+    Return = 0x0F, "return";
+    Call = 0x10, "call";
+    CallIndirect = 0x11, "call_indirect";
+    ReturnCall = 0x12, "return_call";
+    ReturnCallIndirect = 0x13, "return_call_indirect";
+
+    // Reference Instructions
+    RefNull = 0xD0, "ref.null";
+    RefIsNull = 0xD1, "ref.is_null";
+    RefFunc = 0xD2, "ref.func";
+
+    // Parametric Instructions
+    Drop = 0x1A, "drop";
+    Select = 0x1B, "select";
+    SelectT = 0x1C, "select";
+
+    // Variable Instructions
+    LocalGet = 0x20, "local.get";
+    LocalSet = 0x21, "local.set";
+    LocalTee = 0x22, "local.tee";
+    GlobalGet = 0x23, "global.get";
+    GlobalSet = 0x24, "global.set";
+
+    // Table Instructions (part 1)
+    TableGet = 0x25, "table.get";
+    TableSet = 0x26, "table.set";
+
+    // Memory Instructions (part 1)
+    I32Load = 0x28, "i32.load";
+    I64Load = 0x29, "i64.load";
+    F32Load = 0x2A, "f32.load";
+    F64Load = 0x2B, "f64.load";
+    I32Load8S = 0x2C, "i32.load8_s";
+    I32Load8U = 0x2D, "i32.load8_u";
+    I32Load16S = 0x2E, "i32.load16_s";
+    I32Load16U = 0x2F, "i32.load16_u";
+    I64Load8S = 0x30, "i64.load8_s";
+    I64Load8U = 0x31, "i64.load8_u";
+    I64Load16S = 0x32, "i64.load16_s";
+    I64Load16U = 0x33, "i64.load16_u";
+    I64Load32S = 0x34, "i64.load32_s";
+    I64Load32U = 0x35, "i64.load32_u";
+    I32Store = 0x36, "i32.store";
+    I64Store = 0x37, "i64.store";
+    F32Store = 0x38, "f32.store";
+    F64Store = 0x39, "f64.store";
+    I32Store8 = 0x3A, "i32.store8";
+    I32Store16 = 0x3B, "i32.store16";
+    I64Store8 = 0x3C, "i64.store8";
+    I64Store16 = 0x3D, "i64.store16";
+    I64Store32 = 0x3E, "i64.store32";
+    MemorySize = 0x3F, "memory.size";
+    MemoryGrow = 0x40, "memory.grow";
+
+    // Const numeric instructions
+    I32Const = 0x41, "i32.const";
+    I64Const = 0x42, "i64.const";
+    F32Const = 0x43, "f32.const";
+    F64Const = 0x44, "f64.const";
+
+    // Numeric instructions
+    I32Eqz = 0x45, "i32.eqz";
+    I32Eq = 0x46, "i32.eq";
+    I32Ne = 0x47, "i32.ne";
+    I32LtS = 0x48, "i32.lt_s";
+    I32LtU = 0x49, "i32.lt_u";
+    I32GtS = 0x4A, "i32.gt_s";
+    I32GtU = 0x4B, "i32.gt_u";
+    I32LeS = 0x4C, "i32.le_s";
+    I32LeU = 0x4D, "i32.le_u";
+    I32GeS = 0x4E, "i32.ge_s";
+    I32GeU = 0x4F, "i32.ge_u";
+    I64Eqz = 0x50, "i64.eqz";
+    I64Eq = 0x51, "i64.eq";
+    I64Ne = 0x52, "i64.ne";
+    I64LtS = 0x53, "i64.lt_s";
+    I64LtU = 0x54, "i64.lt_u";
+    I64GtS = 0x55, "i64.gt_s";
+    I64GtU = 0x56, "i64.gt_u";
+    I64LeS = 0x57, "i64.le_s";
+    I64LeU = 0x58, "i64.le_u";
+    I64GeS = 0x59, "i64.ge_s";
+    I64GeU = 0x5A, "i64.ge_u";
+    F32Eq = 0x5B, "f32.eq";
+    F32Ne = 0x5C, "f32.ne";
+    F32Lt = 0x5D, "f32.lt";
+    F32Gt = 0x5E, "f32.gt";
+    F32Le = 0x5F, "f32.le";
+    F32Ge = 0x60, "f32.ge";
+    F64Eq = 0x61, "f64.eq";
+    F64Ne = 0x62, "f64.ne";
+    F64Lt = 0x63, "f64.lt";
+    F64Gt = 0x64, "f64.gt";
+    F64Le = 0x65, "f64.le";
+    F64Ge = 0x66, "f64.ge";
+    I32Clz = 0x67, "i32.clz";
+    I32Ctz = 0x68, "i32.ctz";
+    I32Popcnt = 0x69, "i32.popcnt";
+    I32Add = 0x6A, "i32.add";
+    I32Sub = 0x6B, "i32.sub";
+    I32Mul = 0x6C, "i32.mul";
+    I32DivS = 0x6D, "i32.div_s";
+    I32DivU = 0x6E, "i32.div_u";
+    I32RemS = 0x6F, "i32.rem_s";
+    I32RemU = 0x70, "i32.rem_u";
+    I32And = 0x71, "i32.and";
+    I32Or = 0x72, "i32.or";
+    I32Xor = 0x73, "i32.xor";
+    I32Shl = 0x74, "i32.shl";
+    I32ShrS = 0x75, "i32.shr_s";
+    I32ShrU = 0x76, "i32.shr_u";
+    I32Rotl = 0x77, "i32.rotl";
+    I32Rotr = 0x78, "i32.rotr";
+    I64Clz = 0x79, "i64.clz";
+    I64Ctz = 0x7A, "i64.ctz";
+    I64Popcnt = 0x7B, "i64.popcnt";
+    I64Add = 0x7C, "i64.add";
+    I64Sub = 0x7D, "i64.sub";
+    I64Mul = 0x7E, "i64.mul";
+    I64DivS = 0x7F, "i64.div_s";
+    I64DivU = 0x80, "i64.div_u";
+    I64RemS = 0x81, "i64.rem_s";
+    I64RemU = 0x82, "i64.rem_u";
+    I64And = 0x83, "i64.and";
+    I64Or = 0x84, "i64.or";
+    I64Xor = 0x85, "i64.xor";
+    I64Shl = 0x86, "i64.shl";
+    I64ShrS = 0x87, "i64.shr_s";
+    I64ShrU = 0x88, "i64.shr_u";
+    I64Rotl = 0x89, "i64.rotl";
+    I64Rotr = 0x8A, "i64.rotr";
+    F32Abs = 0x8B, "f32.abs";
+    F32Neg = 0x8C, "f32.neg";
+    F32Ceil = 0x8D, "f32.ceil";
+    F32Floor = 0x8E, "f32.floor";
+    F32Trunc = 0x8F, "f32.trunc";
+    F32Nearest = 0x90, "f32.nearest";
+    F32Sqrt = 0x91, "f32.sqrt";
+    F32Add = 0x92, "f32.add";
+    F32Sub = 0x93, "f32.sub";
+    F32Mul = 0x94, "f32.mul";
+    F32Div = 0x95, "f32.div";
+    F32Min = 0x96, "f32.min";
+    F32Max = 0x97, "f32.max";
+    F32Copysign = 0x98, "f32.copysign";
+    F64Abs = 0x99, "f64.abs";
+    F64Neg = 0x9A, "f64.neg";
+    F64Ceil = 0x9B, "f64.ceil";
+    F64Floor = 0x9C, "f64.floor";
+    F64Trunc = 0x9D, "f64.trunc";
+    F64Nearest = 0x9E, "f64.nearest";
+    F64Sqrt = 0x9F, "f64.sqrt";
+    F64Add = 0xA0, "f64.add";
+    F64Sub = 0xA1, "f64.sub";
+    F64Mul = 0xA2, "f64.mul";
+    F64Div = 0xA3, "f64.div";
+    F64Min = 0xA4, "f64.min";
+    F64Max = 0xA5, "f64.max";
+    F64Copysign = 0xA6, "f64.copysign";
+    I32WrapI64 = 0xA7, "i32.wrap_i64";
+    I32TruncF32S = 0xA8, "i32.trunc_f32_s";
+    I32TruncF32U = 0xA9, "i32.trunc_f32_u";
+    I32TruncF64S = 0xAA, "i32.trunc_f64_s";
+    I32TruncF64U = 0xAB, "i32.trunc_f64_u";
+    I64ExtendI32S = 0xAC, "i64.extend_i32_s";
+    I64ExtendI32U = 0xAD, "i64.extend_i32_u";
+    I64TruncF32S = 0xAE, "i64.trunc_f32_s";
+    I64TruncF32U = 0xAF, "i64.trunc_f32_u";
+    I64TruncF64S = 0xB0, "i64.trunc_f64_s";
+    I64TruncF64U = 0xB1, "i64.trunc_f64_u";
+    F32ConvertI32S = 0xB2, "f32.convert_i32_s";
+    F32ConvertI32U = 0xB3, "f32.convert_i32_u";
+    F32ConvertI64S = 0xB4, "f32.convert_i64_s";
+    F32ConvertI64U = 0xB5, "f32.convert_i64_u";
+    F32DemoteF64 = 0xB6, "f32.demote_f64";
+    F64ConvertI32S = 0xB7, "f64.convert_i32_s";
+    F64ConvertI32U = 0xB8, "f64.convert_i32_u";
+    F64ConvertI64S = 0xB9, "f64.convert_i64_s";
+    F64ConvertI64U = 0xBA, "f64.convert_i64_u";
+    F64PromoteF32 = 0xBB, "f64.promote_f32";
+    I32ReinterpretF32 = 0xBC, "i32.reinterpret_f32";
+    I64ReinterpretF64 = 0xBD, "i64.reinterpret_f64";
+    F32ReinterpretI32 = 0xBE, "f32.reinterpret_i32";
+    F64ReinterpretI64 = 0xBF, "f64.reinterpret_i64";
+    I32Extend8S = 0xC0, "i32.extend8_s";
+    I32Extend16S = 0xC1, "i32.extend16_s";
+    I64Extend8S = 0xC2, "i64.extend8_s";
+    I64Extend16S = 0xC3, "i64.extend16_s";
+    I64Extend32S = 0xC4, "i64.extend32_s";
+    I32TruncSatF32S = 0xFC00, "i32.trunc_sat_f32_s";
+    I32TruncSatF32U = 0xFC01, "i32.trunc_sat_f32_u";
+    I32TruncSatF64S = 0xFC02, "i32.trunc_sat_f64_s";
+    I32TruncSatF64U = 0xFC03, "i32.trunc_sat_f64_u";
+    I64TruncSatF32S = 0xFC04, "i64.trunc_sat_f32_s";
+    I64TruncSatF32U = 0xFC05, "i64.trunc_sat_f32_u";
+    I64TruncSatF64S = 0xFC06, "i64.trunc_sat_f64_s";
+    I64TruncSatF64U = 0xFC07, "i64.trunc_sat_f64_u";
+
+    // Memory Instructions (part 2)
+    MemoryInit = 0xFC08, "memory.init";
+    DataDrop = 0xFC09, "data.drop";
+    MemoryCopy = 0xFC0A, "memory.copy";
+    MemoryFill = 0xFC0B, "memory.fill";
+
+    // Table Instructions (part 2)
+    TableInit = 0xFC0C, "table.init";
+    ElemDrop = 0xFC0D, "elem.drop";
+    TableCopy = 0xFC0E, "table.copy";
+    TableGrow = 0xFC0F, "table.grow";
+    TableSize = 0xFC10, "table.size";
+    TableFill = 0xFC11, "table.fill";
+
+    // SIMD Memory Instructions
+    V128Load = 0xFD00, "v128.load";
+    V128Load8x8S = 0xFD01, "v128.load8x8_s";
+    V128Load8x8U = 0xFD02, "v128.load8x8_u";
+    V128Load16x4S = 0xFD03, "v128.load16x4_s";
+    V128Load16x4U = 0xFD04, "v128.load16x4_u";
+    V128Load32x2S = 0xFD05, "v128.load32x2_s";
+    V128Load32x2U = 0xFD06, "v128.load32x2_u";
+    V128Load8Splat = 0xFD07, "v128.load8_splat";
+    V128Load16Splat = 0xFD08, "v128.load16_splat";
+    V128Load32Splat = 0xFD09, "v128.load32_splat";
+    V128Load64Splat = 0xFD0A, "v128.load64_splat";
+    V128Load32Zero = 0xFD5C, "v128.load32_zero";
+    V128Load64Zero = 0xFD5D, "v128.load64_zero";
+    V128Store = 0xFD0B, "v128.store";
+    V128Load8Lane = 0xFD54, "v128.load8_lane";
+    V128Load16Lane = 0xFD55, "v128.load16_lane";
+    V128Load32Lane = 0xFD56, "v128.load32_lane";
+    V128Load64Lane = 0xFD57, "v128.load64_lane";
+    V128Store8Lane = 0xFD58, "v128.store8_lane";
+    V128Store16Lane = 0xFD59, "v128.store16_lane";
+    V128Store32Lane = 0xFD5A, "v128.store32_lane";
+    V128Store64Lane = 0xFD5B, "v128.store64_lane";
+
+    // SIMD Const Instructions
+    V128Const = 0xFD0C, "v128.const";
+
+    // SIMD Shuffle Instructions
+    I8x16Shuffle = 0xFD0D, "i8x16.shuffle";
+
+    // SIMD Lane Instructions
+    I8x16ExtractLaneS = 0xFD15, "i8x16.extract_lane_s";
+    I8x16ExtractLaneU = 0xFD16, "i8x16.extract_lane_u";
+    I8x16ReplaceLane = 0xFD17, "i8x16.replace_lane";
+    I16x8ExtractLaneS = 0xFD18, "i16x8.extract_lane_s";
+    I16x8ExtractLaneU = 0xFD19, "i16x8.extract_lane_u";
+    I16x8ReplaceLane = 0xFD1A, "i16x8.replace_lane";
+    I32x4ExtractLane = 0xFD1B, "i32x4.extract_lane";
+    I32x4ReplaceLane = 0xFD1C, "i32x4.replace_lane";
+    I64x2ExtractLane = 0xFD1D, "i64x2.extract_lane";
+    I64x2ReplaceLane = 0xFD1E, "i64x2.replace_lane";
+    F32x4ExtractLane = 0xFD1F, "f32x4.extract_lane";
+    F32x4ReplaceLane = 0xFD20, "f32x4.replace_lane";
+    F64x2ExtractLane = 0xFD21, "f64x2.extract_lane";
+    F64x2ReplaceLane = 0xFD22, "f64x2.replace_lane";
+
+    // SIMD Numeric Instructions
+    I8x16Swizzle = 0xFD0E, "i8x16.swizzle";
+    I8x16Splat = 0xFD0F, "i8x16.splat";
+    I16x8Splat = 0xFD10, "i16x8.splat";
+    I32x4Splat = 0xFD11, "i32x4.splat";
+    I64x2Splat = 0xFD12, "i64x2.splat";
+    F32x4Splat = 0xFD13, "f32x4.splat";
+    F64x2Splat = 0xFD14, "f64x2.splat";
+    I8x16Eq = 0xFD23, "i8x16.eq";
+    I8x16Ne = 0xFD24, "i8x16.ne";
+    I8x16LtS = 0xFD25, "i8x16.lt_s";
+    I8x16LtU = 0xFD26, "i8x16.lt_u";
+    I8x16GtS = 0xFD27, "i8x16.gt_s";
+    I8x16GtU = 0xFD28, "i8x16.gt_u";
+    I8x16LeS = 0xFD29, "i8x16.le_s";
+    I8x16LeU = 0xFD2A, "i8x16.le_u";
+    I8x16GeS = 0xFD2B, "i8x16.ge_s";
+    I8x16GeU = 0xFD2C, "i8x16.ge_u";
+    I16x8Eq = 0xFD2D, "i16x8.eq";
+    I16x8Ne = 0xFD2E, "i16x8.ne";
+    I16x8LtS = 0xFD2F, "i16x8.lt_s";
+    I16x8LtU = 0xFD30, "i16x8.lt_u";
+    I16x8GtS = 0xFD31, "i16x8.gt_s";
+    I16x8GtU = 0xFD32, "i16x8.gt_u";
+    I16x8LeS = 0xFD33, "i16x8.le_s";
+    I16x8LeU = 0xFD34, "i16x8.le_u";
+    I16x8GeS = 0xFD35, "i16x8.ge_s";
+    I16x8GeU = 0xFD36, "i16x8.ge_u";
+    I32x4Eq = 0xFD37, "i32x4.eq";
+    I32x4Ne = 0xFD38, "i32x4.ne";
+    I32x4LtS = 0xFD39, "i32x4.lt_s";
+    I32x4LtU = 0xFD3A, "i32x4.lt_u";
+    I32x4GtS = 0xFD3B, "i32x4.gt_s";
+    I32x4GtU = 0xFD3C, "i32x4.gt_u";
+    I32x4LeS = 0xFD3D, "i32x4.le_s";
+    I32x4LeU = 0xFD3E, "i32x4.le_u";
+    I32x4GeS = 0xFD3F, "i32x4.ge_s";
+    I32x4GeU = 0xFD40, "i32x4.ge_u";
+    I64x2Eq = 0xFDD6, "i64x2.eq";
+    I64x2Ne = 0xFDD7, "i64x2.ne";
+    I64x2LtS = 0xFDD8, "i64x2.lt_s";
+    I64x2GtS = 0xFDD9, "i64x2.gt_s";
+    I64x2LeS = 0xFDDA, "i64x2.le_s";
+    I64x2GeS = 0xFDDB, "i64x2.ge_s";
+    F32x4Eq = 0xFD41, "f32x4.eq";
+    F32x4Ne = 0xFD42, "f32x4.ne";
+    F32x4Lt = 0xFD43, "f32x4.lt";
+    F32x4Gt = 0xFD44, "f32x4.gt";
+    F32x4Le = 0xFD45, "f32x4.le";
+    F32x4Ge = 0xFD46, "f32x4.ge";
+    F64x2Eq = 0xFD47, "f64x2.eq";
+    F64x2Ne = 0xFD48, "f64x2.ne";
+    F64x2Lt = 0xFD49, "f64x2.lt";
+    F64x2Gt = 0xFD4A, "f64x2.gt";
+    F64x2Le = 0xFD4B, "f64x2.le";
+    F64x2Ge = 0xFD4C, "f64x2.ge";
+    V128Not = 0xFD4D, "v128.not";
+    V128And = 0xFD4E, "v128.and";
+    V128Andnot = 0xFD4F, "v128.andnot";
+    V128Or = 0xFD50, "v128.or";
+    V128Xor = 0xFD51, "v128.xor";
+    V128Bitselect = 0xFD52, "v128.bitselect";
+    V128AnyTrue = 0xFD53, "v128.any_true";
+    I8x16Abs = 0xFD60, "i8x16.abs";
+    I8x16Neg = 0xFD61, "i8x16.neg";
+    I8x16Popcnt = 0xFD62, "i8x16.popcnt";
+    I8x16AllTrue = 0xFD63, "i8x16.all_true";
+    I8x16Bitmask = 0xFD64, "i8x16.bitmask";
+    I8x16NarrowI16x8S = 0xFD65, "i8x16.narrow_i16x8_s";
+    I8x16NarrowI16x8U = 0xFD66, "i8x16.narrow_i16x8_u";
+    I8x16Shl = 0xFD6B, "i8x16.shl";
+    I8x16ShrS = 0xFD6C, "i8x16.shr_s";
+    I8x16ShrU = 0xFD6D, "i8x16.shr_u";
+    I8x16Add = 0xFD6E, "i8x16.add";
+    I8x16AddSatS = 0xFD6F, "i8x16.add_sat_s";
+    I8x16AddSatU = 0xFD70, "i8x16.add_sat_u";
+    I8x16Sub = 0xFD71, "i8x16.sub";
+    I8x16SubSatS = 0xFD72, "i8x16.sub_sat_s";
+    I8x16SubSatU = 0xFD73, "i8x16.sub_sat_u";
+    I8x16MinS = 0xFD76, "i8x16.min_s";
+    I8x16MinU = 0xFD77, "i8x16.min_u";
+    I8x16MaxS = 0xFD78, "i8x16.max_s";
+    I8x16MaxU = 0xFD79, "i8x16.max_u";
+    I8x16AvgrU = 0xFD7B, "i8x16.avgr_u";
+    I16x8Abs = 0xFD80, "i16x8.abs";
+    I16x8Neg = 0xFD81, "i16x8.neg";
+    I16x8AllTrue = 0xFD83, "i16x8.all_true";
+    I16x8Bitmask = 0xFD84, "i16x8.bitmask";
+    I16x8NarrowI32x4S = 0xFD85, "i16x8.narrow_i32x4_s";
+    I16x8NarrowI32x4U = 0xFD86, "i16x8.narrow_i32x4_u";
+    I16x8ExtendLowI8x16S = 0xFD87, "i16x8.extend_low_i8x16_s";
+    I16x8ExtendHighI8x16S = 0xFD88, "i16x8.extend_high_i8x16_s";
+    I16x8ExtendLowI8x16U = 0xFD89, "i16x8.extend_low_i8x16_u";
+    I16x8ExtendHighI8x16U = 0xFD8A, "i16x8.extend_high_i8x16_u";
+    I16x8Shl = 0xFD8B, "i16x8.shl";
+    I16x8ShrS = 0xFD8C, "i16x8.shr_s";
+    I16x8ShrU = 0xFD8D, "i16x8.shr_u";
+    I16x8Add = 0xFD8E, "i16x8.add";
+    I16x8AddSatS = 0xFD8F, "i16x8.add_sat_s";
+    I16x8AddSatU = 0xFD90, "i16x8.add_sat_u";
+    I16x8Sub = 0xFD91, "i16x8.sub";
+    I16x8SubSatS = 0xFD92, "i16x8.sub_sat_s";
+    I16x8SubSatU = 0xFD93, "i16x8.sub_sat_u";
+    I16x8Mul = 0xFD95, "i16x8.mul";
+    I16x8MinS = 0xFD96, "i16x8.min_s";
+    I16x8MinU = 0xFD97, "i16x8.min_u";
+    I16x8MaxS = 0xFD98, "i16x8.max_s";
+    I16x8MaxU = 0xFD99, "i16x8.max_u";
+    I16x8AvgrU = 0xFD9B, "i16x8.avgr_u";
+    I16x8ExtmulLowI8x16S = 0xFD9C, "i16x8.extmul_low_i8x16_s";
+    I16x8ExtmulHighI8x16S = 0xFD9D, "i16x8.extmul_high_i8x16_s";
+    I16x8ExtmulLowI8x16U = 0xFD9E, "i16x8.extmul_low_i8x16_u";
+    I16x8ExtmulHighI8x16U = 0xFD9F, "i16x8.extmul_high_i8x16_u";
+    I16x8Q15mulrSatS = 0xFD82, "i16x8.q15mulr_sat_s";
+    I16x8ExtaddPairwiseI8x16S = 0xFD7C, "i16x8.extadd_pairwise_i8x16_s";
+    I16x8ExtaddPairwiseI8x16U = 0xFD7D, "i16x8.extadd_pairwise_i8x16_u";
+    I32x4Abs = 0xFDA0, "i32x4.abs";
+    I32x4Neg = 0xFDA1, "i32x4.neg";
+    I32x4AllTrue = 0xFDA3, "i32x4.all_true";
+    I32x4Bitmask = 0xFDA4, "i32x4.bitmask";
+    I32x4ExtendLowI16x8S = 0xFDA7, "i32x4.extend_low_i16x8_s";
+    I32x4ExtendHighI16x8S = 0xFDA8, "i32x4.extend_high_i16x8_s";
+    I32x4ExtendLowI16x8U = 0xFDA9, "i32x4.extend_low_i16x8_u";
+    I32x4ExtendHighI16x8U = 0xFDAA, "i32x4.extend_high_i16x8_u";
+    I32x4Shl = 0xFDAB, "i32x4.shl";
+    I32x4ShrS = 0xFDAC, "i32x4.shr_s";
+    I32x4ShrU = 0xFDAD, "i32x4.shr_u";
+    I32x4Add = 0xFDAE, "i32x4.add";
+    I32x4Sub = 0xFDB1, "i32x4.sub";
+    I32x4Mul = 0xFDB5, "i32x4.mul";
+    I32x4MinS = 0xFDB6, "i32x4.min_s";
+    I32x4MinU = 0xFDB7, "i32x4.min_u";
+    I32x4MaxS = 0xFDB8, "i32x4.max_s";
+    I32x4MaxU = 0xFDB9, "i32x4.max_u";
+    I32x4DotI16x8S = 0xFDBA, "i32x4.dot_i16x8_s";
+    I32x4ExtmulLowI16x8S = 0xFDBC, "i32x4.extmul_low_i16x8_s";
+    I32x4ExtmulHighI16x8S = 0xFDBD, "i32x4.extmul_high_i16x8_s";
+    I32x4ExtmulLowI16x8U = 0xFDBE, "i32x4.extmul_low_i16x8_u";
+    I32x4ExtmulHighI16x8U = 0xFDBF, "i32x4.extmul_high_i16x8_u";
+    I32x4ExtaddPairwiseI16x8S = 0xFD7E, "i32x4.extadd_pairwise_i16x8_s";
+    I32x4ExtaddPairwiseI16x8U = 0xFD7F, "i32x4.extadd_pairwise_i16x8_u";
+    I64x2Abs = 0xFDC0, "i64x2.abs";
+    I64x2Neg = 0xFDC1, "i64x2.neg";
+    I64x2AllTrue = 0xFDC3, "i64x2.all_true";
+    I64x2Bitmask = 0xFDC4, "i64x2.bitmask";
+    I64x2ExtendLowI32x4S = 0xFDC7, "i64x2.extend_low_i32x4_s";
+    I64x2ExtendHighI32x4S = 0xFDC8, "i64x2.extend_high_i32x4_s";
+    I64x2ExtendLowI32x4U = 0xFDC9, "i64x2.extend_low_i32x4_u";
+    I64x2ExtendHighI32x4U = 0xFDCA, "i64x2.extend_high_i32x4_u";
+    I64x2Shl = 0xFDCB, "i64x2.shl";
+    I64x2ShrS = 0xFDCC, "i64x2.shr_s";
+    I64x2ShrU = 0xFDCD, "i64x2.shr_u";
+    I64x2Add = 0xFDCE, "i64x2.add";
+    I64x2Sub = 0xFDD1, "i64x2.sub";
+    I64x2Mul = 0xFDD5, "i64x2.mul";
+    I64x2ExtmulLowI32x4S = 0xFDDC, "i64x2.extmul_low_i32x4_s";
+    I64x2ExtmulHighI32x4S = 0xFDDD, "i64x2.extmul_high_i32x4_s";
+    I64x2ExtmulLowI32x4U = 0xFDDE, "i64x2.extmul_low_i32x4_u";
+    I64x2ExtmulHighI32x4U = 0xFDDF, "i64x2.extmul_high_i32x4_u";
+    F32x4Abs = 0xFDE0, "f32x4.abs";
+    F32x4Neg = 0xFDE1, "f32x4.neg";
+    F32x4Sqrt = 0xFDE3, "f32x4.sqrt";
+    F32x4Add = 0xFDE4, "f32x4.add";
+    F32x4Sub = 0xFDE5, "f32x4.sub";
+    F32x4Mul = 0xFDE6, "f32x4.mul";
+    F32x4Div = 0xFDE7, "f32x4.div";
+    F32x4Min = 0xFDE8, "f32x4.min";
+    F32x4Max = 0xFDE9, "f32x4.max";
+    F32x4Pmin = 0xFDEA, "f32x4.pmin";
+    F32x4Pmax = 0xFDEB, "f32x4.pmax";
+    F32x4Ceil = 0xFD67, "f32x4.ceil";
+    F32x4Floor = 0xFD68, "f32x4.floor";
+    F32x4Trunc = 0xFD69, "f32x4.trunc";
+    F32x4Nearest = 0xFD6A, "f32x4.nearest";
+    F64x2Abs = 0xFDEC, "f64x2.abs";
+    F64x2Neg = 0xFDED, "f64x2.neg";
+    F64x2Sqrt = 0xFDEF, "f64x2.sqrt";
+    F64x2Add = 0xFDF0, "f64x2.add";
+    F64x2Sub = 0xFDF1, "f64x2.sub";
+    F64x2Mul = 0xFDF2, "f64x2.mul";
+    F64x2Div = 0xFDF3, "f64x2.div";
+    F64x2Min = 0xFDF4, "f64x2.min";
+    F64x2Max = 0xFDF5, "f64x2.max";
+    F64x2Pmin = 0xFDF6, "f64x2.pmin";
+    F64x2Pmax = 0xFDF7, "f64x2.pmax";
+    F64x2Ceil = 0xFD74, "f64x2.ceil";
+    F64x2Floor = 0xFD75, "f64x2.floor";
+    F64x2Trunc = 0xFD7A, "f64x2.trunc";
+    F64x2Nearest = 0xFD94, "f64x2.nearest";
+    I32x4TruncSatF32x4S = 0xFDF8, "i32x4.trunc_sat_f32x4_s";
+    I32x4TruncSatF32x4U = 0xFDF9, "i32x4.trunc_sat_f32x4_u";
+    F32x4ConvertI32x4S = 0xFDFA, "f32x4.convert_i32x4_s";
+    F32x4ConvertI32x4U = 0xFDFB, "f32x4.convert_i32x4_u";
+    I32x4TruncSatF64x2SZero = 0xFDFC, "i32x4.trunc_sat_f64x2_s_zero";
+    I32x4TruncSatF64x2UZero = 0xFDFD, "i32x4.trunc_sat_f64x2_u_zero";
+    F64x2ConvertLowI32x4S = 0xFDFE, "f64x2.convert_low_i32x4_s";
+    F64x2ConvertLowI32x4U = 0xFDFF, "f64x2.convert_low_i32x4_u";
+    F32x4DemoteF64x2Zero = 0xFD5E, "f32x4.demote_f64x2_zero";
+    F64x2PromoteLowF32x4 = 0xFD5F, "f64x2.promote_low_f32x4";
+}
+
+impl Opcode {
+    /// Returns `true` if the instruction accesses or resizes a linear memory.
+    pub fn is_memory(&self) -> bool {
+        let mnemonic = self.mnemonic();
+        mnemonic.contains("load") || mnemonic.contains("store") || mnemonic.starts_with("memory.")
+    }
+}
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+impl FromStr for Opcode {
+    type Err = WasmEdgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Opcode::ALL
+            .iter()
+            .find(|opcode| opcode.mnemonic() == s)
+            .copied()
+            .ok_or_else(|| WasmEdgeError::CostTable(CostTableError::UnknownOpcode(s.to_string())))
+    }
+}
+impl TryFrom<u16> for Opcode {
+    type Error = WasmEdgeError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Opcode::ALL
+            .iter()
+            .find(|opcode| **opcode as u16 == value)
+            .copied()
+            .ok_or_else(|| {
+                WasmEdgeError::CostTable(CostTableError::UnknownOpcode(format!("{:#X}", value)))
+            })
+    }
+}
+
+/// Defines the costs of instructions, which are used to measure the cost of WASM execution.
+///
+/// A [CostTable] maps each [Opcode] to its cost, and can be set into a `Statistics` by `set_cost_table`.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_types::cost::{CostTable, Opcode};
+///
+/// let table = CostTable::memory_expensive(1, 10)
+///     .cost(Opcode::Call, 5)
+///     .cost_by_name("memory.grow", 1000)
+///     .expect("memory.grow is a valid instruction");
+/// assert_eq!(table.get(Opcode::I32Add), 1);
+/// assert_eq!(table.get(Opcode::I64Load), 10);
+/// assert_eq!(table.get(Opcode::MemoryGrow), 1000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostTable {
+    costs: Vec<u64>,
+}
+impl CostTable {
+    /// Creates a [CostTable] in which all the instructions have the same cost.
+    ///
+    /// # Argument
+    ///
+    /// * `cost` - The cost of each instruction.
+    pub fn uniform(cost: u64) -> Self {
+        let mut costs = vec![0; COST_TABLE_LEN];
+        for opcode in Opcode::ALL {
+            costs[*opcode as usize] = cost;
+        }
+        Self { costs }
+    }
+
+    /// Creates a [CostTable] in which the memory instructions are more expensive than the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `cost` - The cost of each non-memory instruction.
+    ///
+    /// * `memory_cost` - The cost of each memory instruction, such as loads, stores and `memory.grow`.
+    pub fn memory_expensive(cost: u64, memory_cost: u64) -> Self {
+        let mut table = Self::uniform(cost);
+        for opcode in Opcode::ALL.iter().filter(|opcode| opcode.is_memory()) {
+            table.set(*opcode, memory_cost);
+        }
+        table
+    }
+
+    /// Sets the cost of an instruction, and returns the [CostTable].
+    ///
+    /// # Arguments
+    ///
+    /// * `opcode` - The instruction.
+    ///
+    /// * `cost` - The cost of the instruction.
+    pub fn cost(mut self, opcode: Opcode, cost: u64) -> Self {
+        self.set(opcode, cost);
+        self
+    }
+
+    /// Sets the cost of the instructions of the given mnemonic, and returns the [CostTable].
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - The mnemonic of the instructions, such as `i32.add`.
+    ///
+    /// * `cost` - The cost of the instructions.
+    ///
+    /// # Error
+    ///
+    /// If the mnemonic is unknown, then an error is returned.
+    pub fn cost_by_name(mut self, mnemonic: impl AsRef<str>, cost: u64) -> WasmEdgeResult<Self> {
+        self.set_by_name(mnemonic, cost)?;
+        Ok(self)
+    }
+
+    /// Sets the cost of an instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `opcode` - The instruction.
+    ///
+    /// * `cost` - The cost of the instruction.
+    pub fn set(&mut self, opcode: Opcode, cost: u64) {
+        self.costs[opcode as usize] = cost;
+    }
+
+    /// Sets the cost of the instructions of the given mnemonic. Some mnemonics, such as `select`, name more than one
+    /// instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - The mnemonic of the instructions, such as `i32.add`.
+    ///
+    /// * `cost` - The cost of the instructions.
+    ///
+    /// # Error
+    ///
+    /// If the mnemonic is unknown, then an error is returned.
+    pub fn set_by_name(&mut self, mnemonic: impl AsRef<str>, cost: u64) -> WasmEdgeResult<()> {
+        let mnemonic = mnemonic.as_ref();
+        let mut found = false;
+        for opcode in Opcode::ALL
+            .iter()
+            .filter(|opcode| opcode.mnemonic() == mnemonic)
+        {
+            self.set(*opcode, cost);
+            found = true;
+        }
+        match found {
+            true => Ok(()),
+            false => Err(WasmEdgeError::CostTable(CostTableError::UnknownOpcode(
+                mnemonic.to_string(),
+            ))),
+        }
+    }
+
+    /// Returns the cost of an instruction.
+    ///
+    /// # Argument
+    ///
+    /// * `opcode` - The instruction.
+    pub fn get(&self, opcode: Opcode) -> u64 {
+        self.costs[opcode as usize]
+    }
+
+    /// Creates a [CostTable] from a JSON document.
+    ///
+    /// The document is an object of the following optional fields:
+    ///
+    /// * `default` - The cost of each instruction. Defaults to `1`.
+    ///
+    /// * `memory` - The cost of each memory instruction. Defaults to `default`.
+    ///
+    /// * `opcodes` - An object which maps the mnemonics of instructions to their costs.
+    ///
+    /// # Argument
+    ///
+    /// * `json` - The JSON document.
+    ///
+    /// # Error
+    ///
+    /// If fail to parse the document or any mnemonic is unknown, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_types::cost::{CostTable, Opcode};
+    ///
+    /// let table = CostTable::from_json(r#"{ "default": 1, "memory": 10, "opcodes": { "call": 5 } }"#)
+    ///     .expect("fail to parse the cost table");
+    /// assert_eq!(table.get(Opcode::I64Add), 1);
+    /// assert_eq!(table.get(Opcode::I32Load), 10);
+    /// assert_eq!(table.get(Opcode::Call), 5);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json(json: impl AsRef<str>) -> WasmEdgeResult<Self> {
+        let spec: CostTableSpec = serde_json::from_str(json.as_ref())
+            .map_err(|err| WasmEdgeError::CostTable(CostTableError::Parse(err.to_string())))?;
+        spec.build()
+    }
+
+    /// Creates a [CostTable] from a TOML document, which has the same fields as the JSON document of
+    /// [from_json](crate::cost::CostTable::from_json).
+    ///
+    /// # Argument
+    ///
+    /// * `toml` - The TOML document.
+    ///
+    /// # Error
+    ///
+    /// If fail to parse the document or any mnemonic is unknown, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_types::cost::{CostTable, Opcode};
+    ///
+    /// let table = CostTable::from_toml(
+    ///     r#"
+    ///     default = 1
+    ///     memory = 10
+    ///
+    ///     [opcodes]
+    ///     call = 5
+    ///     "memory.grow" = 1000
+    ///     "#,
+    /// )
+    /// .expect("fail to parse the cost table");
+    /// assert_eq!(table.get(Opcode::Call), 5);
+    /// assert_eq!(table.get(Opcode::MemoryGrow), 1000);
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: impl AsRef<str>) -> WasmEdgeResult<Self> {
+        let spec: CostTableSpec = toml::from_str(toml.as_ref())
+            .map_err(|err| WasmEdgeError::CostTable(CostTableError::Parse(err.to_string())))?;
+        spec.build()
+    }
+}
+impl Default for CostTable {
+    /// Returns the [CostTable] used by WasmEdge if no cost table is set, in which the cost of each instruction is `1`.
+    fn default() -> Self {
+        Self {
+            costs: vec![1; COST_TABLE_LEN],
+        }
+    }
+}
+impl AsRef<[u64]> for CostTable {
+    fn as_ref(&self) -> &[u64] {
+        &self.costs
+    }
+}
+
+/// The document of a [CostTable].
+#[cfg(any(feature = "json", feature = "toml"))]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CostTableSpec {
+    #[serde(default = "default_cost")]
+    default: u64,
+    memory: Option<u64>,
+    #[serde(default)]
+    opcodes: std::collections::BTreeMap<String, u64>,
+}
+#[cfg(any(feature = "json", feature = "toml"))]
+impl CostTableSpec {
+    fn build(self) -> WasmEdgeResult<CostTable> {
+        let mut table =
+            CostTable::memory_expensive(self.default, self.memory.unwrap_or(self.default));
+        for (mnemonic, cost) in self.opcodes {
+            table.set_by_name(mnemonic, cost)?;
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+fn default_cost() -> u64 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cost_opcodes() {
+        // every opcode has a distinct number and a distinct mnemonic, except the typed and untyped `select`
+        let mut numbers = Opcode::ALL.iter().map(|x| *x as u16).collect::<Vec<_>>();
+        numbers.sort_unstable();
+        numbers.dedup();
+        assert_eq!(numbers.len(), Opcode::ALL.len());
+        let mut mnemonics = Opcode::ALL.iter().map(|x| x.mnemonic()).collect::<Vec<_>>();
+        mnemonics.sort_unstable();
+        mnemonics.dedup();
+        assert_eq!(mnemonics.len(), Opcode::ALL.len() - 1);
+
+        // the opcodes of all the numeric instructions are covered
+        for value in 0x45..=0xC4 {
+            let result = Opcode::try_from(value);
+            assert!(result.is_ok(), "missing opcode {:#X}", value);
+        }
+
+        let result = "i64.add".parse::<Opcode>();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Opcode::I64Add);
+        assert_eq!(Opcode::I64DivS.to_string(), "i64.div_s");
+        let result = Opcode::try_from(0xFC0B);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Opcode::MemoryFill);
+
+        let result = "i64.plus".parse::<Opcode>();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::CostTable(CostTableError::UnknownOpcode("i64.plus".into()))
+        );
+        let result = Opcode::try_from(0xFF);
+        assert!(result.is_err());
+
+        assert!(Opcode::I64Load32U.is_memory());
+        assert!(Opcode::MemoryGrow.is_memory());
+        assert!(!Opcode::I64Mul.is_memory());
+    }
+
+    #[test]
+    fn test_cost_table() {
+        // all the opcodes are priced by the presets
+        let table = CostTable::uniform(2);
+        assert!(Opcode::ALL.iter().all(|x| table.get(*x) == 2));
+        let table = CostTable::memory_expensive(1, 10);
+        assert_eq!(table.get(Opcode::I64Add), 1);
+        assert_eq!(table.get(Opcode::I64Ctz), 1);
+        assert_eq!(table.get(Opcode::I64Store), 10);
+        assert_eq!(table.get(Opcode::MemoryCopy), 10);
+
+        // the default table prices every slot as WasmEdge does
+        let table = CostTable::default();
+        assert_eq!(table.as_ref().len(), COST_TABLE_LEN);
+        assert!(table.as_ref().iter().all(|x| *x == 1));
+
+        let result = CostTable::uniform(1)
+            .cost(Opcode::Call, 5)
+            .cost_by_name("i64.mul", 3);
+        assert!(result.is_ok());
+        let table = result.unwrap();
+        assert_eq!(table.get(Opcode::Call), 5);
+        assert_eq!(table.get(Opcode::I64Mul), 3);
+        assert_eq!(table.as_ref()[0x7E], 3);
+
+        // both the untyped and the typed `select` are priced by the name
+        let result = CostTable::uniform(1).cost_by_name("select", 4);
+        assert!(result.is_ok());
+        let table = result.unwrap();
+        assert_eq!(table.get(Opcode::Select), 4);
+        assert_eq!(table.get(Opcode::SelectT), 4);
+
+        let result = CostTable::uniform(1).cost_by_name("i64.plus", 3);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::CostTable(CostTableError::UnknownOpcode("i64.plus".into()))
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_cost_table_json() {
+        let result = CostTable::from_json(r#"{ "opcodes": { "i64.sub": 7 } }"#);
+        assert!(result.is_ok());
+        let table = result.unwrap();
+        assert_eq!(table.get(Opcode::I64Sub), 7);
+        assert_eq!(table.get(Opcode::I64Load), 1);
+
+        let result = CostTable::from_json(r#"{ "opcodes": { "i64.plus": 7 } }"#);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::CostTable(CostTableError::UnknownOpcode("i64.plus".into()))
+        );
+        let result = CostTable::from_json(r#"{ "unknown": 1 }"#);
+        assert!(matches!(
+            result,
+            Err(WasmEdgeError::CostTable(CostTableError::Parse(_)))
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_cost_table_toml() {
+        let result =
+            CostTable::from_toml("default = 2\nmemory = 8\n[opcodes]\n\"i64.popcnt\" = 3\n");
+        assert!(result.is_ok());
+        let table = result.unwrap();
+        assert_eq!(table.get(Opcode::I64Add), 2);
+        assert_eq!(table.get(Opcode::F64Store), 8);
+        assert_eq!(table.get(Opcode::I64Popcnt), 3);
+
+        let result = CostTable::from_toml("default = \"one\"");
+        assert!(matches!(
+            result,
+            Err(WasmEdgeError::CostTable(CostTableError::Parse(_)))
+        ));
+    }
+}
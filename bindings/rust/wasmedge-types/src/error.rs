@@ -1,6 +1,6 @@
 //! Defines WasmEdge error types.
 
-use crate::ExternalInstanceType;
+use crate::{ExternalInstanceType, ValType};
 use thiserror::Error;
 
 /// The error types used by both wasmedge-sys and wasmedge crates.
@@ -54,8 +54,33 @@ pub enum WasmEdgeError {
     Export(ExportError),
     #[error("{0}")]
     Instance(InstanceError),
+    #[error("{0}")]
+    Value(ValueError),
+    #[error("{0}")]
+    Linker(LinkerError),
+    #[error("{0}")]
+    Pool(PoolError),
+    #[error("{0}")]
+    Module(ModuleError),
+    #[error("{0}")]
+    Wat(WatError),
+    #[error("{0}")]
+    Trap(Trap),
+    /// Errors raised by WasmEdge Core when loading or validating a module, with the [Diagnostic] of the failure.
+    #[error("{error} in {diagnostic}")]
+    Diagnosed {
+        error: CoreError,
+        diagnostic: Box<Diagnostic>,
+    },
+    #[error("{0}")]
+    CostTable(CostTableError),
 
     // std
+    #[error("Fail to read the file ({path}): {kind}")]
+    ReadFile {
+        path: String,
+        kind: std::io::ErrorKind,
+    },
     #[error("Found an interior nul byte")]
     FoundNulByte(#[from] std::ffi::NulError),
     #[error("Fail to find a nul byte in the expected position")]
@@ -73,6 +98,40 @@ pub enum FuncError {
     CreateBinding(String),
     #[error("Fail to get the function type")]
     Type,
+    #[error(
+        "Function type mismatch at {position}: expected {}, found {}",
+        type_or_nothing(.expected),
+        type_or_nothing(.actual)
+    )]
+    FuncTypeMismatch {
+        position: FuncTypePosition,
+        expected: Option<ValType>,
+        actual: Option<ValType>,
+    },
+}
+
+/// The position of a value in the signature of a function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuncTypePosition {
+    /// The parameter at the given index.
+    Param(usize),
+    /// The return value at the given index.
+    Return(usize),
+}
+impl std::fmt::Display for FuncTypePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FuncTypePosition::Param(index) => write!(f, "parameter {}", index),
+            FuncTypePosition::Return(index) => write!(f, "return value {}", index),
+        }
+    }
+}
+
+fn type_or_nothing(ty: &Option<ValType>) -> String {
+    match ty {
+        Some(ty) => format!("{:?}", ty),
+        None => String::from("nothing"),
+    }
 }
 
 /// The error types for WasmEdge Memory.
@@ -88,6 +147,8 @@ pub enum MemError {
     MutPtr,
     #[error("Fail to convert a raw pointer to a reference")]
     Ptr2Ref,
+    #[error("Unaligned atomic memory access at offset {0}")]
+    UnalignedAtomic(u32),
 }
 
 /// The error types for WasmEdge Global.
@@ -99,8 +160,15 @@ pub enum GlobalError {
     Type,
     #[error("Trying to set value to a const global variable")]
     ModifyConst,
-    #[error("")]
-    UnmatchedValType,
+    #[error("The value type of the global variable is {expected:?}, but found {actual:?}")]
+    UnmatchedValType { expected: ValType, actual: ValType },
+}
+
+/// The error types for WasmEdge WasmValue.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum ValueError {
+    #[error("The expected value type is {expected:?}, but found {actual:?}")]
+    UnmatchedValType { expected: ValType, actual: ValType },
 }
 
 /// The error types for WasmEdge Table.
@@ -110,6 +178,8 @@ pub enum TableError {
     Create,
     #[error("Fail to get the table type")]
     Type,
+    #[error("The type of the value does not match the element type of the table")]
+    UnmatchedRefType,
 }
 
 /// The error types for WasmEdge ImportType.
@@ -167,6 +237,199 @@ pub enum InstanceError {
     NotFoundMem(String),
     #[error("Fail to find the target global ({0})")]
     NotFoundGlobal(String),
+    #[error("Fail to find the target export ({0})")]
+    NotFoundExport(String),
+    #[error("Fail to find the target {expected} ({name}), which is exported as a {found}")]
+    MismatchedExport {
+        name: String,
+        /// The expected kind of the export. The type it carries is the default one of the kind.
+        expected: ExternalInstanceType,
+        /// The type of the instance found with the name.
+        found: ExternalInstanceType,
+    },
+}
+
+/// The error types for WasmEdge AST Module.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum ModuleError {
+    #[error("Malformed name section: {0}")]
+    MalformedNameSection(String),
+    #[error("Malformed module at offset {offset:#x}: {reason}")]
+    Malformed { offset: usize, reason: String },
+}
+
+/// The error types for parsing the WebAssembly text format.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum WatError {
+    #[error("Fail to parse WAT at line {line}, column {column}: {message}")]
+    Parse {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+}
+
+/// A trap raised when running WASM code, with the backtrace of the WASM call stack.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trap {
+    /// The error raised by WasmEdge Core.
+    pub error: CoreError,
+    /// The frames of the WASM call stack. The innermost frame is the first.
+    pub frames: Vec<TrapFrame>,
+    /// The payload of the panic, if the trap is caused by a panicking host function.
+    pub host_panic: Option<String>,
+}
+impl std::fmt::Display for Trap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(message) = &self.host_panic {
+            write!(f, ": host function panicked: {}", message)?;
+        }
+        if !self.frames.is_empty() {
+            write!(f, "\nwasm backtrace:")?;
+            for (i, frame) in self.frames.iter().enumerate() {
+                write!(f, "\n  {:>3}: {}", i, frame)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for Trap {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A frame in the backtrace of a [Trap].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrapFrame {
+    /// The name of the module instance. `None` for the anonymous module instance.
+    pub module_name: Option<String>,
+    /// The index of the function in the module instance, including the imported functions.
+    pub func_index: u32,
+    /// The name of the function recorded in the name section of the module.
+    pub func_name: Option<String>,
+    /// The byte offset of the instruction in the module. `None` for host functions and AOT compiled functions.
+    pub offset: Option<u64>,
+}
+impl std::fmt::Display for TrapFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}!", self.module_name.as_deref().unwrap_or("<module>"))?;
+        match &self.func_name {
+            Some(name) => write!(f, "{}", name)?,
+            None => write!(f, "<func {}>", self.func_index)?,
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " @ {:#x}", offset)?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes where the loading or the validation of a module failed.
+///
+/// A [Diagnostic] is attached to the [Diagnosed](crate::error::WasmEdgeError::Diagnosed) error returned by the
+/// failed call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The name of the section in which the failure occurred, such as "code section". `None` if the failure is not in a specific section.
+    pub section: Option<String>,
+    /// The byte offset in the WASM binary.
+    pub offset: Option<u64>,
+    /// The index of the function in which the failure occurred. The index includes the imported functions.
+    pub func_index: Option<u32>,
+    /// The name of the failed instruction, such as "i32.add".
+    pub instruction: Option<String>,
+    /// The expected operand types of a type mismatch.
+    pub expected: Vec<ValType>,
+    /// The actual operand types of a type mismatch. Empty if the value stack underflowed.
+    pub actual: Vec<ValType>,
+}
+impl Diagnostic {
+    /// Returns `true` if nothing is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.section.is_none()
+            && self.offset.is_none()
+            && self.func_index.is_none()
+            && self.instruction.is_none()
+            && self.expected.is_empty()
+            && self.actual.is_empty()
+    }
+}
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.section.as_deref().unwrap_or("module"))?;
+        if let Some(func_index) = self.func_index {
+            write!(f, ", function {}", func_index)?;
+        }
+        if let Some(instruction) = &self.instruction {
+            write!(f, ", instruction {}", instruction)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {:#x}", offset)?;
+        }
+        if !self.expected.is_empty() || !self.actual.is_empty() {
+            write!(f, ": expected {:?}, found {:?}", self.expected, self.actual)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error types for cost tables.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum CostTableError {
+    #[error("Unknown instruction: {0}")]
+    UnknownOpcode(String),
+    #[error("Fail to parse the cost table: {0}")]
+    Parse(String),
+}
+
+/// The error types for WasmEdge Linker.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum LinkerError {
+    #[error("Duplicate definition of `{module}::{name}`")]
+    DuplicateDefinition { module: String, name: String },
+    #[error("The module `{0}` has been defined")]
+    DuplicateModule(String),
+    #[error("The module `{0}` has been registered, no more definitions can be added to it")]
+    ModuleRegistered(String),
+    #[error("An anonymous module instance cannot be defined in a Linker")]
+    AnonymousInstance,
+    #[error("The Linker has registered its definitions into another Store")]
+    StoreMismatch,
+    #[error("The module instance `{0}` is bound to a Store, and cannot be imported by a pre-instantiated module")]
+    StoreBoundModule(String),
+    #[error(
+        "Fail to resolve {} import(s): {}",
+        .0.len(),
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+    )]
+    UnresolvedImports(Vec<LinkError>),
+}
+
+/// Describes an import which a Linker fails to resolve.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum LinkError {
+    #[error("unknown import `{module}::{name}` of {ty} type")]
+    Unknown {
+        module: String,
+        name: String,
+        ty: ExternalInstanceType,
+    },
+    #[error("incompatible import type for `{module}::{name}`: expected {expected:?}, but found {actual:?}")]
+    IncompatibleType {
+        module: String,
+        name: String,
+        expected: ExternalInstanceType,
+        actual: ExternalInstanceType,
+    },
+}
+
+/// The error types for WasmEdge VmPool.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum PoolError {
+    #[error("The capacity of a VmPool must be greater than zero")]
+    ZeroCapacity,
 }
 
 /// The error types for WasmEdge Store.
@@ -200,6 +463,8 @@ pub enum StoreError {
     NotFoundModule(String),
     #[error("Not found the active module")]
     NotFoundActiveModule,
+    #[error("The module ({0}) is imported by live module instances")]
+    ModuleInUse(String),
 }
 
 /// The error types for WasmEdge Vm.
@@ -320,6 +585,8 @@ pub enum CoreLoadError {
     IllegalOpCode,
     #[error("invalid wasm grammar")]
     IllegalGrammar,
+    #[error("tables cannot be shared")]
+    SharedTable,
 }
 
 /// The error type for the validation phase from WasmEdge Core.
@@ -369,6 +636,8 @@ pub enum CoreValidationError {
     InvalidStartFunc,
     #[error("invalid lane index")]
     InvalidLaneIdx,
+    #[error("shared memory must have maximum")]
+    SharedMemoryNoMax,
 }
 
 /// The error type for the instantiation phase from WasmEdge Core.
@@ -384,6 +653,10 @@ pub enum CoreInstantiationError {
     DataSegDoesNotFit,
     #[error("elements segment does not fit")]
     ElemSegDoesNotFit,
+    #[error("unknown module")]
+    UnknownModule,
+    #[error("module in use")]
+    ModuleInUse,
 }
 
 /// The error type for the execution phase from WasmEdge Core.
@@ -419,4 +692,51 @@ pub enum CoreExecutionError {
     ExecutionFailed,
     #[error("reference type mismatch")]
     RefTypeMismatch,
+    #[error("unaligned atomic")]
+    UnalignedAtomicAccess,
+    #[error("expected shared memory")]
+    ExpectSharedMemory,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostic = Diagnostic {
+            section: Some(String::from("code section")),
+            offset: Some(40),
+            func_index: Some(1),
+            instruction: Some(String::from("i32.add")),
+            expected: vec![ValType::I32],
+            actual: vec![ValType::I64],
+        };
+        assert_eq!(
+            diagnostic.to_string(),
+            "code section, function 1, instruction i32.add at offset 0x28: expected [I32], found [I64]"
+        );
+
+        let diagnostic = Diagnostic {
+            section: None,
+            offset: Some(4),
+            func_index: None,
+            instruction: None,
+            expected: vec![],
+            actual: vec![],
+        };
+        assert_eq!(diagnostic.to_string(), "module at offset 0x4");
+
+        let err = WasmEdgeError::Diagnosed {
+            error: CoreError::Load(CoreLoadError::UnexpectedEnd),
+            diagnostic: Box::new(diagnostic),
+        };
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} in module at offset 0x4",
+                CoreError::Load(CoreLoadError::UnexpectedEnd)
+            )
+        );
+    }
 }
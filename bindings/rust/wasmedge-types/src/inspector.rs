@@ -0,0 +1,1057 @@
+//! Defines a pure-Rust inspector of the structure of WebAssembly binaries.
+
+use crate::{
+    error::{ModuleError, WasmEdgeError},
+    ExternalInstanceType, FuncType, GlobalType, MemoryType, Mutability, RefType, TableType,
+    ValType, WasmEdgeResult,
+};
+
+/// A [ModuleInfo] describes the structure of a WebAssembly binary, including the parts which are not visible from
+/// the imports and exports of an AST module, such as the full type section, the internal functions, the start
+/// function, and the layouts of the element and data segments.
+///
+/// [ModuleInfo] decodes the binary by itself, so it requires neither a loader nor a validator. Note that only the
+/// structure of the binary is checked, the function bodies are not validated.
+///
+/// # Example
+///
+/// ```ignore
+/// let wasm_bytes = std::fs::read("fibonacci.wasm")?;
+/// let info = ModuleInfo::from_bytes(&wasm_bytes)?;
+/// for func in info.functions() {
+///     println!("func[{}]: {:?}", func.index, func.ty);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleInfo {
+    types: Vec<FuncType>,
+    imports: Vec<ImportInfo>,
+    functions: Vec<FunctionInfo>,
+    tables: Vec<TableType>,
+    memories: Vec<MemoryType>,
+    globals: Vec<GlobalInfo>,
+    start: Option<u32>,
+    elements: Vec<ElementSegment>,
+    data: Vec<DataSegment>,
+}
+impl ModuleInfo {
+    /// Decodes the structure of a WebAssembly binary.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The in-memory WebAssembly binary.
+    ///
+    /// # Error
+    ///
+    /// If the binary is malformed or uses an unsupported encoding, then an error is returned.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> WasmEdgeResult<Self> {
+        Self::decode(bytes.as_ref()).map_err(|err| {
+            WasmEdgeError::Module(ModuleError::Malformed {
+                offset: err.offset,
+                reason: err.reason,
+            })
+        })
+    }
+
+    /// Returns the function types declared in the type section.
+    pub fn types(&self) -> &[FuncType] {
+        &self.types
+    }
+
+    /// Returns the imports in the order they are declared.
+    pub fn imports(&self) -> &[ImportInfo] {
+        &self.imports
+    }
+
+    /// Returns all the functions in the function index space. The imported functions come first.
+    pub fn functions(&self) -> &[FunctionInfo] {
+        &self.functions
+    }
+
+    /// Returns the number of the functions defined in this module, imported functions excluded.
+    pub fn defined_func_count(&self) -> usize {
+        self.functions.iter().filter(|x| x.body.is_some()).count()
+    }
+
+    /// Returns the tables defined in this module, imported tables excluded.
+    pub fn tables(&self) -> &[TableType] {
+        &self.tables
+    }
+
+    /// Returns the memories defined in this module, imported memories excluded.
+    pub fn memories(&self) -> &[MemoryType] {
+        &self.memories
+    }
+
+    /// Returns the globals defined in this module, imported globals excluded.
+    pub fn globals(&self) -> &[GlobalInfo] {
+        &self.globals
+    }
+
+    /// Returns the index of the start function.
+    pub fn start(&self) -> Option<u32> {
+        self.start
+    }
+
+    /// Returns the element segments in the order they are declared.
+    pub fn elements(&self) -> &[ElementSegment] {
+        &self.elements
+    }
+
+    /// Returns the data segments in the order they are declared.
+    pub fn data(&self) -> &[DataSegment] {
+        &self.data
+    }
+
+    fn decode(bytes: &[u8]) -> ReadResult<Self> {
+        let mut reader = BinaryReader::new(bytes);
+        if reader.read_bytes(4)? != b"\0asm" {
+            return Err(reader.error("magic header not detected"));
+        }
+        if reader.read_bytes(4)? != [0x01, 0x00, 0x00, 0x00] {
+            return Err(reader.error("unknown binary version"));
+        }
+
+        let mut info = ModuleInfo::default();
+        let mut func_types: Vec<u32> = Vec::new();
+        let mut bodies: Vec<FunctionBody> = Vec::new();
+        let mut data_count = None;
+        let mut last_id = 0u8;
+        while !reader.is_empty() {
+            let id = reader.read_u8()?;
+            let size = reader.read_u32()? as usize;
+            let mut section = reader.sub_reader(size)?;
+            // the data count section is placed between the element and the code sections
+            let order = match id {
+                12 => 10,
+                10 | 11 => id + 1,
+                _ => id,
+            };
+            if id != 0 {
+                if id > 12 {
+                    return Err(section.error("malformed section id"));
+                }
+                if order <= last_id {
+                    return Err(section.error("junk section"));
+                }
+                last_id = order;
+            }
+
+            match id {
+                0 => continue,
+                1 => {
+                    info.types = section.read_vec(|r| r.read_func_type())?;
+                }
+                2 => {
+                    info.imports = section.read_vec(|r| r.read_import(&info.types))?;
+                }
+                3 => {
+                    func_types = section.read_vec(|r| r.read_u32())?;
+                }
+                4 => {
+                    info.tables = section.read_vec(|r| r.read_table_type())?;
+                }
+                5 => {
+                    info.memories = section.read_vec(|r| r.read_memory_type())?;
+                }
+                6 => {
+                    info.globals = section.read_vec(|r| {
+                        let ty = r.read_global_type()?;
+                        let init = r.read_const_expr()?;
+                        Ok(GlobalInfo { ty, init })
+                    })?;
+                }
+                7 => {
+                    section.read_vec(|r| {
+                        r.read_name()?;
+                        if r.read_u8()? > 0x03 {
+                            return Err(r.error("malformed export kind"));
+                        }
+                        r.read_u32()
+                    })?;
+                }
+                8 => {
+                    info.start = Some(section.read_u32()?);
+                }
+                9 => {
+                    info.elements = section.read_vec(|r| r.read_element_segment())?;
+                }
+                10 => {
+                    bodies = section.read_vec(|r| r.read_function_body())?;
+                }
+                11 => {
+                    info.data = section.read_vec(|r| r.read_data_segment())?;
+                }
+                _ => {
+                    data_count = Some(section.read_u32()?);
+                }
+            }
+            if !section.is_empty() {
+                return Err(section.error("section size mismatch"));
+            }
+        }
+
+        if func_types.len() != bodies.len() {
+            return Err(reader.error("function and code section have inconsistent lengths"));
+        }
+        if let Some(count) = data_count {
+            if count as usize != info.data.len() {
+                return Err(reader.error("data count and data section have inconsistent lengths"));
+            }
+        }
+
+        let imported = info
+            .imports
+            .iter()
+            .filter_map(|import| match (&import.ty, import.type_index) {
+                (ExternalInstanceType::Func(ty), Some(type_index)) => {
+                    Some((type_index, ty.clone(), None))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut defined = Vec::with_capacity(func_types.len());
+        for (type_index, body) in func_types.into_iter().zip(bodies) {
+            let ty = info
+                .types
+                .get(type_index as usize)
+                .cloned()
+                .ok_or_else(|| reader.error("unknown type"))?;
+            defined.push((type_index, ty, Some(body)));
+        }
+        info.functions = imported
+            .into_iter()
+            .chain(defined)
+            .enumerate()
+            .map(|(index, (type_index, ty, body))| FunctionInfo {
+                index: index as u32,
+                type_index,
+                ty,
+                body,
+            })
+            .collect();
+
+        Ok(info)
+    }
+}
+
+/// Describes an import of a WebAssembly binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportInfo {
+    /// The module name that this import is expected to come from.
+    pub module: String,
+    /// The name of this import.
+    pub name: String,
+    /// The type of this import.
+    pub ty: ExternalInstanceType,
+    /// The index of the function type if this is a function import; otherwise, `None`.
+    pub type_index: Option<u32>,
+}
+
+/// Describes a function in the function index space of a WebAssembly binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionInfo {
+    /// The index of this function in the function index space.
+    pub index: u32,
+    /// The index of the function type in the type section.
+    pub type_index: u32,
+    /// The type of this function.
+    pub ty: FuncType,
+    /// The body of this function. An imported function has no body.
+    pub body: Option<FunctionBody>,
+}
+
+/// Describes the body of a function defined in a WebAssembly binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionBody {
+    /// The declared locals in run-length encoding, parameters excluded.
+    pub locals: Vec<(u32, ValType)>,
+    /// The offset of the body in the binary, local declarations included.
+    pub offset: usize,
+    /// The size of the body in bytes, local declarations included.
+    pub size: usize,
+}
+
+/// Describes a global defined in a WebAssembly binary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalInfo {
+    /// The type of this global.
+    pub ty: GlobalType,
+    /// The initializer of this global.
+    pub init: ConstExpr,
+}
+
+/// Defines the constant expressions used as initializers and segment offsets.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstExpr {
+    /// An `i32.const` instruction.
+    I32(i32),
+    /// An `i64.const` instruction.
+    I64(i64),
+    /// An `f32.const` instruction.
+    F32(f32),
+    /// An `f64.const` instruction.
+    F64(f64),
+    /// A `v128.const` instruction.
+    V128(u128),
+    /// A `ref.null` instruction.
+    RefNull(RefType),
+    /// A `ref.func` instruction with the index of the function.
+    RefFunc(u32),
+    /// A `global.get` instruction with the index of the global.
+    GlobalGet(u32),
+    /// An expression with more than one instruction. The raw bytes exclude the trailing `end` opcode.
+    Expr(Vec<u8>),
+}
+
+/// Defines the modes of element and data segments.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SegmentMode {
+    /// The segment is copied into the table or memory with the given index at the given offset during instantiation.
+    Active {
+        /// The index of the table or memory.
+        index: u32,
+        /// The offset in the table or memory.
+        offset: ConstExpr,
+    },
+    /// The segment is only used by the `table.init` or `memory.init` instructions.
+    Passive,
+    /// The segment only forward-declares the functions referenced by `ref.func`.
+    Declarative,
+}
+
+/// Describes an element segment of a WebAssembly binary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementSegment {
+    /// The mode of this segment.
+    pub mode: SegmentMode,
+    /// The reference type of the items.
+    pub ty: RefType,
+    /// The items of this segment. A function index is normalized as a [ConstExpr::RefFunc].
+    pub items: Vec<ConstExpr>,
+}
+
+/// Describes a data segment of a WebAssembly binary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataSegment {
+    /// The mode of this segment. A data segment is never declarative.
+    pub mode: SegmentMode,
+    /// The offset of the data bytes in the binary.
+    pub offset: usize,
+    /// The size of the data bytes.
+    pub size: usize,
+}
+
+#[doc(hidden)]
+pub type ReadResult<T> = Result<T, ReadError>;
+
+/// The error raised by [BinaryReader], with the offset in the binary where it occurs.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ReadError {
+    pub offset: usize,
+    pub reason: String,
+}
+
+/// A cursor over a WebAssembly binary, which decodes the LEB128 integers, names and types. It is shared with the
+/// decoder of the name section in wasmedge-sys.
+#[doc(hidden)]
+pub struct BinaryReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    base: usize,
+}
+impl<'a> BinaryReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            base: 0,
+        }
+    }
+
+    pub fn error(&self, reason: impl Into<String>) -> ReadError {
+        ReadError {
+            offset: self.base + self.pos,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len().saturating_sub(self.pos)
+    }
+
+    pub fn offset(&self) -> usize {
+        self.base + self.pos
+    }
+
+    pub fn read_u8(&mut self) -> ReadResult<u8> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| self.error("unexpected end"))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> ReadResult<&'a [u8]> {
+        match self.pos.checked_add(len) {
+            Some(end) if end <= self.bytes.len() => {
+                let bytes = &self.bytes[self.pos..end];
+                self.pos = end;
+                Ok(bytes)
+            }
+            _ => Err(self.error("unexpected end")),
+        }
+    }
+
+    /// Returns a reader over the next `len` bytes, and skips them in this reader.
+    pub fn sub_reader(&mut self, len: usize) -> ReadResult<BinaryReader<'a>> {
+        let base = self.offset();
+        let bytes = self.read_bytes(len)?;
+        Ok(BinaryReader {
+            bytes,
+            pos: 0,
+            base,
+        })
+    }
+
+    pub fn read_u32(&mut self) -> ReadResult<u32> {
+        let value = self.read_leb(32, false)?;
+        Ok(value as u32)
+    }
+
+    fn read_i32(&mut self) -> ReadResult<i32> {
+        let value = self.read_leb(32, true)?;
+        Ok(value as i32)
+    }
+
+    fn read_i64(&mut self) -> ReadResult<i64> {
+        let value = self.read_leb(64, true)?;
+        Ok(value as i64)
+    }
+
+    /// Decodes an LEB128 integer of at most `bits` bits. A signed integer is sign-extended to 64 bits.
+    fn read_leb(&mut self, bits: u32, signed: bool) -> ReadResult<u64> {
+        let mut result = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.read_u8()?;
+            if shift + 7 > bits {
+                // the unused bits of the last byte must be the extension of the value
+                let used = bits - shift;
+                let unused = (byte & 0x7F) >> used;
+                let valid = match signed && (byte >> (used - 1)) & 1 == 1 {
+                    true => unused == (0x7F >> used),
+                    false => unused == 0,
+                };
+                if byte & 0x80 != 0 || !valid {
+                    return Err(self.error("integer too large"));
+                }
+            }
+            result |= ((byte & 0x7F) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if signed && shift < 64 && byte & 0x40 != 0 {
+                    result |= !0u64 << shift;
+                }
+                return Ok(result);
+            }
+        }
+    }
+
+    pub fn read_name(&mut self) -> ReadResult<String> {
+        let len = self.read_u32()? as usize;
+        let bytes = self.read_bytes(len)?;
+        std::str::from_utf8(bytes)
+            .map(|x| x.to_string())
+            .map_err(|_| self.error("malformed UTF-8 encoding"))
+    }
+
+    pub fn read_vec<T>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> ReadResult<T>,
+    ) -> ReadResult<Vec<T>> {
+        let count = self.read_u32()? as usize;
+        // every item takes at least one byte, which bounds the preallocation of malformed vectors
+        let mut items = Vec::with_capacity(count.min(self.remaining()));
+        for _ in 0..count {
+            items.push(f(self)?);
+        }
+        Ok(items)
+    }
+
+    fn read_val_type(&mut self) -> ReadResult<ValType> {
+        match self.read_u8()? {
+            0x7F => Ok(ValType::I32),
+            0x7E => Ok(ValType::I64),
+            0x7D => Ok(ValType::F32),
+            0x7C => Ok(ValType::F64),
+            0x7B => Ok(ValType::V128),
+            0x70 => Ok(ValType::FuncRef),
+            0x6F => Ok(ValType::ExternRef),
+            _ => Err(self.error("malformed value type")),
+        }
+    }
+
+    fn read_ref_type(&mut self) -> ReadResult<RefType> {
+        match self.read_u8()? {
+            0x70 => Ok(RefType::FuncRef),
+            0x6F => Ok(RefType::ExternRef),
+            _ => Err(self.error("malformed reference type")),
+        }
+    }
+
+    fn read_func_type(&mut self) -> ReadResult<FuncType> {
+        if self.read_u8()? != 0x60 {
+            return Err(self.error("malformed function type"));
+        }
+        let args = self.read_vec(|r| r.read_val_type())?;
+        let returns = self.read_vec(|r| r.read_val_type())?;
+        Ok(FuncType::new(
+            Some(args).filter(|x| !x.is_empty()),
+            Some(returns).filter(|x| !x.is_empty()),
+        ))
+    }
+
+    fn read_limits(&mut self) -> ReadResult<(u8, u32, Option<u32>)> {
+        let flags = self.read_u8()?;
+        let (min, max) = match flags {
+            0x00 | 0x02 => (self.read_u32()?, None),
+            0x01 | 0x03 => (self.read_u32()?, Some(self.read_u32()?)),
+            _ => return Err(self.error("integer too large")),
+        };
+        Ok((flags, min, max))
+    }
+
+    fn read_table_type(&mut self) -> ReadResult<TableType> {
+        let elem_ty = self.read_ref_type()?;
+        match self.read_limits()? {
+            (0x00 | 0x01, min, max) => Ok(TableType::new(elem_ty, min, max)),
+            _ => Err(self.error("unsupported table limits")),
+        }
+    }
+
+    fn read_memory_type(&mut self) -> ReadResult<MemoryType> {
+        match self.read_limits()? {
+            (0x00 | 0x01, min, max) => Ok(MemoryType::new(min, max)),
+            (0x03, min, Some(max)) => Ok(MemoryType::new_shared(min, max)),
+            _ => Err(self.error("shared memory must have maximum")),
+        }
+    }
+
+    fn read_global_type(&mut self) -> ReadResult<GlobalType> {
+        let ty = self.read_val_type()?;
+        let mutability = match self.read_u8()? {
+            0x00 => Mutability::Const,
+            0x01 => Mutability::Var,
+            _ => return Err(self.error("malformed mutability")),
+        };
+        Ok(GlobalType::new(ty, mutability))
+    }
+
+    fn read_import(&mut self, types: &[FuncType]) -> ReadResult<ImportInfo> {
+        let module = self.read_name()?;
+        let name = self.read_name()?;
+        let (ty, type_index) = match self.read_u8()? {
+            0x00 => {
+                let idx = self.read_u32()?;
+                let ty = types
+                    .get(idx as usize)
+                    .cloned()
+                    .ok_or_else(|| self.error("unknown type"))?;
+                (ExternalInstanceType::Func(ty), Some(idx))
+            }
+            0x01 => (ExternalInstanceType::Table(self.read_table_type()?), None),
+            0x02 => (ExternalInstanceType::Memory(self.read_memory_type()?), None),
+            0x03 => (ExternalInstanceType::Global(self.read_global_type()?), None),
+            _ => return Err(self.error("malformed import kind")),
+        };
+        Ok(ImportInfo {
+            module,
+            name,
+            ty,
+            type_index,
+        })
+    }
+
+    fn read_const_expr(&mut self) -> ReadResult<ConstExpr> {
+        let start = self.pos;
+        let mut instrs = Vec::new();
+        loop {
+            let instr = match self.read_u8()? {
+                0x0B => break,
+                0x41 => ConstExpr::I32(self.read_i32()?),
+                0x42 => ConstExpr::I64(self.read_i64()?),
+                0x43 => {
+                    let bytes = self.read_bytes(4)?;
+                    ConstExpr::F32(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                }
+                0x44 => {
+                    let mut buf = [0u8; 8];
+                    buf.copy_from_slice(self.read_bytes(8)?);
+                    ConstExpr::F64(f64::from_le_bytes(buf))
+                }
+                0xD0 => ConstExpr::RefNull(self.read_ref_type()?),
+                0xD2 => ConstExpr::RefFunc(self.read_u32()?),
+                0x23 => ConstExpr::GlobalGet(self.read_u32()?),
+                // i32.add, i32.sub, i32.mul, i64.add, i64.sub and i64.mul of the extended constant expressions
+                0x6A | 0x6B | 0x6C | 0x7C | 0x7D | 0x7E => ConstExpr::Expr(Vec::new()),
+                0xFD => {
+                    if self.read_u32()? != 12 {
+                        return Err(self.error("constant expression required"));
+                    }
+                    let mut buf = [0u8; 16];
+                    buf.copy_from_slice(self.read_bytes(16)?);
+                    ConstExpr::V128(u128::from_le_bytes(buf))
+                }
+                _ => return Err(self.error("constant expression required")),
+            };
+            instrs.push(instr);
+        }
+        match instrs.len() {
+            0 => Err(self.error("type mismatch")),
+            1 if !matches!(instrs[0], ConstExpr::Expr(_)) => Ok(instrs.remove(0)),
+            _ => Ok(ConstExpr::Expr(self.bytes[start..self.pos - 1].to_vec())),
+        }
+    }
+
+    fn read_element_segment(&mut self) -> ReadResult<ElementSegment> {
+        let flags = self.read_u32()?;
+        if flags > 7 {
+            return Err(self.error("malformed elements segment kind"));
+        }
+        let mode = match flags {
+            0 | 4 => SegmentMode::Active {
+                index: 0,
+                offset: self.read_const_expr()?,
+            },
+            2 | 6 => {
+                let index = self.read_u32()?;
+                SegmentMode::Active {
+                    index,
+                    offset: self.read_const_expr()?,
+                }
+            }
+            1 | 5 => SegmentMode::Passive,
+            _ => SegmentMode::Declarative,
+        };
+        // flags with the bit 2 set use expressions instead of function indexes
+        let uses_exprs = flags & 0x04 != 0;
+        let ty = match (flags, uses_exprs) {
+            (0 | 4, _) => RefType::FuncRef,
+            (_, true) => self.read_ref_type()?,
+            (_, false) => match self.read_u8()? {
+                0x00 => RefType::FuncRef,
+                _ => return Err(self.error("malformed element kind")),
+            },
+        };
+        let items = match uses_exprs {
+            true => self.read_vec(|r| r.read_const_expr())?,
+            false => self.read_vec(|r| r.read_u32().map(ConstExpr::RefFunc))?,
+        };
+        Ok(ElementSegment { mode, ty, items })
+    }
+
+    fn read_function_body(&mut self) -> ReadResult<FunctionBody> {
+        let size = self.read_u32()? as usize;
+        let offset = self.offset();
+        let mut body = self.sub_reader(size)?;
+        let locals = body.read_vec(|r| {
+            let count = r.read_u32()?;
+            let ty = r.read_val_type()?;
+            Ok((count, ty))
+        })?;
+        let total = locals
+            .iter()
+            .try_fold(0u64, |sum, (count, _)| sum.checked_add(*count as u64));
+        if !matches!(total, Some(x) if x <= u32::MAX as u64) {
+            return Err(body.error("too many locals"));
+        }
+        Ok(FunctionBody {
+            locals,
+            offset,
+            size,
+        })
+    }
+
+    fn read_data_segment(&mut self) -> ReadResult<DataSegment> {
+        let mode = match self.read_u32()? {
+            0 => SegmentMode::Active {
+                index: 0,
+                offset: self.read_const_expr()?,
+            },
+            1 => SegmentMode::Passive,
+            2 => {
+                let index = self.read_u32()?;
+                SegmentMode::Active {
+                    index,
+                    offset: self.read_const_expr()?,
+                }
+            }
+            _ => return Err(self.error("malformed data segment kind")),
+        };
+        let size = self.read_u32()? as usize;
+        let offset = self.offset();
+        self.read_bytes(size)?;
+        Ok(DataSegment { mode, offset, size })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wat2wasm;
+
+    #[test]
+    fn test_inspector_module_info() {
+        let result = wat2wasm(
+            br#"
+            (module
+                (type $binop (func (param i32 i32) (result i32)))
+                (import "env" "log" (func $log (param i32)))
+                (import "env" "mem" (memory 1))
+                (table 2 10 funcref)
+                (global $counter (mut i32) (i32.const 7))
+                (global $base i64 (i64.const -1))
+                (func $add (type $binop)
+                    local.get 0
+                    local.get 1
+                    i32.add)
+                (func $init
+                    (local i32 i32) (local i64)
+                    i32.const 1
+                    call $log)
+                (start $init)
+                (elem (i32.const 1) $add)
+                (elem declare func $init)
+                (data (i32.const 16) "hello")
+                (data "world!")
+                (export "add" (func $add))
+            )
+        "#,
+        );
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+
+        let result = ModuleInfo::from_bytes(&wasm_bytes);
+        assert!(result.is_ok());
+        let info = result.unwrap();
+
+        // check types
+        let binop = FuncType::new(Some(vec![ValType::I32; 2]), Some(vec![ValType::I32]));
+        assert_eq!(info.types().len(), 3);
+        assert_eq!(info.types()[0], binop);
+
+        // check imports
+        assert_eq!(info.imports().len(), 2);
+        assert_eq!(info.imports()[0].module, "env");
+        assert_eq!(info.imports()[0].name, "log");
+        assert_eq!(
+            info.imports()[0].ty,
+            ExternalInstanceType::Func(FuncType::new(Some(vec![ValType::I32]), None))
+        );
+        assert_eq!(
+            info.imports()[1].ty,
+            ExternalInstanceType::Memory(MemoryType::new(1, None))
+        );
+
+        // check functions
+        assert_eq!(info.functions().len(), 3);
+        assert_eq!(info.defined_func_count(), 2);
+        let log = &info.functions()[0];
+        assert_eq!(log.index, 0);
+        assert!(log.body.is_none());
+        let add = &info.functions()[1];
+        assert_eq!(add.index, 1);
+        assert_eq!(add.type_index, 0);
+        assert_eq!(add.ty, binop);
+        assert!(add.body.is_some());
+        let init = &info.functions()[2];
+        assert_eq!(init.ty, FuncType::new(None, None));
+        let body = init.body.as_ref().unwrap();
+        assert_eq!(body.locals, [(2, ValType::I32), (1, ValType::I64)]);
+        assert_eq!(wasm_bytes[body.offset + body.size - 1], 0x0B);
+
+        // check start function
+        assert_eq!(info.start(), Some(2));
+
+        // check tables, memories and globals
+        assert_eq!(
+            info.tables(),
+            [TableType::new(RefType::FuncRef, 2, Some(10))]
+        );
+        assert!(info.memories().is_empty());
+        assert_eq!(info.globals().len(), 2);
+        assert_eq!(
+            info.globals()[0].ty,
+            GlobalType::new(ValType::I32, Mutability::Var)
+        );
+        assert_eq!(info.globals()[0].init, ConstExpr::I32(7));
+        assert_eq!(info.globals()[1].init, ConstExpr::I64(-1));
+
+        // check element segments
+        assert_eq!(info.elements().len(), 2);
+        assert_eq!(
+            info.elements()[0].mode,
+            SegmentMode::Active {
+                index: 0,
+                offset: ConstExpr::I32(1)
+            }
+        );
+        assert_eq!(info.elements()[0].ty, RefType::FuncRef);
+        assert_eq!(info.elements()[0].items, [ConstExpr::RefFunc(1)]);
+        assert_eq!(info.elements()[1].mode, SegmentMode::Declarative);
+        assert_eq!(info.elements()[1].items, [ConstExpr::RefFunc(2)]);
+
+        // check data segments
+        assert_eq!(info.data().len(), 2);
+        let hello = &info.data()[0];
+        assert_eq!(
+            hello.mode,
+            SegmentMode::Active {
+                index: 0,
+                offset: ConstExpr::I32(16)
+            }
+        );
+        assert_eq!(
+            &wasm_bytes[hello.offset..hello.offset + hello.size],
+            b"hello"
+        );
+        let world = &info.data()[1];
+        assert_eq!(world.mode, SegmentMode::Passive);
+        assert_eq!(world.size, 6);
+    }
+
+    #[test]
+    fn test_inspector_malformed() {
+        let result = ModuleInfo::from_bytes(b"\0asm");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Module(ModuleError::Malformed {
+                offset: 4,
+                reason: "unexpected end".into()
+            })
+        );
+
+        let result = ModuleInfo::from_bytes(b"\0wasm\x01\x00\x00\x00");
+        assert!(result.is_err());
+
+        // a type section declaring more bytes than present
+        let result = ModuleInfo::from_bytes(b"\0asm\x01\x00\x00\x00\x01\x05\x01\x60");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Module(ModuleError::Malformed {
+                offset: 10,
+                reason: "unexpected end".into()
+            })
+        );
+
+        // a function section without a code section
+        let result = ModuleInfo::from_bytes(
+            b"\0asm\x01\x00\x00\x00\x01\x04\x01\x60\x00\x00\x03\x02\x01\x00",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_inspector_truncated() {
+        let result = wat2wasm(
+            br#"
+            (module
+                (type (func (param i32) (result i32)))
+                (import "env" "f" (func (type 0)))
+                (table 1 2 funcref)
+                (memory 1 2)
+                (global (mut i32) (i32.const 1))
+                (export "g" (global 0))
+                (start 2)
+                (elem (i32.const 0) func 0)
+                (func (type 0) local.get 0)
+                (func data.drop 0)
+                (data (i32.const 0) "x")
+            )
+            "#,
+        );
+        assert!(result.is_ok());
+        let wasm_bytes = result.unwrap();
+        assert!(ModuleInfo::from_bytes(&wasm_bytes).is_ok());
+
+        let sections = sections(&wasm_bytes);
+        assert_eq!(
+            sections.iter().map(|(id, _, _)| *id).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 12, 10, 11]
+        );
+        for (id, start, size) in sections {
+            // the binary ends in the middle of the section
+            let result = ModuleInfo::from_bytes(&wasm_bytes[..start + size - 1]);
+            assert!(result.is_err(), "section {}", id);
+            assert_eq!(
+                result.unwrap_err(),
+                WasmEdgeError::Module(ModuleError::Malformed {
+                    offset: start,
+                    reason: "unexpected end".into()
+                }),
+                "section {}",
+                id
+            );
+
+            // the section ends in the middle of its last item
+            let mut bytes = wasm_bytes.to_vec();
+            bytes[start - 1] -= 1;
+            bytes.remove(start + size - 1);
+            let result = ModuleInfo::from_bytes(&bytes);
+            assert!(result.is_err(), "section {}", id);
+            assert!(
+                matches!(
+                    result.unwrap_err(),
+                    WasmEdgeError::Module(ModuleError::Malformed { offset, reason })
+                        if (start..start + size).contains(&offset) && reason == "unexpected end"
+                ),
+                "section {}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_inspector_malformed_sections() {
+        let func_ty: &[u8] = &[1, 0x60, 0, 0];
+
+        // section ids and order
+        assert_eq!(malformed(&[(13, &[])]), "malformed section id");
+        assert_eq!(malformed(&[(3, &[0]), (2, &[0])]), "junk section");
+        assert_eq!(malformed(&[(8, &[0, 0])]), "section size mismatch");
+
+        // type section
+        assert_eq!(
+            malformed(&[(1, &[1, 0x61, 0, 0])]),
+            "malformed function type"
+        );
+        assert_eq!(
+            malformed(&[(1, &[1, 0x60, 1, 0x40, 0])]),
+            "malformed value type"
+        );
+
+        // import section
+        assert_eq!(
+            malformed(&[(2, &[1, 1, b'm', 1, b'n', 0x04, 0])]),
+            "malformed import kind"
+        );
+        assert_eq!(
+            malformed(&[(2, &[1, 1, b'm', 1, b'n', 0x00, 0])]),
+            "unknown type"
+        );
+
+        // function section
+        assert_eq!(
+            malformed(&[(1, func_ty), (3, &[1, 5]), (10, &[1, 2, 0, 0x0B])]),
+            "unknown type"
+        );
+
+        // table section
+        assert_eq!(
+            malformed(&[(4, &[1, 0x40, 0, 1])]),
+            "malformed reference type"
+        );
+        assert_eq!(
+            malformed(&[(4, &[1, 0x70, 0x02, 1])]),
+            "unsupported table limits"
+        );
+
+        // memory section
+        assert_eq!(
+            malformed(&[(5, &[1, 0x02, 1])]),
+            "shared memory must have maximum"
+        );
+        assert_eq!(malformed(&[(5, &[1, 0x04, 1])]), "integer too large");
+        assert_eq!(
+            malformed(&[(5, &[1, 0x00, 0x80, 0x80, 0x80, 0x80, 0x10])]),
+            "integer too large"
+        );
+
+        // global section
+        assert_eq!(
+            malformed(&[(6, &[1, 0x7F, 0x02, 0x41, 0, 0x0B])]),
+            "malformed mutability"
+        );
+        assert_eq!(malformed(&[(6, &[1, 0x7F, 0x00, 0x0B])]), "type mismatch");
+        assert_eq!(
+            malformed(&[(6, &[1, 0x7F, 0x00, 0x01, 0x0B])]),
+            "constant expression required"
+        );
+
+        // export section
+        assert_eq!(
+            malformed(&[(7, &[1, 1, b'e', 0x04, 0])]),
+            "malformed export kind"
+        );
+        assert_eq!(
+            malformed(&[(7, &[1, 2, 0xFF, 0xFE, 0x00, 0])]),
+            "malformed UTF-8 encoding"
+        );
+
+        // element section
+        assert_eq!(
+            malformed(&[(9, &[1, 8])]),
+            "malformed elements segment kind"
+        );
+        assert_eq!(
+            malformed(&[(9, &[1, 1, 0x01, 0])]),
+            "malformed element kind"
+        );
+
+        // code section
+        assert_eq!(
+            malformed(&[(10, &[1, 2, 0, 0x0B])]),
+            "function and code section have inconsistent lengths"
+        );
+        let mut code = vec![1, 13, 2];
+        code.extend([0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x7F].repeat(2));
+        assert_eq!(
+            malformed(&[(1, func_ty), (3, &[1, 0]), (10, &code)]),
+            "too many locals"
+        );
+
+        // data count and data sections
+        assert_eq!(
+            malformed(&[(12, &[1])]),
+            "data count and data section have inconsistent lengths"
+        );
+        assert_eq!(malformed(&[(11, &[1, 3])]), "malformed data segment kind");
+    }
+
+    /// Returns the ids, the offsets and the sizes of the contents of the sections in a binary.
+    fn sections(bytes: &[u8]) -> Vec<(u8, usize, usize)> {
+        let mut sections = Vec::new();
+        let mut pos = 8;
+        while pos < bytes.len() {
+            let id = bytes[pos];
+            let size = bytes[pos + 1] as usize;
+            assert!(size < 0x80);
+            sections.push((id, pos + 2, size));
+            pos += 2 + size;
+        }
+        sections
+    }
+
+    /// Builds a binary with the given sections, and returns the reason why it is malformed.
+    fn malformed(sections: &[(u8, &[u8])]) -> String {
+        let mut bytes = b"\0asm\x01\x00\x00\x00".to_vec();
+        for (id, content) in sections {
+            bytes.push(*id);
+            bytes.push(content.len() as u8);
+            bytes.extend_from_slice(content);
+        }
+        match ModuleInfo::from_bytes(&bytes) {
+            Err(WasmEdgeError::Module(ModuleError::Malformed { reason, .. })) => reason,
+            result => panic!("expected a malformed module, got {:?}", result),
+        }
+    }
+}
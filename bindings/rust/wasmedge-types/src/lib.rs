@@ -4,7 +4,9 @@
 //!
 //! * [WasmEdge Runtime](https://wasmedge.org/)
 
+pub mod cost;
 pub mod error;
+pub mod inspector;
 
 /// Defines WasmEdge reference types.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -315,7 +317,7 @@ impl FuncType {
 pub struct TableType {
     elem_ty: RefType,
     min: u32,
-    max: u32,
+    max: Option<u32>,
 }
 impl TableType {
     /// Creates a new [TableType] with the given element type and the size range.
@@ -326,12 +328,8 @@ impl TableType {
     ///
     /// * `min` - The minimum size of the table to be created.
     ///
-    /// * `max` - The maximum size of the table to be created.    
+    /// * `max` - The maximum size of the table to be created. `None` means the table can grow without an upper bound.
     pub fn new(elem_ty: RefType, min: u32, max: Option<u32>) -> Self {
-        let max = match max {
-            Some(val) => val,
-            None => u32::MAX,
-        };
         Self { elem_ty, min, max }
     }
 
@@ -345,8 +343,8 @@ impl TableType {
         self.min
     }
 
-    /// Returns the maximum size defined in the [TableType].
-    pub fn maximum(&self) -> u32 {
+    /// Returns the maximum size defined in the [TableType], or `None` if the maximum size is not declared.
+    pub fn maximum(&self) -> Option<u32> {
         self.max
     }
 }
@@ -355,18 +353,20 @@ impl Default for TableType {
         Self {
             elem_ty: RefType::FuncRef,
             min: 0,
-            max: u32::MAX,
+            max: None,
         }
     }
 }
 
 /// Struct of WasmEdge MemoryType.
 ///
-/// A [MemoryType] is used to declare the size range of a WasmEdge Memory to be created.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// A [MemoryType] is used to declare the size range of a WasmEdge Memory to be created. The size range is counted in
+/// pages of a 32-bit memory, since the WasmEdge runtime does not support the 64-bit memories of the Memory64 proposal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct MemoryType {
     min: u32,
-    max: u32,
+    max: Option<u32>,
+    shared: bool,
 }
 impl MemoryType {
     /// Creates a new [MemoryType] with the given size range.
@@ -375,13 +375,30 @@ impl MemoryType {
     ///
     /// * `min` - The minimum size of the memory to be created.
     ///
-    /// * `max` - The maximum size of the memory to be created.
+    /// * `max` - The maximum size of the memory to be created. `None` means the memory can grow without an upper bound.
     pub fn new(min: u32, max: Option<u32>) -> Self {
-        let max = match max {
-            Some(max) => max,
-            None => u32::MAX,
-        };
-        Self { min, max }
+        Self {
+            min,
+            max,
+            shared: false,
+        }
+    }
+
+    /// Creates a new shared [MemoryType] with the given size range.
+    ///
+    /// Shared memories are defined in the [Threads proposal](https://github.com/WebAssembly/threads/blob/main/proposals/threads/Overview.md), and must declare their maximum size.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum size of the memory to be created.
+    ///
+    /// * `max` - The maximum size of the memory to be created.
+    pub fn new_shared(min: u32, max: u32) -> Self {
+        Self {
+            min,
+            max: Some(max),
+            shared: true,
+        }
     }
 
     /// Returns the minimum size defined in the [MemoryType].
@@ -389,17 +406,14 @@ impl MemoryType {
         self.min
     }
 
-    /// Returns the maximum size defined in the [MemoryType].
-    pub fn maximum(&self) -> u32 {
+    /// Returns the maximum size defined in the [MemoryType], or `None` if the maximum size is not declared.
+    pub fn maximum(&self) -> Option<u32> {
         self.max
     }
-}
-impl Default for MemoryType {
-    fn default() -> Self {
-        Self {
-            min: 0,
-            max: u32::MAX,
-        }
+
+    /// Returns `true` if the [MemoryType] declares a shared memory.
+    pub fn is_shared(&self) -> bool {
+        self.shared
     }
 }
 
@@ -442,8 +456,68 @@ impl Default for GlobalType {
     }
 }
 
+/// Defines the result of waiting on a shared memory address.
+///
+/// The values follow the return codes of the `memory.atomic.wait` instructions defined in the [Threads proposal](https://github.com/WebAssembly/threads/blob/main/proposals/threads/Overview.md).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomicWaitResult {
+    /// The waiter was woken up by a notification.
+    Ok,
+    /// The loaded value did not match the expected value, so the waiter did not wait.
+    NotEqual,
+    /// The waiter was not woken up before the timeout expired.
+    TimedOut,
+}
+impl From<u32> for AtomicWaitResult {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => AtomicWaitResult::Ok,
+            1 => AtomicWaitResult::NotEqual,
+            2 => AtomicWaitResult::TimedOut,
+            _ => panic!("[wasmedge-types] Invalid AtomicWaitResult: {:#X}", value),
+        }
+    }
+}
+impl From<AtomicWaitResult> for u32 {
+    fn from(value: AtomicWaitResult) -> Self {
+        match value {
+            AtomicWaitResult::Ok => 0,
+            AtomicWaitResult::NotEqual => 1,
+            AtomicWaitResult::TimedOut => 2,
+        }
+    }
+}
+
 /// Parses in-memory bytes as either the [WebAssembly Text format](http://webassembly.github.io/spec/core/text/index.html), or a binary WebAssembly module.
 pub use wat::parse_bytes as wat2wasm;
 
+/// Parses a module in the WebAssembly text format into the binary format.
+///
+/// Unlike [wat2wasm], a parse error is returned as a [WatError](crate::error::WatError) carrying the 1-based line
+/// and column where the error occurs.
+///
+/// # Argument
+///
+/// * `wat` - The module in the WebAssembly text format.
+///
+/// # Error
+///
+/// If fail to parse or encode the module, then an error is returned.
+pub fn parse_wat(wat: impl AsRef<str>) -> Result<Vec<u8>, error::WatError> {
+    let wat = wat.as_ref();
+    let to_wat_error = |err: wast::Error| {
+        let (line, column) = err.span().linecol_in(wat);
+        error::WatError::Parse {
+            message: err.message(),
+            line: line + 1,
+            column: column + 1,
+        }
+    };
+
+    let buf = wast::parser::ParseBuffer::new(wat).map_err(to_wat_error)?;
+    let mut module = wast::parser::parse::<wast::Wat>(&buf).map_err(to_wat_error)?;
+    module.encode().map_err(to_wat_error)
+}
+
 /// The WasmEdge result type.
 pub type WasmEdgeResult<T> = Result<T, error::WasmEdgeError>;
@@ -51,6 +51,25 @@ typedef struct WasmEdge_String {
   const char *Buf;
 } WasmEdge_String;
 
+/// Logging levels of the WasmEdge logging system.
+enum WasmEdge_LogLevel {
+  WasmEdge_LogLevel_Trace,
+  WasmEdge_LogLevel_Debug,
+  WasmEdge_LogLevel_Info,
+  WasmEdge_LogLevel_Warn,
+  WasmEdge_LogLevel_Error,
+  WasmEdge_LogLevel_Critical,
+  WasmEdge_LogLevel_Off
+};
+
+/// Callback of the WasmEdge logging system.
+///
+/// The logger name and the message are only valid during the callback.
+typedef void (*WasmEdge_LogCallback_t)(void *Data,
+                                       const enum WasmEdge_LogLevel Level,
+                                       const WasmEdge_String LoggerName,
+                                       const WasmEdge_String Message);
+
 /// Opaque struct of WASM execution result.
 typedef struct WasmEdge_Result {
   uint8_t Code;
@@ -67,8 +86,45 @@ typedef struct WasmEdge_Limit {
   uint32_t Min;
   /// Maximum value. Will be ignored if the `HasMax` is false.
   uint32_t Max;
+  /// Boolean to describe is shared memory or not (Threads proposal).
+  bool Shared;
 } WasmEdge_Limit;
 
+/// Struct of a frame in the backtrace of a trap.
+typedef struct WasmEdge_TrapFrame {
+  /// Name of the module instance. Empty for the anonymous module instance.
+  WasmEdge_String ModuleName;
+  /// Index of the function in the module instance, including the imported
+  /// functions.
+  uint32_t FunctionIndex;
+  /// Boolean to describe has the instruction offset or not. Only the
+  /// interpreted functions have the offsets.
+  bool HasOffset;
+  /// Byte offset of the instruction in the WASM binary. Will be ignored if the
+  /// `HasOffset` is false.
+  uint64_t Offset;
+} WasmEdge_TrapFrame;
+
+/// Struct of a function in a profiled call path.
+typedef struct WasmEdge_ProfileEntry {
+  /// Index of the entry of the caller. UINT32_MAX for the outermost functions.
+  uint32_t Parent;
+  /// Name of the module instance. Empty for the anonymous module instance.
+  WasmEdge_String ModuleName;
+  /// Index of the function in the module instance, including the imported
+  /// functions.
+  uint32_t FunctionIndex;
+  /// Boolean to describe the function is a host function or not.
+  bool IsHost;
+  /// Executed instructions in the function itself.
+  uint64_t InstrCount;
+  /// Costs of the instructions in the function itself.
+  uint64_t Cost;
+  /// Execution time in nanoseconds of the function itself, excluding its
+  /// callees.
+  uint64_t Time;
+} WasmEdge_ProfileEntry;
+
 /// Opaque struct of WasmEdge configure.
 typedef struct WasmEdge_ConfigureContext WasmEdge_ConfigureContext;
 
@@ -105,6 +161,9 @@ typedef struct WasmEdge_LoaderContext WasmEdge_LoaderContext;
 /// Opaque struct of WasmEdge validator.
 typedef struct WasmEdge_ValidatorContext WasmEdge_ValidatorContext;
 
+/// Opaque struct of WasmEdge load or validation diagnostic.
+typedef struct WasmEdge_DiagnosticContext WasmEdge_DiagnosticContext;
+
 /// Opaque struct of WasmEdge executor.
 typedef struct WasmEdge_ExecutorContext WasmEdge_ExecutorContext;
 
@@ -171,6 +230,24 @@ WASMEDGE_CAPI_EXPORT extern void WasmEdge_LogSetErrorLevel(void);
 /// Set the logging system to filter to debug level.
 WASMEDGE_CAPI_EXPORT extern void WasmEdge_LogSetDebugLevel(void);
 
+/// Set the logging system to filter to the given level.
+///
+/// \param Level the lowest level of the log messages to output.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_LogSetLevel(const enum WasmEdge_LogLevel Level);
+
+/// Set the callback to receive the log messages instead of the console.
+///
+/// The callback may be called from any thread, and the calls are serialized.
+/// The log messages are output to the console again if the callback is NULL.
+///
+/// \param Callback the callback to receive the log messages, or NULL to
+/// restore the console output.
+/// \param Data the additional object, such as the pointer to a data structure,
+/// to pass into the callback.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_LogSetCallback(WasmEdge_LogCallback_t Callback, void *Data);
+
 // <<<<<<<< WasmEdge logging functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge value functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -798,6 +875,72 @@ WASMEDGE_CAPI_EXPORT extern void
 WasmEdge_StatisticsSetCostLimit(WasmEdge_StatisticsContext *Cxt,
                                 const uint64_t Limit);
 
+/// Get the cost limit in execution.
+///
+/// \param Cxt the WasmEdge_StatisticsContext to get data.
+///
+/// \returns the cost limit, UINT64_MAX if no limit is set.
+WASMEDGE_CAPI_EXPORT extern uint64_t
+WasmEdge_StatisticsGetCostLimit(const WasmEdge_StatisticsContext *Cxt);
+
+typedef bool (*WasmEdge_CostLimitHandler_t)(void *Data,
+                                            WasmEdge_StatisticsContext *Cxt);
+/// Set the handler called when the instruction costs exceed the limit.
+///
+/// Instead of aborting the WASM execution, the handler is called with the
+/// statistics context, in which the handler can raise the cost limit by
+/// `WasmEdge_StatisticsSetCostLimit`. The execution resumes if the handler
+/// returns true and the raised limit covers the cost. Otherwise, the execution
+/// is aborted and the ErrCode::CostLimitExceeded will be returned.
+///
+/// The handler is not called for the AOT compiled functions.
+///
+/// \param Cxt the WasmEdge_StatisticsContext to set the handler.
+/// \param Handler the handler. NULL to remove the handler.
+/// \param Data the additional object passed to the handler.
+WASMEDGE_CAPI_EXPORT extern void WasmEdge_StatisticsSetCostLimitHandler(
+    WasmEdge_StatisticsContext *Cxt, WasmEdge_CostLimitHandler_t Handler,
+    void *Data);
+
+/// Set the profiling mode on or off.
+///
+/// In the profiling mode, the executed instructions, costs and execution time
+/// are attributed to the functions in each call path. The instructions of the
+/// AOT compiled functions are not profiled, while their execution time is.
+///
+/// \param Cxt the WasmEdge_StatisticsContext to set the profiling mode.
+/// \param IsEnable true to turn on the profiling mode.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_StatisticsSetProfiling(WasmEdge_StatisticsContext *Cxt,
+                                const bool IsEnable);
+
+/// Get the length of the profile.
+///
+/// \param Cxt the WasmEdge_StatisticsContext to get data.
+///
+/// \returns the number of the profiled call paths.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_StatisticsGetProfileLength(const WasmEdge_StatisticsContext *Cxt);
+
+/// Get the profile.
+///
+/// Each entry is a function in a call path, and is always after the entry of
+/// its caller. The module names in the entries are references to the strings
+/// in the statistics context, and are invalidated after clearing the context.
+/// The instructions and the time of a function are charged when it calls or
+/// returns, so the entries of the running functions lag behind during the
+/// execution.
+///
+/// \param Cxt the WasmEdge_StatisticsContext to get data.
+/// \param [out] Buf the WasmEdge_ProfileEntry buffer to fill the entries.
+/// \param BufLen the buffer length.
+///
+/// \returns the number of the profiled call paths.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_StatisticsGetProfile(const WasmEdge_StatisticsContext *Cxt,
+                              WasmEdge_ProfileEntry *Buf,
+                              const uint32_t BufLen);
+
 /// Clear all data in the WasmEdge_StatisticsContext.
 ///
 /// \param Cxt the WasmEdge_StatisticsContext to clear.
@@ -865,6 +1008,46 @@ WasmEdge_ASTModuleListExports(const WasmEdge_ASTModuleContext *Cxt,
                               const WasmEdge_ExportTypeContext **Exports,
                               const uint32_t Len);
 
+/// Get the length of custom sections list of the AST module.
+///
+/// \param Cxt the WasmEdge_ASTModuleContext.
+///
+/// \returns length of the custom sections list.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ASTModuleListCustomSectionsLength(
+    const WasmEdge_ASTModuleContext *Cxt);
+
+/// Get the name of a custom section in the AST module.
+///
+/// The custom sections are indexed in the order they appear in the WASM
+/// binary. The returned string is binded into the AST module context and
+/// should __NOT__ be destroyed.
+///
+/// \param Cxt the WasmEdge_ASTModuleContext.
+/// \param Idx the index of the custom section.
+///
+/// \returns string object. Length will be 0 and Buf will be NULL if failed.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_String
+WasmEdge_ASTModuleGetCustomSectionName(const WasmEdge_ASTModuleContext *Cxt,
+                                       const uint32_t Idx);
+
+/// Get the content of a custom section in the AST module.
+///
+/// The custom sections are indexed in the order they appear in the WASM
+/// binary. The content excludes the section name, and the buffer is binded
+/// into the AST module context.
+///
+/// \param Cxt the WasmEdge_ASTModuleContext.
+/// \param Idx the index of the custom section.
+/// \param [out] Content the pointer to the content buffer. Will be set to
+/// NULL if failed.
+///
+/// \returns length of the content in bytes. 0 if failed.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ASTModuleGetCustomSectionContent(const WasmEdge_ASTModuleContext *Cxt,
+                                          const uint32_t Idx,
+                                          const uint8_t **Content);
+
 /// Deletion of the WasmEdge_ASTModuleContext.
 ///
 /// After calling this function, the context will be destroyed and should
@@ -1004,7 +1187,8 @@ WasmEdge_TableTypeDelete(WasmEdge_TableTypeContext *Cxt);
 /// The caller owns the object and should call `WasmEdge_MemoryTypeDelete` to
 /// destroy it.
 ///
-/// \param Limit the limit struct of the memory type.
+/// \param Limit the limit struct of the memory type. A shared memory type
+/// must have the maximum value.
 ///
 /// \returns pointer to context, NULL if failed.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_MemoryTypeContext *
@@ -1353,6 +1537,17 @@ WasmEdge_LoaderParseFromBuffer(WasmEdge_LoaderContext *Cxt,
                                WasmEdge_ASTModuleContext **Module,
                                const uint8_t *Buf, const uint32_t BufLen);
 
+/// Get the diagnostic of the latest failed parsing.
+///
+/// The diagnostic context links to the loader context, and the caller should
+/// __NOT__ destroy it. The content will be overwritten by the next parsing.
+///
+/// \param Cxt the WasmEdge_LoaderContext.
+///
+/// \returns pointer to the diagnostic context, NULL if failed.
+WASMEDGE_CAPI_EXPORT extern const WasmEdge_DiagnosticContext *
+WasmEdge_LoaderGetDiagnostic(const WasmEdge_LoaderContext *Cxt);
+
 /// Deletion of the WasmEdge_LoaderContext.
 ///
 /// After calling this function, the context will be destroyed and should
@@ -1389,6 +1584,18 @@ WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
 WasmEdge_ValidatorValidate(WasmEdge_ValidatorContext *Cxt,
                            const WasmEdge_ASTModuleContext *ASTCxt);
 
+/// Get the diagnostic of the latest failed validation.
+///
+/// The diagnostic context links to the validator context, and the caller
+/// should __NOT__ destroy it. The content will be overwritten by the next
+/// validation.
+///
+/// \param Cxt the WasmEdge_ValidatorContext.
+///
+/// \returns pointer to the diagnostic context, NULL if failed.
+WASMEDGE_CAPI_EXPORT extern const WasmEdge_DiagnosticContext *
+WasmEdge_ValidatorGetDiagnostic(const WasmEdge_ValidatorContext *Cxt);
+
 /// Deletion of the WasmEdge_ValidatorContext.
 ///
 /// After calling this function, the context will be destroyed and should
@@ -1400,6 +1607,87 @@ WasmEdge_ValidatorDelete(WasmEdge_ValidatorContext *Cxt);
 
 // <<<<<<<< WasmEdge validator functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
+// >>>>>>>> WasmEdge diagnostic functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+/// Get the name of the section in which the failure occurred.
+///
+/// The returned string object is linked to a static string, and the caller
+/// should __NOT__ call the `WasmEdge_StringDelete`.
+///
+/// \param Cxt the WasmEdge_DiagnosticContext.
+///
+/// \returns string object, such as "code section". "module" if the failure is
+/// not in a specific section. Length will be 0 and Buf will be NULL if failed.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_String
+WasmEdge_DiagnosticGetSection(const WasmEdge_DiagnosticContext *Cxt);
+
+/// Get the byte offset in the WASM binary at which the failure occurred.
+///
+/// \param Cxt the WasmEdge_DiagnosticContext.
+/// \param [out] Offset the byte offset.
+///
+/// \returns true if the offset is recorded, false if not.
+WASMEDGE_CAPI_EXPORT extern bool
+WasmEdge_DiagnosticGetOffset(const WasmEdge_DiagnosticContext *Cxt,
+                             uint64_t *Offset);
+
+/// Get the index of the function in which the failure occurred.
+///
+/// The function index includes the imported functions.
+///
+/// \param Cxt the WasmEdge_DiagnosticContext.
+/// \param [out] FuncIdx the function index.
+///
+/// \returns true if the function index is recorded, false if not.
+WASMEDGE_CAPI_EXPORT extern bool
+WasmEdge_DiagnosticGetFunctionIndex(const WasmEdge_DiagnosticContext *Cxt,
+                                    uint32_t *FuncIdx);
+
+/// Get the name of the instruction at which the failure occurred.
+///
+/// The returned string object is linked to a static string, and the caller
+/// should __NOT__ call the `WasmEdge_StringDelete`.
+///
+/// \param Cxt the WasmEdge_DiagnosticContext.
+///
+/// \returns string object, such as "i32.add". Length will be 0 and Buf will be
+/// NULL if no instruction is recorded.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_String
+WasmEdge_DiagnosticGetInstruction(const WasmEdge_DiagnosticContext *Cxt);
+
+/// Get the expected value types of the type mismatch.
+///
+/// If the `Buf` buffer length `BufLen` is smaller than the length of the
+/// expected types, the overflowed types will be discarded.
+///
+/// \param Cxt the WasmEdge_DiagnosticContext.
+/// \param [out] Buf the WasmEdge_ValType buffer to fill the types.
+/// \param BufLen the buffer length.
+///
+/// \returns length of the expected types.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_DiagnosticGetExpectedTypes(const WasmEdge_DiagnosticContext *Cxt,
+                                    enum WasmEdge_ValType *Buf,
+                                    const uint32_t BufLen);
+
+/// Get the actual value types of the type mismatch.
+///
+/// If the `Buf` buffer length `BufLen` is smaller than the length of the
+/// actual types, the overflowed types will be discarded. The length is 0 if
+/// the value stack underflowed.
+///
+/// \param Cxt the WasmEdge_DiagnosticContext.
+/// \param [out] Buf the WasmEdge_ValType buffer to fill the types.
+/// \param BufLen the buffer length.
+///
+/// \returns length of the actual types.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_DiagnosticGetActualTypes(const WasmEdge_DiagnosticContext *Cxt,
+                                  enum WasmEdge_ValType *Buf,
+                                  const uint32_t BufLen);
+
+// <<<<<<<< WasmEdge diagnostic functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+
 // >>>>>>>> WasmEdge executor functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
 /// Creation of the WasmEdge_ExecutorContext.
@@ -1520,6 +1808,31 @@ WasmEdge_ExecutorInvoke(WasmEdge_ExecutorContext *Cxt,
 WASMEDGE_CAPI_EXPORT extern void
 WasmEdge_ExecutorDelete(WasmEdge_ExecutorContext *Cxt);
 
+/// Get the length of the backtrace of the latest failed invocation.
+///
+/// The backtrace is recorded per thread when invoking a function or running the
+/// start function by the executor or VM context fails, and is cleared when the
+/// next invocation or instantiation in the same thread starts.
+///
+/// \returns length of the backtrace. 0 if the latest invocation in this thread
+/// succeeded.
+WASMEDGE_CAPI_EXPORT extern uint32_t WasmEdge_TrapGetBacktraceLength(void);
+
+/// Get the backtrace of the latest failed invocation.
+///
+/// The innermost frame is the first. The module names in the frames are linked
+/// to the backtrace, and the caller should __NOT__ call the
+/// `WasmEdge_StringDelete`. They are valid until the next invocation or
+/// instantiation in the same thread starts. If the `Buf` buffer length `BufLen` is smaller than the
+/// length of the backtrace, the overflowed frames will be discarded.
+///
+/// \param [out] Buf the WasmEdge_TrapFrame buffer to fill the frames.
+/// \param BufLen the buffer length.
+///
+/// \returns length of the backtrace.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_TrapGetBacktrace(WasmEdge_TrapFrame *Buf, const uint32_t BufLen);
+
 // <<<<<<<< WasmEdge executor functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge store functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -1547,6 +1860,27 @@ WASMEDGE_CAPI_EXPORT extern const WasmEdge_ModuleInstanceContext *
 WasmEdge_StoreFindModule(WasmEdge_StoreContext *Cxt,
                          const WasmEdge_String Name);
 
+/// Unregister the module instance by the module name.
+///
+/// After calling this function, the module instance is unlinked from the
+/// store, and another module instance can be registered with the same name.
+/// The module instance is __NOT__ destroyed, and the caller still owns it if it
+/// is created by the caller. The module instance cannot be unregistered while
+/// any alive module instance instantiated with this store imports from it.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_StoreContext.
+/// \param Name the module name WasmEdge_String.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message. The error code is `WasmEdge_ErrCode_UnknownModule` if the module
+/// name is not registered, and `WasmEdge_ErrCode_ModuleInUse` if the module
+/// instance is imported by other alive module instances.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
+WasmEdge_StoreUnregisterModule(WasmEdge_StoreContext *Cxt,
+                               const WasmEdge_String Name);
+
 /// Get the length of registered module list in store.
 ///
 /// This function is thread-safe.
@@ -1890,6 +2224,41 @@ WASMEDGE_CAPI_EXPORT extern uint32_t
 WasmEdge_ModuleInstanceListGlobal(const WasmEdge_ModuleInstanceContext *Cxt,
                                   WasmEdge_String *Names, const uint32_t Len);
 
+/// Get the length of exported instance list of a module instance.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+///
+/// \returns length of the exported instance list.
+WASMEDGE_CAPI_EXPORT extern uint32_t WasmEdge_ModuleInstanceListExportLength(
+    const WasmEdge_ModuleInstanceContext *Cxt);
+
+/// List the exported instance names and external types of a module instance
+/// in the export order.
+///
+/// The returned names filled into the `Names` array are linked to the exported
+/// names of the instances of the module instance context, and the caller
+/// should __NOT__ call the `WasmEdge_StringDelete`.
+/// If the `Names` and `Types` buffer length is smaller than the result of the
+/// exported instance list size, the overflowed return values will be
+/// discarded.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+/// \param [out] Names the output WasmEdge_String buffer of the exported names.
+/// \param [out] Types the output buffer of the external types of the exported
+/// instances.
+/// \param Len the buffer length.
+///
+/// \returns actual exported instance list size.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ModuleInstanceListExport(const WasmEdge_ModuleInstanceContext *Cxt,
+                                  WasmEdge_String *Names,
+                                  enum WasmEdge_ExternalType *Types,
+                                  const uint32_t Len);
+
 /// Add a function instance context into a WasmEdge_ModuleInstanceContext.
 ///
 /// Export and move the ownership of the function instance into the module
@@ -2201,6 +2570,39 @@ WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
 WasmEdge_TableInstanceGrow(WasmEdge_TableInstanceContext *Cxt,
                            const uint32_t Size);
 
+/// Fill a range of a table instance with a reference value.
+///
+/// \param Cxt the WasmEdge_TableInstanceContext.
+/// \param Data the reference value to fill into the table instance.
+/// \param Offset the offset (index) of the first reference value to fill.
+/// \param Length the count of reference values to fill.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
+WasmEdge_TableInstanceFill(WasmEdge_TableInstanceContext *Cxt,
+                           WasmEdge_Value Data, const uint32_t Offset,
+                           const uint32_t Length);
+
+/// Copy a range of reference values from a table instance to another one.
+///
+/// The source and the destination table instances can be the same one, and the
+/// ranges can overlap.
+///
+/// \param DstCxt the WasmEdge_TableInstanceContext to copy into.
+/// \param SrcCxt the WasmEdge_TableInstanceContext to copy from.
+/// \param DstOffset the offset (index) in the destination table instance.
+/// \param SrcOffset the offset (index) in the source table instance.
+/// \param Length the count of reference values to copy.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
+WasmEdge_TableInstanceCopy(WasmEdge_TableInstanceContext *DstCxt,
+                           const WasmEdge_TableInstanceContext *SrcCxt,
+                           const uint32_t DstOffset, const uint32_t SrcOffset,
+                           const uint32_t Length);
+
 /// Deletion of the WasmEdge_TableInstanceContext.
 ///
 /// After calling this function, the context will be destroyed and should
@@ -2312,6 +2714,71 @@ WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
 WasmEdge_MemoryInstanceGrowPage(WasmEdge_MemoryInstanceContext *Cxt,
                                 const uint32_t Page);
 
+/// Wake up the threads waiting on an address of a shared memory instance
+/// (Threads proposal).
+///
+/// Only the threads blocked in `WasmEdge_MemoryInstanceAtomicWait32` or
+/// `WasmEdge_MemoryInstanceAtomicWait64` on the same address are woken up, in
+/// the waiting order. No thread can wait on a non-shared memory instance, so
+/// the woken count is always 0 for it.
+///
+/// \param Cxt the WasmEdge_MemoryInstanceContext.
+/// \param Offset the address in the memory instance. It must be aligned to 4
+/// bytes.
+/// \param Count the maximum number of the threads to wake up.
+/// \param [out] Woken the number of the woken threads.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
+WasmEdge_MemoryInstanceAtomicNotify(WasmEdge_MemoryInstanceContext *Cxt,
+                                    const uint32_t Offset, const uint32_t Count,
+                                    uint32_t *Woken);
+
+/// Block the current thread on an address of a shared memory instance until
+/// it is woken up by `WasmEdge_MemoryInstanceAtomicNotify` or the timeout
+/// expires (Threads proposal).
+///
+/// If the `uint32_t` value at the address is not equal to `Expected`, this
+/// function returns immediately.
+///
+/// \param Cxt the WasmEdge_MemoryInstanceContext.
+/// \param Offset the address in the memory instance. It must be aligned to 4
+/// bytes.
+/// \param Expected the expected value at the address.
+/// \param Timeout the timeout in nanoseconds. Negative for no timeout.
+/// \param [out] Result 0 if woken up, 1 if the value is not equal to
+/// `Expected`, 2 if timed out. These are the results of the
+/// `memory.atomic.wait32` instruction.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message. Waiting on a non-shared memory instance fails.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_MemoryInstanceAtomicWait32(
+    WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Offset,
+    const uint32_t Expected, const int64_t Timeout, uint32_t *Result);
+
+/// Block the current thread on an address of a shared memory instance until
+/// it is woken up by `WasmEdge_MemoryInstanceAtomicNotify` or the timeout
+/// expires (Threads proposal).
+///
+/// If the `uint64_t` value at the address is not equal to `Expected`, this
+/// function returns immediately.
+///
+/// \param Cxt the WasmEdge_MemoryInstanceContext.
+/// \param Offset the address in the memory instance. It must be aligned to 8
+/// bytes.
+/// \param Expected the expected value at the address.
+/// \param Timeout the timeout in nanoseconds. Negative for no timeout.
+/// \param [out] Result 0 if woken up, 1 if the value is not equal to
+/// `Expected`, 2 if timed out. These are the results of the
+/// `memory.atomic.wait64` instruction.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message. Waiting on a non-shared memory instance fails.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_MemoryInstanceAtomicWait64(
+    WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Offset,
+    const uint64_t Expected, const int64_t Timeout, uint32_t *Result);
+
 /// Deletion of the WasmEdge_MemoryInstanceContext.
 ///
 /// After calling this function, the context will be destroyed and should
@@ -2547,6 +3014,28 @@ WasmEdge_VMRegisterModuleFromASTModule(WasmEdge_VMContext *Cxt,
 WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_VMRegisterModuleFromImport(
     WasmEdge_VMContext *Cxt, const WasmEdge_ModuleInstanceContext *ImportCxt);
 
+/// Unregister the module instance from the store in VM by the module name.
+///
+/// After calling this function, the module instance is unlinked from the store
+/// in VM, and another module instance can be registered with the same name. If
+/// the module instance is registered from a WASM file, buffer, or AST module,
+/// it is owned by the VM and is destroyed. Otherwise, the caller still owns the
+/// module instance. The module instance cannot be unregistered while any alive
+/// module instance instantiated with the store in VM imports from it.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_VMContext which contains the store.
+/// \param ModuleName the module name WasmEdge_String.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message. The error code is `WasmEdge_ErrCode_UnknownModule` if the module
+/// name is not registered, and `WasmEdge_ErrCode_ModuleInUse` if the module
+/// instance is imported by other alive module instances.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
+WasmEdge_VMUnregisterModule(WasmEdge_VMContext *Cxt,
+                            const WasmEdge_String ModuleName);
+
 /// Instantiate the WASM module from a WASM file and invoke a function by name.
 ///
 /// This is the function to invoke a WASM function rapidly.
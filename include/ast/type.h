@@ -27,20 +27,44 @@ namespace AST {
 class Limit {
 public:
   /// Limit type enumeration class.
-  enum class LimitType : uint8_t { HasMin = 0x00, HasMinMax = 0x01 };
+  enum class LimitType : uint8_t {
+    HasMin = 0x00,
+    HasMinMax = 0x01,
+    SharedNoMax = 0x02,
+    Shared = 0x03
+  };
 
   /// Constructors.
   Limit() noexcept : Type(LimitType::HasMin), Min(0U), Max(0U) {}
   Limit(uint32_t MinVal) noexcept
       : Type(LimitType::HasMin), Min(MinVal), Max(MinVal) {}
-  Limit(uint32_t MinVal, uint32_t MaxVal) noexcept
-      : Type(LimitType::HasMinMax), Min(MinVal), Max(MaxVal) {}
+  Limit(uint32_t MinVal, uint32_t MaxVal, bool Shared = false) noexcept
+      : Type(Shared ? LimitType::Shared : LimitType::HasMinMax), Min(MinVal),
+        Max(MaxVal) {}
   Limit(const Limit &L) noexcept : Type(L.Type), Min(L.Min), Max(L.Max) {}
 
   /// Getter and setter of limit mode.
-  bool hasMax() const noexcept { return Type == LimitType::HasMinMax; }
+  bool hasMax() const noexcept {
+    return Type == LimitType::HasMinMax || Type == LimitType::Shared;
+  }
   void setHasMax(bool HasMax) noexcept {
-    Type = HasMax ? LimitType::HasMinMax : LimitType::HasMin;
+    if (isShared()) {
+      Type = HasMax ? LimitType::Shared : LimitType::SharedNoMax;
+    } else {
+      Type = HasMax ? LimitType::HasMinMax : LimitType::HasMin;
+    }
+  }
+
+  /// Getter and setter of shared memory mode (Threads proposal).
+  bool isShared() const noexcept {
+    return Type == LimitType::SharedNoMax || Type == LimitType::Shared;
+  }
+  void setShared(bool Shared) noexcept {
+    if (hasMax()) {
+      Type = Shared ? LimitType::Shared : LimitType::HasMinMax;
+    } else {
+      Type = Shared ? LimitType::SharedNoMax : LimitType::HasMin;
+    }
   }
 
   /// Getter and setter of min value.
@@ -118,7 +142,8 @@ public:
   /// Constructors.
   MemoryType() noexcept = default;
   MemoryType(uint32_t MinVal) noexcept : Lim(MinVal) {}
-  MemoryType(uint32_t MinVal, uint32_t MaxVal) noexcept : Lim(MinVal, MaxVal) {}
+  MemoryType(uint32_t MinVal, uint32_t MaxVal, bool Shared = false) noexcept
+      : Lim(MinVal, MaxVal, Shared) {}
   MemoryType(const Limit &L) noexcept : Lim(L) {}
 
   /// Getter of limit.
@@ -24,6 +24,7 @@
 #include <cstdint>
 #include <iosfwd>
 #include <limits>
+#include <optional>
 #include <string>
 #include <string_view>
 #include <vector>
@@ -295,5 +296,21 @@ struct InfoProposal {
   Proposal P;
 };
 
+/// Structured diagnostic of the latest loading or validation failure.
+struct InfoDiagnostic {
+  void reset() noexcept { *this = InfoDiagnostic(); }
+
+  /// The section in which the failure occurred.
+  ASTNodeAttr Section = ASTNodeAttr::Module;
+  /// The byte offset in the module binary.
+  std::optional<uint64_t> Offset;
+  /// The function index (including the imported functions).
+  std::optional<uint32_t> FuncIndex;
+  /// The failed instruction.
+  std::optional<OpCode> Code;
+  /// The expected and actual operand types of a type mismatch.
+  std::vector<ValType> ExpTypes, GotTypes;
+};
+
 } // namespace ErrInfo
 } // namespace WasmEdge
@@ -17,6 +17,9 @@
 #include "spdlog/fmt/ostr.h"
 #include "spdlog/spdlog.h"
 
+#include <functional>
+#include <string_view>
+
 namespace WasmEdge {
 namespace Log {
 
@@ -28,5 +31,17 @@ void setWarnLoggingLevel();
 
 void setErrorLoggingLevel();
 
+void setLoggingLevel(spdlog::level::level_enum Level);
+
+/// Callback of the log messages, which receives the logging level, the name of
+/// the logger, and the formatted message.
+using Callback = std::function<void(spdlog::level::level_enum Level,
+                                    std::string_view LoggerName,
+                                    std::string_view Message)>;
+
+/// Forward the log messages to the callback instead of the console. The
+/// console output is restored if the callback is empty.
+void setLoggingCallback(Callback Func);
+
 } // namespace Log
 } // namespace WasmEdge
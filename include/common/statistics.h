@@ -21,6 +21,13 @@
 #include "common/timer.h"
 
 #include <atomic>
+#include <deque>
+#include <functional>
+#include <map>
+#include <mutex>
+#include <optional>
+#include <string>
+#include <utility>
 #include <vector>
 
 namespace WasmEdge {
@@ -28,6 +35,10 @@ namespace Statistics {
 
 class Statistics {
 public:
+  /// Handler of the exceeded cost limit. Returns true to resume the execution
+  /// if the cost limit is raised by the handler.
+  using CostLimitHandler = std::function<bool(Statistics &)>;
+
   Statistics(const uint64_t Lim = UINT64_MAX)
       : CostTab(UINT16_MAX + 1, 1ULL), InstrCnt(0), CostLimit(Lim), CostSum(0) {
   }
@@ -78,23 +89,30 @@ public:
   std::atomic_uint64_t &getTotalCostRef() { return CostSum; }
 
   /// Getter and setter of cost limit.
-  void setCostLimit(uint64_t Lim) { CostLimit = Lim; }
-  uint64_t getCostLimit() const { return CostLimit; }
+  void setCostLimit(uint64_t Lim) {
+    CostLimit.store(Lim, std::memory_order_relaxed);
+  }
+  uint64_t getCostLimit() const {
+    return CostLimit.load(std::memory_order_relaxed);
+  }
+
+  /// Setter of the handler called when the cost exceeds the limit.
+  void setCostLimitHandler(CostLimitHandler Handler) {
+    LimitHandler = std::move(Handler);
+  }
 
   /// Add cost and return false if exceeded limit.
   bool addCost(uint64_t Cost) {
-    const auto Limit = CostLimit;
-    uint64_t OldCostSum = CostSum.load(std::memory_order_relaxed);
-    uint64_t NewCostSum;
-    do {
-      NewCostSum = OldCostSum + Cost;
-      if (unlikely(NewCostSum > Limit)) {
-        spdlog::error("Cost exceeded limit. Force terminate the execution.");
-        return false;
-      }
-    } while (!CostSum.compare_exchange_weak(OldCostSum, NewCostSum,
-                                            std::memory_order_relaxed));
-    return true;
+    if (likely(tryAddCost(Cost))) {
+      return true;
+    }
+    // The handler is called out of the retry loop, so a failed exchange never
+    // raises the limit twice.
+    if (handleCostLimit() && tryAddCost(Cost)) {
+      return true;
+    }
+    spdlog::error("Cost exceeded limit. Force terminate the execution.");
+    return false;
   }
 
   /// Return cost back.
@@ -111,11 +129,88 @@ public:
     return true;
   }
 
+  /// Profile of a function in a call path.
+  struct ProfileNode {
+    /// Index of the node of the caller. None for the outermost functions.
+    std::optional<uint32_t> Parent;
+    /// Name of the module instance. Empty for the anonymous module instance.
+    std::string ModuleName;
+    /// Index of the function in the module instance.
+    uint32_t FuncIdx = 0;
+    /// The function is a host function.
+    bool IsHost = false;
+    /// Executed instructions in the function itself.
+    uint64_t InstrCount = 0;
+    /// Costs of the instructions in the function itself.
+    uint64_t Cost = 0;
+    /// Execution time of the function itself, excluding its callees.
+    Timer::Timer::Clock::duration Time = Timer::Timer::Clock::duration::zero();
+  };
+
+  /// Getter and setter of the profiling mode.
+  void setProfiling(bool Flag) noexcept {
+    Profiling.store(Flag, std::memory_order_relaxed);
+  }
+  bool isProfiling() const noexcept {
+    return Profiling.load(std::memory_order_relaxed);
+  }
+
+  /// Getter of the number of the profile nodes.
+  size_t getProfileLength() const noexcept {
+    std::lock_guard Lock(ProfileMutex);
+    return ProfileNodes.size();
+  }
+
+  /// Call `Func` with each profile node under the lock. A node is always after
+  /// its parent. The nodes are not moved until the measurement data are
+  /// cleared.
+  template <typename FuncT> void visitProfile(FuncT &&Func) const {
+    std::lock_guard Lock(ProfileMutex);
+    for (const auto &Node : ProfileNodes) {
+      Func(Node);
+    }
+  }
+
+  /// Getter of the node of the function `Key` called from the `Parent` node.
+  /// The node is created by `Create` if not found.
+  template <typename CreateT>
+  uint32_t getProfileNode(std::optional<uint32_t> Parent, const void *Key,
+                          CreateT &&Create) {
+    std::lock_guard Lock(ProfileMutex);
+    auto [It, Inserted] = ProfileIndex.try_emplace(
+        std::make_pair(Parent.value_or(UINT32_MAX), Key),
+        static_cast<uint32_t>(ProfileNodes.size()));
+    if (Inserted) {
+      ProfileNodes.push_back(Create());
+      ProfileNodes.back().Parent = Parent;
+    }
+    return It->second;
+  }
+
+  /// Charge the instructions, their costs, and the execution time to a node.
+  void chargeProfile(uint32_t Node, uint64_t InstrCount, uint64_t Cost,
+                     Timer::Timer::Clock::duration Time) noexcept {
+    std::lock_guard Lock(ProfileMutex);
+    if (Node < ProfileNodes.size()) {
+      ProfileNodes[Node].InstrCount += InstrCount;
+      ProfileNodes[Node].Cost += Cost;
+      ProfileNodes[Node].Time += Time;
+    }
+  }
+
+  /// Getter of the cost of an instruction.
+  uint64_t getInstrCost(OpCode Code) const noexcept {
+    return CostTab[uint16_t(Code)];
+  }
+
   /// Clear measurement data for instructions.
   void clear() noexcept {
     TimeRecorder.reset();
     InstrCnt.store(0, std::memory_order_relaxed);
     CostSum.store(0, std::memory_order_relaxed);
+    std::lock_guard Lock(ProfileMutex);
+    ProfileNodes.clear();
+    ProfileIndex.clear();
   }
 
   /// Start recording wasm time.
@@ -183,11 +278,35 @@ public:
   }
 
 private:
+  /// Add cost and return false if the cost limit is exceeded.
+  bool tryAddCost(uint64_t Cost) {
+    uint64_t OldCostSum = CostSum.load(std::memory_order_relaxed);
+    uint64_t NewCostSum;
+    do {
+      NewCostSum = OldCostSum + Cost;
+      if (unlikely(NewCostSum > getCostLimit())) {
+        return false;
+      }
+    } while (!CostSum.compare_exchange_weak(OldCostSum, NewCostSum,
+                                            std::memory_order_relaxed));
+    return true;
+  }
+
+  /// Let the handler raise the cost limit.
+  bool handleCostLimit() { return LimitHandler && LimitHandler(*this); }
+
   std::vector<uint64_t> CostTab;
   std::atomic_uint64_t InstrCnt;
-  uint64_t CostLimit;
+  std::atomic_uint64_t CostLimit;
   std::atomic_uint64_t CostSum;
+  CostLimitHandler LimitHandler;
   Timer::Timer TimeRecorder;
+  std::atomic_bool Profiling{false};
+  /// The profile nodes are shared by the invocations in all threads, and the
+  /// profiled call path of each invocation is kept by the executor.
+  mutable std::mutex ProfileMutex;
+  std::deque<ProfileNode> ProfileNodes;
+  std::map<std::pair<uint32_t, const void *>, uint32_t> ProfileIndex;
 };
 
 } // namespace Statistics
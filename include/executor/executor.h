@@ -26,8 +26,10 @@
 #include <atomic>
 #include <csignal>
 #include <cstdint>
+#include <deque>
 #include <memory>
 #include <optional>
+#include <string>
 #include <string_view>
 #include <type_traits>
 #include <utility>
@@ -143,6 +145,21 @@ public:
   /// Stop execution
   void stop() noexcept { StopToken.store(1, std::memory_order_relaxed); }
 
+  /// Frame of a trap backtrace.
+  struct TrapFrame {
+    /// Name of the module instance. Empty for the anonymous module instance.
+    std::string ModuleName;
+    /// Index of the function in the module instance.
+    uint32_t FuncIdx;
+    /// Byte offset of the instruction in the module. Only recorded for the
+    /// interpreted functions.
+    std::optional<uint64_t> Offset;
+  };
+
+  /// Getter of the backtrace of the latest failed invocation in this thread.
+  /// The innermost frame is the first.
+  static Span<const TrapFrame> getBacktrace() noexcept { return Backtrace; }
+
 private:
   /// Run Wasm bytecode expression for initialization.
   Expect<void> runExpression(Runtime::StackManager &StackMgr,
@@ -158,6 +175,47 @@ private:
                        const AST::InstrView::iterator Start,
                        const AST::InstrView::iterator End);
 
+  /// Record the backtrace from the frames in stack.
+  void recordBacktrace(const Runtime::StackManager &StackMgr,
+                       std::optional<uint64_t> Offset);
+
+  /// Get the module name and the function index of a function instance.
+  static void locateFunction(const Runtime::Instance::FunctionInstance &Func,
+                             std::string &ModuleName, uint32_t &FuncIdx);
+
+  /// \name Functions for profiling.
+  /// @{
+  /// Profiled call path of an invocation.
+  struct ProfileState {
+    /// Pairs of the function instances and the indices of their profile
+    /// nodes. The outermost function is the first.
+    std::vector<std::pair<const void *, uint32_t>> Path;
+    /// Number of the stack frames, including the dummy frames, when the path
+    /// was synchronized.
+    size_t FrameCount = 0;
+    /// Time of the latest charging.
+    Timer::Timer::Clock::time_point Time;
+    /// Instructions and their costs executed since the latest charging.
+    uint64_t InstrCount = 0;
+    uint64_t Cost = 0;
+  };
+
+  /// Start profiling an invocation. The profiled call path of the outer
+  /// invocation in this thread, if any, is kept until the invocation stops.
+  void startProfile();
+
+  /// Stop profiling the innermost invocation in this thread.
+  void stopProfile();
+
+  /// Synchronize the profiled call path with the frames in stack. Called at
+  /// the function calls and returns.
+  void syncProfile(const Runtime::StackManager &StackMgr);
+
+  /// Charge the instructions and the time since the latest charging to the
+  /// innermost function in the profiled call path.
+  void chargeProfile(ProfileState &State);
+  /// @}
+
   /// \name Functions for instantiation.
   /// @{
   /// Instantiation of Module Instance.
@@ -596,6 +654,12 @@ private:
   static thread_local Runtime::StackManager *CurrentStack;
   /// Execution context for compiled functions
   static thread_local ExecutionContextStruct ExecutionContext;
+  /// Backtrace of the latest failed invocation
+  static thread_local std::vector<TrapFrame> Backtrace;
+  /// Profiled call paths of the nested invocations in this thread. The
+  /// innermost invocation is the last. The elements are not moved when the
+  /// nested invocations push and pop.
+  static thread_local std::deque<ProfileState> ProfileStack;
   /// @}
 
 private:
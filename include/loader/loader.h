@@ -25,6 +25,7 @@
 #include <memory>
 #include <mutex>
 #include <optional>
+#include <type_traits>
 #include <vector>
 
 namespace WasmEdge {
@@ -94,10 +95,16 @@ public:
   /// Parse module from byte code.
   Expect<std::unique_ptr<AST::Module>> parseModule(Span<const uint8_t> Code);
 
+  /// Get the diagnostic of the latest failed parsing.
+  const ErrInfo::InfoDiagnostic &getDiagnostic() const noexcept {
+    return Diag;
+  }
+
 private:
   /// \name Helper functions to print error log when loading AST nodes
   /// @{
   inline auto logLoadError(ErrCode Code, uint64_t Off, ASTNodeAttr Node) {
+    Diag.Offset = Off;
     spdlog::error(Code);
     spdlog::error(ErrInfo::InfoLoading(Off));
     spdlog::error(ErrInfo::InfoAST(Node));
@@ -105,6 +112,7 @@ private:
   }
   inline auto logNeedProposal(ErrCode Code, Proposal Prop, uint64_t Off,
                               ASTNodeAttr Node) {
+    Diag.Offset = Off;
     spdlog::error(Code);
     spdlog::error(ErrInfo::InfoProposal(Prop));
     spdlog::error(ErrInfo::InfoLoading(Off));
@@ -136,15 +144,18 @@ private:
       auto StartOffset = FMgr.getOffset();
       auto ResContent = Func();
       if (!ResContent) {
+        Diag.Section = NodeAttrFromAST<T>();
         return Unexpect(ResContent);
       }
       // Check the read size match the section size.
       auto EndOffset = FMgr.getOffset();
       if (EndOffset - StartOffset != Sec.getContentSize()) {
+        Diag.Section = NodeAttrFromAST<T>();
         return logLoadError(ErrCode::SectionSizeMismatch, EndOffset,
                             NodeAttrFromAST<T>());
       }
     } else {
+      Diag.Section = NodeAttrFromAST<T>();
       return Unexpect(Res);
     }
     return {};
@@ -164,6 +175,10 @@ private:
     // Sequently create the AST node T and read data.
     for (uint32_t I = 0; I < VecCnt; ++I) {
       if (auto Res = Func(Sec.getContent()[I]); !Res) {
+        if constexpr (std::is_same_v<T, AST::CodeSection>) {
+          // Index in the code section. Shifted by the imported functions later.
+          Diag.FuncIndex = I;
+        }
         spdlog::error(ErrInfo::InfoAST(NodeAttrFromAST<T>()));
         return Unexpect(Res);
       }
@@ -215,6 +230,7 @@ private:
   bool HasDataSection;
   bool IsSharedLibraryWASM;
   bool IsUniversalWASM;
+  ErrInfo::InfoDiagnostic Diag;
   /// @}
 };
 
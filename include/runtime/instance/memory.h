@@ -20,11 +20,17 @@
 #include "system/allocator.h"
 
 #include <algorithm>
+#include <atomic>
+#include <chrono>
+#include <condition_variable>
 #include <cstdint>
 #include <cstring>
 #include <fstream>
+#include <list>
 #include <memory>
+#include <mutex>
 #include <set>
+#include <unordered_map>
 #include <utility>
 
 namespace WasmEdge {
@@ -317,15 +323,127 @@ public:
     return {};
   }
 
+  /// Wake up the waiters on the address of a shared memory (Threads
+  /// proposal).
+  ///
+  /// \param Offset the address in data array. Must be aligned to 4 bytes.
+  /// \param Count the maximum number of the waiters to wake up.
+  ///
+  /// \returns the number of the woken waiters, ErrCode when failed.
+  Expect<uint32_t> atomicNotify(uint32_t Offset, uint32_t Count) noexcept {
+    if (auto Res = checkAtomicAccess(Offset, sizeof(uint32_t)); !Res) {
+      return Unexpect(Res);
+    }
+    // No one can wait on a non-shared memory.
+    if (!MemType.getLimit().isShared()) {
+      return 0;
+    }
+
+    std::unique_lock Lock(WaiterMutex);
+    uint32_t Woken = 0;
+    if (auto It = Waiters.find(Offset); It != Waiters.end()) {
+      auto &Queue = It->second;
+      while (Woken < Count && !Queue.empty()) {
+        Queue.front()->Notified = true;
+        Queue.front()->Cond.notify_one();
+        Queue.pop_front();
+        ++Woken;
+      }
+      if (Queue.empty()) {
+        Waiters.erase(It);
+      }
+    }
+    return Woken;
+  }
+
+  /// Block the current thread on the address of a shared memory until it is
+  /// woken up by `atomicNotify` or the timeout expires (Threads proposal).
+  ///
+  /// Only uint32 and uint64 values can be waited on.
+  ///
+  /// \param Offset the address in data array. Must be aligned to sizeof(T).
+  /// \param Expected the expected value at the address.
+  /// \param Timeout the timeout in nanoseconds. Negative for no timeout.
+  ///
+  /// \returns 0 if woken up, 1 if the value at the address is not equal to
+  /// the expected value, 2 if timed out, ErrCode when failed.
+  template <typename T>
+  typename std::enable_if_t<
+      std::is_same_v<T, uint32_t> || std::is_same_v<T, uint64_t>,
+      Expect<uint32_t>>
+  atomicWait(uint32_t Offset, T Expected, int64_t Timeout) noexcept {
+    if (unlikely(!MemType.getLimit().isShared())) {
+      spdlog::error(ErrCode::ExpectSharedMemory);
+      return Unexpect(ErrCode::ExpectSharedMemory);
+    }
+    if (auto Res = checkAtomicAccess(Offset, sizeof(T)); !Res) {
+      return Unexpect(Res);
+    }
+    auto *Value = reinterpret_cast<std::atomic<T> *>(&DataPtr[Offset]);
+
+    // Check the value and enqueue under the lock, so that no notification is
+    // missed.
+    std::unique_lock Lock(WaiterMutex);
+    if (Value->load() != Expected) {
+      return 1;
+    }
+    Waiter W;
+    auto &Queue = Waiters[Offset];
+    auto It = Queue.insert(Queue.end(), &W);
+    if (Timeout < 0) {
+      W.Cond.wait(Lock, [&W]() { return W.Notified; });
+      return 0;
+    }
+    if (W.Cond.wait_for(Lock, std::chrono::nanoseconds(Timeout),
+                        [&W]() { return W.Notified; })) {
+      return 0;
+    }
+    // Not notified, so the waiter is still in the queue.
+    auto QueueIt = Waiters.find(Offset);
+    QueueIt->second.erase(It);
+    if (QueueIt->second.empty()) {
+      Waiters.erase(QueueIt);
+    }
+    return 2;
+  }
+
   uint8_t *getDataPtr() const noexcept { return DataPtr; }
 
 private:
+  /// Check the address of an atomic access.
+  Expect<void> checkAtomicAccess(uint32_t Offset,
+                                 uint32_t Length) const noexcept {
+    if (unlikely(!checkAccessBound(Offset, Length))) {
+      spdlog::error(ErrCode::MemoryOutOfBounds);
+      spdlog::error(ErrInfo::InfoBoundary(Offset, Length, getBoundIdx()));
+      return Unexpect(ErrCode::MemoryOutOfBounds);
+    }
+    if (unlikely(Offset % Length != 0)) {
+      spdlog::error(ErrCode::UnalignedAtomicAccess);
+      spdlog::error(ErrInfo::InfoBoundary(Offset, Length, getBoundIdx()));
+      return Unexpect(ErrCode::UnalignedAtomicAccess);
+    }
+    return {};
+  }
+
+  /// A thread blocked in `atomicWait`.
+  struct Waiter {
+    std::condition_variable Cond;
+    bool Notified = false;
+  };
+
   /// \name Data of memory instance.
   /// @{
   AST::MemoryType MemType;
   uint8_t *DataPtr = nullptr;
   const uint32_t PageLimit;
   /// @}
+
+  /// \name Waiters on the addresses of a shared memory, in the waiting order.
+  /// @{
+  std::mutex WaiterMutex;
+  std::unordered_map<uint32_t, std::list<Waiter *>> Waiters;
+  /// @}
 };
 
 } // namespace Instance
@@ -28,10 +28,12 @@
 #include <map>
 #include <memory>
 #include <mutex>
+#include <optional>
 #include <set>
 #include <shared_mutex>
 #include <string>
 #include <type_traits>
+#include <unordered_map>
 #include <vector>
 
 namespace WasmEdge {
@@ -84,32 +86,34 @@ public:
                    std::unique_ptr<HostFunctionBase> &&Func) {
     std::unique_lock Lock(Mutex);
     unsafeAddHostInstance(
-        Name, OwnedFuncInsts, FuncInsts, ExpFuncs,
+        Name, ExternalType::Function, OwnedFuncInsts, FuncInsts, ExpFuncs,
         std::make_unique<Runtime::Instance::FunctionInstance>(std::move(Func)));
+    unsafeAddFuncIndex();
   }
   void addHostFunc(std::string_view Name,
                    std::unique_ptr<Instance::FunctionInstance> &&Func) {
     std::unique_lock Lock(Mutex);
-    unsafeAddHostInstance(Name, OwnedFuncInsts, FuncInsts, ExpFuncs,
-                          std::move(Func));
+    unsafeAddHostInstance(Name, ExternalType::Function, OwnedFuncInsts,
+                          FuncInsts, ExpFuncs, std::move(Func));
+    unsafeAddFuncIndex();
   }
   void addHostTable(std::string_view Name,
                     std::unique_ptr<Instance::TableInstance> &&Tab) {
     std::unique_lock Lock(Mutex);
-    unsafeAddHostInstance(Name, OwnedTabInsts, TabInsts, ExpTables,
-                          std::move(Tab));
+    unsafeAddHostInstance(Name, ExternalType::Table, OwnedTabInsts, TabInsts,
+                          ExpTables, std::move(Tab));
   }
   void addHostMemory(std::string_view Name,
                      std::unique_ptr<Instance::MemoryInstance> &&Mem) {
     std::unique_lock Lock(Mutex);
-    unsafeAddHostInstance(Name, OwnedMemInsts, MemInsts, ExpMems,
-                          std::move(Mem));
+    unsafeAddHostInstance(Name, ExternalType::Memory, OwnedMemInsts, MemInsts,
+                          ExpMems, std::move(Mem));
   }
   void addHostGlobal(std::string_view Name,
                      std::unique_ptr<Instance::GlobalInstance> &&Glob) {
     std::unique_lock Lock(Mutex);
-    unsafeAddHostInstance(Name, OwnedGlobInsts, GlobInsts, ExpGlobals,
-                          std::move(Glob));
+    unsafeAddHostInstance(Name, ExternalType::Global, OwnedGlobInsts,
+                          GlobInsts, ExpGlobals, std::move(Glob));
   }
 
   /// Find and get the exported instance by name.
@@ -147,6 +151,10 @@ public:
     std::shared_lock Lock(Mutex);
     return static_cast<uint32_t>(ExpGlobals.size());
   }
+  uint32_t getExportNum() const noexcept {
+    std::shared_lock Lock(Mutex);
+    return static_cast<uint32_t>(ExpOrder.size());
+  }
 
   /// Get the exported instances maps.
   template <typename CallbackT>
@@ -170,6 +178,13 @@ public:
     return std::forward<CallbackT>(CallBack)(ExpGlobals);
   }
 
+  /// Get the exported names and external types in the export order.
+  template <typename CallbackT>
+  auto getExports(CallbackT &&CallBack) const noexcept {
+    std::shared_lock Lock(Mutex);
+    return std::forward<CallbackT>(CallBack)(ExpOrder);
+  }
+
 private:
   friend class Executor::Executor;
 
@@ -183,6 +198,7 @@ private:
   template <typename... Args> void addFunc(Args &&...Values) {
     std::unique_lock Lock(Mutex);
     unsafeAddInstance(OwnedFuncInsts, FuncInsts, std::forward<Args>(Values)...);
+    unsafeAddFuncIndex();
   }
   template <typename... Args> void addTable(Args &&...Values) {
     std::unique_lock Lock(Mutex);
@@ -228,18 +244,22 @@ private:
   void exportFunction(std::string_view Name, uint32_t Idx) {
     std::unique_lock Lock(Mutex);
     ExpFuncs.insert_or_assign(std::string(Name), FuncInsts[Idx]);
+    unsafeAddExportOrder(Name, ExternalType::Function);
   }
   void exportTable(std::string_view Name, uint32_t Idx) {
     std::unique_lock Lock(Mutex);
     ExpTables.insert_or_assign(std::string(Name), TabInsts[Idx]);
+    unsafeAddExportOrder(Name, ExternalType::Table);
   }
   void exportMemory(std::string_view Name, uint32_t Idx) {
     std::unique_lock Lock(Mutex);
     ExpMems.insert_or_assign(std::string(Name), MemInsts[Idx]);
+    unsafeAddExportOrder(Name, ExternalType::Memory);
   }
   void exportGlobal(std::string_view Name, uint32_t Idx) {
     std::unique_lock Lock(Mutex);
     ExpGlobals.insert_or_assign(std::string(Name), GlobInsts[Idx]);
+    unsafeAddExportOrder(Name, ExternalType::Global);
   }
 
   /// Get function type by index.
@@ -317,6 +337,16 @@ private:
     return DataInsts[Idx];
   }
 
+  /// Get the index of a function instance owned by this module instance.
+  std::optional<uint32_t>
+  getFuncIndex(const FunctionInstance *Func) const noexcept {
+    std::shared_lock Lock(Mutex);
+    if (auto Iter = FuncIdxs.find(Func); Iter != FuncIdxs.end()) {
+      return Iter->second;
+    }
+    return std::nullopt;
+  }
+
   /// Get the instances count.
   uint32_t getFuncNum() const noexcept {
     std::shared_lock Lock(Mutex);
@@ -365,7 +395,7 @@ private:
   /// Unsafe add and export the existing instance into this module.
   template <typename T, typename... Args>
   std::enable_if_t<IsExportEntityV<T>, void>
-  unsafeAddHostInstance(std::string_view Name,
+  unsafeAddHostInstance(std::string_view Name, ExternalType Type,
                         std::vector<std::unique_ptr<T>> &OwnedInstsVec,
                         std::vector<T *> &InstsVec,
                         std::map<std::string, T *, std::less<>> &InstsMap,
@@ -373,6 +403,25 @@ private:
     OwnedInstsVec.push_back(std::move(Inst));
     InstsVec.push_back(OwnedInstsVec.back().get());
     InstsMap.insert_or_assign(std::string(Name), InstsVec.back());
+    unsafeAddExportOrder(Name, Type);
+  }
+
+  /// Unsafe record the index of the latest added function instance.
+  void unsafeAddFuncIndex() {
+    FuncIdxs.emplace(FuncInsts.back(),
+                     static_cast<uint32_t>(FuncInsts.size() - 1));
+  }
+
+  /// Unsafe append the exported name into the export order. An instance
+  /// exported again with the same name and type moves to the end.
+  void unsafeAddExportOrder(std::string_view Name, ExternalType Type) {
+    for (auto Iter = ExpOrder.begin(); Iter != ExpOrder.end(); ++Iter) {
+      if (Iter->first == Name && Iter->second == Type) {
+        ExpOrder.erase(Iter);
+        break;
+      }
+    }
+    ExpOrder.emplace_back(std::string(Name), Type);
   }
 
   /// Unsafe find and get the exported instance by name.
@@ -437,12 +486,18 @@ private:
   /// Imported instances counts.
   uint32_t ImpGlobalNum = 0;
 
+  /// Indices of the owned function instances.
+  std::unordered_map<const FunctionInstance *, uint32_t> FuncIdxs;
+
   /// Exported name maps.
   std::map<std::string, FunctionInstance *, std::less<>> ExpFuncs;
   std::map<std::string, TableInstance *, std::less<>> ExpTables;
   std::map<std::string, MemoryInstance *, std::less<>> ExpMems;
   std::map<std::string, GlobalInstance *, std::less<>> ExpGlobals;
 
+  /// Exported names and external types in the export order.
+  std::vector<std::pair<std::string, ExternalType>> ExpOrder;
+
   /// Start function instance.
   FunctionInstance *StartFunc = nullptr;
 
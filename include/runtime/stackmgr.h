@@ -26,13 +26,16 @@ public:
   struct Frame {
     Frame() = delete;
     Frame(const Instance::ModuleInstance *Mod, AST::InstrView::iterator FromIt,
-          uint32_t L, uint32_t A, uint32_t V) noexcept
-        : Module(Mod), From(FromIt), Locals(L), Arity(A), VPos(V) {}
+          uint32_t L, uint32_t A, uint32_t V,
+          const Instance::FunctionInstance *F) noexcept
+        : Module(Mod), From(FromIt), Locals(L), Arity(A), VPos(V), Func(F) {}
     const Instance::ModuleInstance *Module;
     AST::InstrView::iterator From;
     uint32_t Locals;
     uint32_t Arity;
     uint32_t VPos;
+    /// The function of this frame. Null for the dummy frames.
+    const Instance::FunctionInstance *Func;
   };
 
   using Value = ValVariant;
@@ -78,9 +81,11 @@ public:
   /// Push a new frame entry to stack.
   void pushFrame(const Instance::ModuleInstance *Module,
                  AST::InstrView::iterator From, uint32_t LocalNum = 0,
-                 uint32_t Arity = 0, bool IsTailCall = false) noexcept {
+                 uint32_t Arity = 0, bool IsTailCall = false,
+                 const Instance::FunctionInstance *Func = nullptr) noexcept {
     if (likely(!IsTailCall)) {
-      FrameStack.emplace_back(Module, From, LocalNum, Arity, ValueStack.size());
+      FrameStack.emplace_back(Module, From, LocalNum, Arity, ValueStack.size(),
+                              Func);
     } else {
       assuming(!FrameStack.empty());
       assuming(FrameStack.back().VPos >= FrameStack.back().Locals);
@@ -90,6 +95,7 @@ public:
                            FrameStack.back().Locals,
                        ValueStack.end() - LocalNum);
       FrameStack.back().Module = Module;
+      FrameStack.back().Func = Func;
       FrameStack.back().Locals = LocalNum;
       FrameStack.back().Arity = Arity;
       FrameStack.back().VPos = static_cast<uint32_t>(ValueStack.size());
@@ -132,6 +138,9 @@ public:
     return FrameStack.back().Module;
   }
 
+  /// Getter of the frames. The bottom frame is the first.
+  Span<const Frame> getFrames() const noexcept { return FrameStack; }
+
   /// Reset stack.
   void reset() noexcept {
     ValueStack.clear();
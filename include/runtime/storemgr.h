@@ -15,7 +15,9 @@
 
 #include "runtime/instance/module.h"
 
+#include <map>
 #include <mutex>
+#include <set>
 #include <shared_mutex>
 #include <vector>
 
@@ -31,11 +33,14 @@ class StoreManager {
 public:
   StoreManager() = default;
   ~StoreManager() {
-    // When destroying this store manager, unlink all the registered module
-    // instances.
+    // When destroying this store manager, unlink all the registered and the
+    // importing module instances.
     for (auto &&Pair : NamedMod) {
       (const_cast<Instance::ModuleInstance *>(Pair.second))->unlinkStore(this);
     }
+    for (auto &&Pair : Importers) {
+      (const_cast<Instance::ModuleInstance *>(Pair.first))->unlinkStore(this);
+    }
   }
 
   /// Get the length of the list of registered modules.
@@ -60,6 +65,32 @@ public:
     return nullptr;
   }
 
+  /// Unregister named module by name.
+  ///
+  /// Fail with ErrCode::UnknownModule if the name is not registered, and with
+  /// ErrCode::ModuleInUse if any alive module instance instantiated with this
+  /// store manager imports from it.
+  Expect<void> unregisterModule(std::string_view Name) {
+    std::unique_lock Lock(Mutex);
+    auto Iter = NamedMod.find(Name);
+    if (unlikely(Iter == NamedMod.cend())) {
+      return Unexpect(ErrCode::UnknownModule);
+    }
+    const auto *ModInst = Iter->second;
+    for (auto &&Pair : Importers) {
+      if (Pair.second.count(ModInst) > 0) {
+        return Unexpect(ErrCode::ModuleInUse);
+      }
+    }
+    // Unlink the module instance from this store manager if it is not an
+    // importer tracked by this store manager as well.
+    if (Importers.find(ModInst) == Importers.cend()) {
+      (const_cast<Instance::ModuleInstance *>(ModInst))->unlinkStore(this);
+    }
+    NamedMod.erase(Iter);
+    return {};
+  }
+
 private:
   /// \name Mutex for thread-safe.
   mutable std::shared_mutex Mutex;
@@ -76,15 +107,40 @@ private:
     NamedMod.emplace(ModInst->getModuleName(), ModInst);
     // Link the module instance to this store manager.
     (const_cast<Instance::ModuleInstance *>(ModInst))
-        ->linkStore(this, [](StoreManager *Store,
-                             const Instance::ModuleInstance *Inst) {
-          // The unlink callback.
-          std::unique_lock CallbackLock(Store->Mutex);
-          (Store->NamedMod).erase(std::string(Inst->getModuleName()));
-        });
+        ->linkStore(this, unlinkCallback);
     return {};
   }
 
+  /// Record the registered module instances which the module instance imports
+  /// from.
+  void registerImporter(
+      const Instance::ModuleInstance *ModInst,
+      std::set<const Instance::ModuleInstance *> &&ImportedMods) {
+    if (ImportedMods.empty()) {
+      return;
+    }
+    std::unique_lock Lock(Mutex);
+    Importers.insert_or_assign(ModInst, std::move(ImportedMods));
+    // Link the module instance to this store manager.
+    (const_cast<Instance::ModuleInstance *>(ModInst))
+        ->linkStore(this, unlinkCallback);
+  }
+
+  /// The callback invoked by the module instance linked to this store manager
+  /// before it is destroyed.
+  static void unlinkCallback(StoreManager *Store,
+                             const Instance::ModuleInstance *Inst) {
+    std::unique_lock CallbackLock(Store->Mutex);
+    if (auto Iter = Store->NamedMod.find(Inst->getModuleName());
+        Iter != Store->NamedMod.cend() && Iter->second == Inst) {
+      Store->NamedMod.erase(Iter);
+    }
+    Store->Importers.erase(Inst);
+    for (auto &&Pair : Store->Importers) {
+      Pair.second.erase(Inst);
+    }
+  }
+
   /// Collect the instantiation failed module.
   void recycleModule(std::unique_ptr<Instance::ModuleInstance> &&Mod) {
     FailedMod = std::move(Mod);
@@ -93,6 +149,11 @@ private:
   /// \name Module name mapping.
   std::map<std::string, const Instance::ModuleInstance *, std::less<>> NamedMod;
 
+  /// \name Importing module instances and the module instances they import.
+  std::map<const Instance::ModuleInstance *,
+           std::set<const Instance::ModuleInstance *>>
+      Importers;
+
   /// \name Last instantiation failed module.
   /// According to the current spec, the instances should be able to be
   /// referenced even if instantiation failed. Therefore store the failed module
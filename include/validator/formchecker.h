@@ -17,6 +17,7 @@
 #include "ast/instruction.h"
 #include "ast/module.h"
 #include "common/errcode.h"
+#include "common/errinfo.h"
 #include "common/span.h"
 
 #include <cstddef>
@@ -77,6 +78,9 @@ public:
   auto &getGlobals() { return Globals; }
   uint32_t getNumImportFuncs() const { return NumImportFuncs; }
   uint32_t getNumImportGlobals() const { return NumImportGlobals; }
+  const ErrInfo::InfoDiagnostic &getDiagnostic() const noexcept {
+    return Diag;
+  }
 
   /// Helper function
   VType ASTToVType(const ValType &V);
@@ -147,6 +151,9 @@ private:
   /// Running stack.
   std::vector<CtrlFrame> CtrlStack;
   std::vector<VType> ValStack;
+
+  /// Diagnostic of the latest failure.
+  ErrInfo::InfoDiagnostic Diag;
 };
 
 } // namespace Validator
@@ -33,7 +33,15 @@ public:
   /// Validate AST::Module.
   Expect<void> validate(const AST::Module &Mod);
 
+  /// Get the diagnostic of the latest failed validation.
+  const ErrInfo::InfoDiagnostic &getDiagnostic() const noexcept {
+    return Diag;
+  }
+
 private:
+  /// Record the diagnostic of the failed section.
+  void setDiagnostic(ASTNodeAttr Section);
+
   /// Validate AST::Types
   Expect<void> validate(const AST::Limit &Lim);
   Expect<void> validate(const AST::TableType &Tab);
@@ -72,6 +80,8 @@ private:
   const Configure Conf;
   /// Formal checker
   FormChecker Checker;
+  /// Diagnostic of the latest failure
+  ErrInfo::InfoDiagnostic Diag;
 };
 
 } // namespace Validator
@@ -68,6 +68,12 @@ public:
     return unsafeRegisterModule(ModInst);
   }
 
+  /// Unregister a named module.
+  Expect<void> unregisterModule(std::string_view Name) {
+    std::unique_lock Lock(Mutex);
+    return unsafeUnregisterModule(Name);
+  }
+
   /// Rapidly load, validate, instantiate, and run wasm function.
   Expect<std::vector<std::pair<ValVariant, ValType>>>
   runWasmFile(const std::filesystem::path &Path, std::string_view Func,
@@ -209,6 +215,8 @@ private:
   Expect<void>
   unsafeRegisterModule(const Runtime::Instance::ModuleInstance &ModInst);
 
+  Expect<void> unsafeUnregisterModule(std::string_view Name);
+
   Expect<std::vector<std::pair<ValVariant, ValType>>>
   unsafeRunWasmFile(const std::filesystem::path &Path, std::string_view Func,
                     Span<const ValVariant> Params = {},
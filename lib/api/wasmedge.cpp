@@ -362,6 +362,7 @@ CONVTO(GlobType, AST::GlobalType, GlobalType, )
 CONVTO(GlobType, AST::GlobalType, GlobalType, const)
 CONVTO(ImpType, AST::ImportDesc, ImportType, const)
 CONVTO(ExpType, AST::ExportDesc, ExportType, const)
+CONVTO(Diag, ErrInfo::InfoDiagnostic, Diagnostic, const)
 CONVTO(Store, Runtime::StoreManager, Store, )
 CONVTO(Mod, Runtime::Instance::ModuleInstance, ModuleInstance, )
 CONVTO(Mod, Runtime::Instance::ModuleInstance, ModuleInstance, const)
@@ -391,6 +392,7 @@ CONVFROM(GlobType, AST::GlobalType, GlobalType, )
 CONVFROM(GlobType, AST::GlobalType, GlobalType, const)
 CONVFROM(ImpType, AST::ImportDesc, ImportType, const)
 CONVFROM(ExpType, AST::ExportDesc, ExportType, const)
+CONVFROM(Diag, ErrInfo::InfoDiagnostic, Diagnostic, const)
 CONVFROM(Store, Runtime::StoreManager, Store, )
 CONVFROM(Store, Runtime::StoreManager, Store, const)
 CONVFROM(Mod, Runtime::Instance::ModuleInstance, ModuleInstance, )
@@ -441,6 +443,32 @@ WASMEDGE_CAPI_EXPORT void WasmEdge_LogSetDebugLevel(void) {
   WasmEdge::Log::setDebugLoggingLevel();
 }
 
+WASMEDGE_CAPI_EXPORT void
+WasmEdge_LogSetLevel(const enum WasmEdge_LogLevel Level) {
+  WasmEdge::Log::setLoggingLevel(
+      static_cast<spdlog::level::level_enum>(Level));
+}
+
+WASMEDGE_CAPI_EXPORT void WasmEdge_LogSetCallback(WasmEdge_LogCallback_t Callback,
+                                                  void *Data) {
+  if (Callback) {
+    WasmEdge::Log::setLoggingCallback(
+        [Callback, Data](spdlog::level::level_enum Level,
+                         std::string_view LoggerName,
+                         std::string_view Message) {
+          Callback(Data, static_cast<enum WasmEdge_LogLevel>(Level),
+                   WasmEdge_String{
+                       .Length = static_cast<uint32_t>(LoggerName.length()),
+                       .Buf = LoggerName.data()},
+                   WasmEdge_String{
+                       .Length = static_cast<uint32_t>(Message.length()),
+                       .Buf = Message.data()});
+        });
+  } else {
+    WasmEdge::Log::setLoggingCallback({});
+  }
+}
+
 // <<<<<<<< WasmEdge logging functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge value functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -618,8 +646,8 @@ WasmEdge_ResultGetMessage(const WasmEdge_Result Res) {
 
 WASMEDGE_CAPI_EXPORT bool WasmEdge_LimitIsEqual(const WasmEdge_Limit Lim1,
                                                 const WasmEdge_Limit Lim2) {
-  return Lim1.HasMax == Lim2.HasMax && Lim1.Min == Lim2.Min &&
-         Lim1.Max == Lim2.Max;
+  return Lim1.HasMax == Lim2.HasMax && Lim1.Shared == Lim2.Shared &&
+         Lim1.Min == Lim2.Min && Lim1.Max == Lim2.Max;
 }
 
 // <<<<<<<< WasmEdge limit functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
@@ -886,6 +914,73 @@ WasmEdge_StatisticsSetCostLimit(WasmEdge_StatisticsContext *Cxt,
   }
 }
 
+WASMEDGE_CAPI_EXPORT uint64_t
+WasmEdge_StatisticsGetCostLimit(const WasmEdge_StatisticsContext *Cxt) {
+  if (Cxt) {
+    return fromStatCxt(Cxt)->getCostLimit();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT void WasmEdge_StatisticsSetCostLimitHandler(
+    WasmEdge_StatisticsContext *Cxt, WasmEdge_CostLimitHandler_t Handler,
+    void *Data) {
+  if (Cxt) {
+    if (Handler) {
+      fromStatCxt(Cxt)->setCostLimitHandler(
+          [Handler, Data](WasmEdge::Statistics::Statistics &Stat) {
+            return Handler(Data, toStatCxt(&Stat));
+          });
+    } else {
+      fromStatCxt(Cxt)->setCostLimitHandler(nullptr);
+    }
+  }
+}
+
+WASMEDGE_CAPI_EXPORT void
+WasmEdge_StatisticsSetProfiling(WasmEdge_StatisticsContext *Cxt,
+                                const bool IsEnable) {
+  if (Cxt) {
+    fromStatCxt(Cxt)->setProfiling(IsEnable);
+  }
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_StatisticsGetProfileLength(const WasmEdge_StatisticsContext *Cxt) {
+  if (Cxt) {
+    return static_cast<uint32_t>(fromStatCxt(Cxt)->getProfileLength());
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_StatisticsGetProfile(const WasmEdge_StatisticsContext *Cxt,
+                              WasmEdge_ProfileEntry *Buf,
+                              const uint32_t BufLen) {
+  if (Cxt) {
+    uint32_t I = 0;
+    fromStatCxt(Cxt)->visitProfile([&](const auto &Node) {
+      if (Buf && I < BufLen) {
+        Buf[I] = WasmEdge_ProfileEntry{
+            .Parent = Node.Parent.value_or(UINT32_MAX),
+            .ModuleName =
+                WasmEdge_String{
+                    .Length = static_cast<uint32_t>(Node.ModuleName.length()),
+                    .Buf = Node.ModuleName.data()},
+            .FunctionIndex = Node.FuncIdx,
+            .IsHost = Node.IsHost,
+            .InstrCount = Node.InstrCount,
+            .Cost = Node.Cost,
+            .Time = static_cast<uint64_t>(
+                std::chrono::nanoseconds(Node.Time).count())};
+      }
+      I++;
+    });
+    return I;
+  }
+  return 0;
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_StatisticsClear(WasmEdge_StatisticsContext *Cxt) {
   if (Cxt) {
@@ -950,6 +1045,47 @@ WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ASTModuleListExports(
   return 0;
 }
 
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ASTModuleListCustomSectionsLength(
+    const WasmEdge_ASTModuleContext *Cxt) {
+  if (Cxt) {
+    return static_cast<uint32_t>(
+        fromASTModCxt(Cxt)->getCustomSections().size());
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_String WasmEdge_ASTModuleGetCustomSectionName(
+    const WasmEdge_ASTModuleContext *Cxt, const uint32_t Idx) {
+  if (Cxt) {
+    const auto &CustomSecs = fromASTModCxt(Cxt)->getCustomSections();
+    if (Idx < CustomSecs.size()) {
+      auto StrView = CustomSecs[Idx].getName();
+      return WasmEdge_String{.Length = static_cast<uint32_t>(StrView.length()),
+                             .Buf = StrView.data()};
+    }
+  }
+  return WasmEdge_String{.Length = 0, .Buf = nullptr};
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ASTModuleGetCustomSectionContent(
+    const WasmEdge_ASTModuleContext *Cxt, const uint32_t Idx,
+    const uint8_t **Content) {
+  if (Content) {
+    *Content = nullptr;
+  }
+  if (Cxt) {
+    const auto &CustomSecs = fromASTModCxt(Cxt)->getCustomSections();
+    if (Idx < CustomSecs.size()) {
+      auto Data = CustomSecs[Idx].getContent();
+      if (Content) {
+        *Content = Data.data();
+      }
+      return static_cast<uint32_t>(Data.size());
+    }
+  }
+  return 0;
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_ASTModuleDelete(WasmEdge_ASTModuleContext *Cxt) {
   std::unique_ptr<WasmEdge::AST::Module> Own(fromASTModCxt(Cxt));
@@ -1055,10 +1191,12 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Limit
 WasmEdge_TableTypeGetLimit(const WasmEdge_TableTypeContext *Cxt) {
   if (Cxt) {
     const auto &Lim = fromTabTypeCxt(Cxt)->getLimit();
-    return WasmEdge_Limit{
-        .HasMax = Lim.hasMax(), .Min = Lim.getMin(), .Max = Lim.getMax()};
+    return WasmEdge_Limit{.HasMax = Lim.hasMax(),
+                          .Min = Lim.getMin(),
+                          .Max = Lim.getMax(),
+                          .Shared = Lim.isShared()};
   }
-  return WasmEdge_Limit{.HasMax = false, .Min = 0, .Max = 0};
+  return WasmEdge_Limit{.HasMax = false, .Min = 0, .Max = 0, .Shared = false};
 }
 
 WASMEDGE_CAPI_EXPORT void
@@ -1072,8 +1210,12 @@ WasmEdge_TableTypeDelete(WasmEdge_TableTypeContext *Cxt) {
 
 WASMEDGE_CAPI_EXPORT WasmEdge_MemoryTypeContext *
 WasmEdge_MemoryTypeCreate(const WasmEdge_Limit Limit) {
+  if (Limit.Shared && !Limit.HasMax) {
+    return nullptr;
+  }
   if (Limit.HasMax) {
-    return toMemTypeCxt(new WasmEdge::AST::MemoryType(Limit.Min, Limit.Max));
+    return toMemTypeCxt(
+        new WasmEdge::AST::MemoryType(Limit.Min, Limit.Max, Limit.Shared));
   } else {
     return toMemTypeCxt(new WasmEdge::AST::MemoryType(Limit.Min));
   }
@@ -1083,10 +1225,12 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Limit
 WasmEdge_MemoryTypeGetLimit(const WasmEdge_MemoryTypeContext *Cxt) {
   if (Cxt) {
     const auto &Lim = fromMemTypeCxt(Cxt)->getLimit();
-    return WasmEdge_Limit{
-        .HasMax = Lim.hasMax(), .Min = Lim.getMin(), .Max = Lim.getMax()};
+    return WasmEdge_Limit{.HasMax = Lim.hasMax(),
+                          .Min = Lim.getMin(),
+                          .Max = Lim.getMax(),
+                          .Shared = Lim.isShared()};
   }
-  return WasmEdge_Limit{.HasMax = false, .Min = 0, .Max = 0};
+  return WasmEdge_Limit{.HasMax = false, .Min = 0, .Max = 0, .Shared = false};
 }
 
 WASMEDGE_CAPI_EXPORT void
@@ -1427,6 +1571,14 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_LoaderParseFromBuffer(
               Module);
 }
 
+WASMEDGE_CAPI_EXPORT const WasmEdge_DiagnosticContext *
+WasmEdge_LoaderGetDiagnostic(const WasmEdge_LoaderContext *Cxt) {
+  if (Cxt) {
+    return toDiagCxt(&Cxt->Load.getDiagnostic());
+  }
+  return nullptr;
+}
+
 WASMEDGE_CAPI_EXPORT void WasmEdge_LoaderDelete(WasmEdge_LoaderContext *Cxt) {
   delete Cxt;
 }
@@ -1451,6 +1603,14 @@ WasmEdge_ValidatorValidate(WasmEdge_ValidatorContext *Cxt,
               EmptyThen, Cxt, ModuleCxt);
 }
 
+WASMEDGE_CAPI_EXPORT const WasmEdge_DiagnosticContext *
+WasmEdge_ValidatorGetDiagnostic(const WasmEdge_ValidatorContext *Cxt) {
+  if (Cxt) {
+    return toDiagCxt(&Cxt->Valid.getDiagnostic());
+  }
+  return nullptr;
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_ValidatorDelete(WasmEdge_ValidatorContext *Cxt) {
   delete Cxt;
@@ -1458,6 +1618,78 @@ WasmEdge_ValidatorDelete(WasmEdge_ValidatorContext *Cxt) {
 
 // <<<<<<<< WasmEdge validator functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
+// >>>>>>>> WasmEdge diagnostic functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+WASMEDGE_CAPI_EXPORT WasmEdge_String
+WasmEdge_DiagnosticGetSection(const WasmEdge_DiagnosticContext *Cxt) {
+  if (Cxt) {
+    auto StrView = ASTNodeAttrStr[fromDiagCxt(Cxt)->Section];
+    return WasmEdge_String{.Length = static_cast<uint32_t>(StrView.length()),
+                           .Buf = StrView.data()};
+  }
+  return WasmEdge_String{.Length = 0, .Buf = nullptr};
+}
+
+WASMEDGE_CAPI_EXPORT bool
+WasmEdge_DiagnosticGetOffset(const WasmEdge_DiagnosticContext *Cxt,
+                             uint64_t *Offset) {
+  if (Cxt && Offset && fromDiagCxt(Cxt)->Offset) {
+    *Offset = *fromDiagCxt(Cxt)->Offset;
+    return true;
+  }
+  return false;
+}
+
+WASMEDGE_CAPI_EXPORT bool
+WasmEdge_DiagnosticGetFunctionIndex(const WasmEdge_DiagnosticContext *Cxt,
+                                    uint32_t *FuncIdx) {
+  if (Cxt && FuncIdx && fromDiagCxt(Cxt)->FuncIndex) {
+    *FuncIdx = *fromDiagCxt(Cxt)->FuncIndex;
+    return true;
+  }
+  return false;
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_String
+WasmEdge_DiagnosticGetInstruction(const WasmEdge_DiagnosticContext *Cxt) {
+  if (Cxt && fromDiagCxt(Cxt)->Code) {
+    auto StrView = OpCodeStr[*fromDiagCxt(Cxt)->Code];
+    return WasmEdge_String{.Length = static_cast<uint32_t>(StrView.length()),
+                           .Buf = StrView.data()};
+  }
+  return WasmEdge_String{.Length = 0, .Buf = nullptr};
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_DiagnosticGetExpectedTypes(const WasmEdge_DiagnosticContext *Cxt,
+                                    WasmEdge_ValType *Buf,
+                                    const uint32_t BufLen) {
+  if (Cxt) {
+    const auto &Types = fromDiagCxt(Cxt)->ExpTypes;
+    for (uint32_t I = 0; I < Types.size() && I < BufLen; I++) {
+      Buf[I] = static_cast<WasmEdge_ValType>(Types[I]);
+    }
+    return static_cast<uint32_t>(Types.size());
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_DiagnosticGetActualTypes(const WasmEdge_DiagnosticContext *Cxt,
+                                  WasmEdge_ValType *Buf,
+                                  const uint32_t BufLen) {
+  if (Cxt) {
+    const auto &Types = fromDiagCxt(Cxt)->GotTypes;
+    for (uint32_t I = 0; I < Types.size() && I < BufLen; I++) {
+      Buf[I] = static_cast<WasmEdge_ValType>(Types[I]);
+    }
+    return static_cast<uint32_t>(Types.size());
+  }
+  return 0;
+}
+
+// <<<<<<<< WasmEdge diagnostic functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+
 // >>>>>>>> WasmEdge executor functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
 WASMEDGE_CAPI_EXPORT WasmEdge_ExecutorContext *
@@ -1538,6 +1770,30 @@ WasmEdge_ExecutorDelete(WasmEdge_ExecutorContext *Cxt) {
   delete Cxt;
 }
 
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_TrapGetBacktraceLength(void) {
+  return static_cast<uint32_t>(
+      WasmEdge::Executor::Executor::getBacktrace().size());
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_TrapGetBacktrace(WasmEdge_TrapFrame *Buf,
+                                                        const uint32_t BufLen) {
+  const auto Frames = WasmEdge::Executor::Executor::getBacktrace();
+  if (Buf) {
+    for (uint32_t I = 0; I < Frames.size() && I < BufLen; I++) {
+      const auto &Frame = Frames[I];
+      Buf[I] = WasmEdge_TrapFrame{
+          .ModuleName =
+              WasmEdge_String{
+                  .Length = static_cast<uint32_t>(Frame.ModuleName.length()),
+                  .Buf = Frame.ModuleName.data()},
+          .FunctionIndex = Frame.FuncIdx,
+          .HasOffset = Frame.Offset.has_value(),
+          .Offset = Frame.Offset.value_or(0)};
+    }
+  }
+  return static_cast<uint32_t>(Frames.size());
+}
+
 // <<<<<<<< WasmEdge executor functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge store functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -1555,6 +1811,14 @@ WasmEdge_StoreFindModule(WasmEdge_StoreContext *Cxt,
   return nullptr;
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result
+WasmEdge_StoreUnregisterModule(WasmEdge_StoreContext *Cxt,
+                               const WasmEdge_String Name) {
+  return wrap(
+      [&]() { return fromStoreCxt(Cxt)->unregisterModule(genStrView(Name)); },
+      EmptyThen, Cxt);
+}
+
 WASMEDGE_CAPI_EXPORT uint32_t
 WasmEdge_StoreListModuleLength(const WasmEdge_StoreContext *Cxt) {
   if (Cxt) {
@@ -1800,6 +2064,37 @@ WasmEdge_ModuleInstanceListGlobal(const WasmEdge_ModuleInstanceContext *Cxt,
   return 0;
 }
 
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ModuleInstanceListExportLength(
+    const WasmEdge_ModuleInstanceContext *Cxt) {
+  if (Cxt) {
+    return fromModCxt(Cxt)->getExportNum();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_ModuleInstanceListExport(const WasmEdge_ModuleInstanceContext *Cxt,
+                                  WasmEdge_String *Names,
+                                  enum WasmEdge_ExternalType *Types,
+                                  const uint32_t Len) {
+  if (Cxt) {
+    return fromModCxt(Cxt)->getExports([&](auto &Exports) {
+      for (uint32_t I = 0; I < Len && I < Exports.size(); I++) {
+        if (Names) {
+          Names[I] = WasmEdge_String{
+              .Length = static_cast<uint32_t>(Exports[I].first.length()),
+              .Buf = Exports[I].first.data()};
+        }
+        if (Types) {
+          Types[I] = static_cast<WasmEdge_ExternalType>(Exports[I].second);
+        }
+      }
+      return static_cast<uint32_t>(Exports.size());
+    });
+  }
+  return 0;
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_ModuleInstanceAddFunction(WasmEdge_ModuleInstanceContext *Cxt,
                                    const WasmEdge_String Name,
@@ -1974,6 +2269,60 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_TableInstanceGrow(
       EmptyThen, Cxt);
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result
+WasmEdge_TableInstanceFill(WasmEdge_TableInstanceContext *Cxt,
+                           WasmEdge_Value Data, const uint32_t Offset,
+                           const uint32_t Length) {
+  return wrap(
+      [&]() -> WasmEdge::Expect<void> {
+        WasmEdge::RefType expType =
+            fromTabCxt(Cxt)->getTableType().getRefType();
+        if (expType != static_cast<WasmEdge::RefType>(Data.Type)) {
+          spdlog::error(WasmEdge::ErrCode::RefTypeMismatch);
+          spdlog::error(WasmEdge::ErrInfo::InfoMismatch(
+              static_cast<WasmEdge::ValType>(expType),
+              static_cast<WasmEdge::ValType>(Data.Type)));
+          return Unexpect(WasmEdge::ErrCode::RefTypeMismatch);
+        }
+        return fromTabCxt(Cxt)->fillRefs(
+            WasmEdge::ValVariant(
+                to_WasmEdge_128_t<WasmEdge::uint128_t>(Data.Value))
+                .get<UnknownRef>(),
+            Offset, Length);
+      },
+      EmptyThen, Cxt);
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_Result
+WasmEdge_TableInstanceCopy(WasmEdge_TableInstanceContext *DstCxt,
+                           const WasmEdge_TableInstanceContext *SrcCxt,
+                           const uint32_t DstOffset, const uint32_t SrcOffset,
+                           const uint32_t Length) {
+  return wrap(
+      [&]() -> WasmEdge::Expect<void> {
+        WasmEdge::RefType DstType =
+            fromTabCxt(DstCxt)->getTableType().getRefType();
+        WasmEdge::RefType SrcType =
+            fromTabCxt(SrcCxt)->getTableType().getRefType();
+        if (DstType != SrcType) {
+          spdlog::error(WasmEdge::ErrCode::RefTypeMismatch);
+          spdlog::error(WasmEdge::ErrInfo::InfoMismatch(
+              static_cast<WasmEdge::ValType>(DstType),
+              static_cast<WasmEdge::ValType>(SrcType)));
+          return Unexpect(WasmEdge::ErrCode::RefTypeMismatch);
+        }
+        // Take the source references out first, since the ranges can overlap.
+        std::vector<WasmEdge::RefVariant> Refs;
+        if (auto Res = fromTabCxt(SrcCxt)->getRefs(SrcOffset, Length)) {
+          Refs.assign(Res->begin(), Res->end());
+        } else {
+          return Unexpect(Res);
+        }
+        return fromTabCxt(DstCxt)->setRefs(Refs, DstOffset, 0, Length);
+      },
+      EmptyThen, DstCxt, SrcCxt);
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_TableInstanceDelete(WasmEdge_TableInstanceContext *Cxt) {
   delete fromTabCxt(Cxt);
@@ -2061,6 +2410,36 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceGrowPage(
       EmptyThen, Cxt);
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result
+WasmEdge_MemoryInstanceAtomicNotify(WasmEdge_MemoryInstanceContext *Cxt,
+                                    const uint32_t Offset, const uint32_t Count,
+                                    uint32_t *Woken) {
+  return wrap([&]() { return fromMemCxt(Cxt)->atomicNotify(Offset, Count); },
+              [&](auto &&Res) { *Woken = *Res; }, Cxt, Woken);
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceAtomicWait32(
+    WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Offset,
+    const uint32_t Expected, const int64_t Timeout, uint32_t *Result) {
+  return wrap(
+      [&]() {
+        return fromMemCxt(Cxt)->atomicWait<uint32_t>(Offset, Expected,
+                                                     Timeout);
+      },
+      [&](auto &&Res) { *Result = *Res; }, Cxt, Result);
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceAtomicWait64(
+    WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Offset,
+    const uint64_t Expected, const int64_t Timeout, uint32_t *Result) {
+  return wrap(
+      [&]() {
+        return fromMemCxt(Cxt)->atomicWait<uint64_t>(Offset, Expected,
+                                                     Timeout);
+      },
+      [&](auto &&Res) { *Result = *Res; }, Cxt, Result);
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_MemoryInstanceDelete(WasmEdge_MemoryInstanceContext *Cxt) {
   delete fromMemCxt(Cxt);
@@ -2228,6 +2607,14 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_VMRegisterModuleFromImport(
               EmptyThen, Cxt, ImportCxt);
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result
+WasmEdge_VMUnregisterModule(WasmEdge_VMContext *Cxt,
+                            const WasmEdge_String ModuleName) {
+  return wrap(
+      [&]() { return Cxt->VM.unregisterModule(genStrView(ModuleName)); },
+      EmptyThen, Cxt);
+}
+
 WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_VMRunWasmFromFile(
     WasmEdge_VMContext *Cxt, const char *Path, const WasmEdge_String FuncName,
     const WasmEdge_Value *Params, const uint32_t ParamLen,
@@ -3,9 +3,42 @@
 
 #include "common/log.h"
 
+#include "spdlog/sinks/base_sink.h"
+
+#include <memory>
+#include <mutex>
+#include <utility>
+
 namespace WasmEdge {
 namespace Log {
 
+namespace {
+
+/// Sink of spdlog to forward the log messages to a callback.
+class CallbackSink final : public spdlog::sinks::base_sink<std::mutex> {
+public:
+  explicit CallbackSink(Callback Func) noexcept : Func(std::move(Func)) {}
+
+protected:
+  void sink_it_(const spdlog::details::log_msg &Msg) override {
+    Func(Msg.level,
+         std::string_view(Msg.logger_name.data(), Msg.logger_name.size()),
+         std::string_view(Msg.payload.data(), Msg.payload.size()));
+  }
+  void flush_() override {}
+
+private:
+  Callback Func;
+};
+
+/// The default logger which outputs to the console.
+std::shared_ptr<spdlog::logger> &consoleLogger() noexcept {
+  static std::shared_ptr<spdlog::logger> Logger;
+  return Logger;
+}
+
+} // namespace
+
 void setDebugLoggingLevel() { spdlog::set_level(spdlog::level::debug); }
 
 void setInfoLoggingLevel() { spdlog::set_level(spdlog::level::info); }
@@ -14,5 +47,26 @@ void setWarnLoggingLevel() { spdlog::set_level(spdlog::level::warn); }
 
 void setErrorLoggingLevel() { spdlog::set_level(spdlog::level::err); }
 
+void setLoggingLevel(spdlog::level::level_enum Level) {
+  spdlog::set_level(Level);
+}
+
+void setLoggingCallback(Callback Func) {
+  static std::mutex Mutex;
+  std::unique_lock Lock(Mutex);
+  auto &Console = consoleLogger();
+  if (!Console) {
+    Console = spdlog::default_logger();
+  }
+  const auto Level = spdlog::default_logger()->level();
+  std::shared_ptr<spdlog::logger> Logger = Console;
+  if (Func) {
+    Logger = std::make_shared<spdlog::logger>(
+        Console->name(), std::make_shared<CallbackSink>(std::move(Func)));
+  }
+  Logger->set_level(Level);
+  spdlog::set_default_logger(std::move(Logger));
+}
+
 } // namespace Log
 } // namespace WasmEdge
@@ -77,6 +77,10 @@ Expect<void> Executor::runReturnOp(Runtime::StackManager &StackMgr,
     return Unexpect(ErrCode::Interrupted);
   }
   PC = StackMgr.popFrame();
+  if (Stat && Stat->isProfiling()) {
+    // Return to the caller in the profiled call path.
+    syncProfile(StackMgr);
+  }
   return {};
 }
 
@@ -23,6 +23,10 @@ Executor::runFunction(Runtime::StackManager &StackMgr,
   if (Stat && Conf.getStatisticsConfigure().isTimeMeasuring()) {
     Stat->startRecordWasm();
   }
+  const bool IsProfiling = Stat && Stat->isProfiling();
+  if (IsProfiling) {
+    startProfile();
+  }
 
   // Reset and push a dummy frame into stack.
   StackMgr.pushFrame(nullptr, AST::InstrView::iterator(), 0, 0);
@@ -37,10 +41,20 @@ Executor::runFunction(Runtime::StackManager &StackMgr,
   if (auto Res = enterFunction(StackMgr, Func, Func.getInstrs().end())) {
     StartIt = *Res;
   } else {
+    if (Res.error() != ErrCode::Terminated) {
+      recordBacktrace(StackMgr, std::nullopt);
+    }
+    if (IsProfiling) {
+      stopProfile();
+    }
     return Unexpect(Res);
   }
   auto Res = execute(StackMgr, StartIt, Func.getInstrs().end());
 
+  if (IsProfiling) {
+    stopProfile();
+  }
+
   if (Res) {
     spdlog::debug(" Execution succeeded.");
   } else if (Res.error() == ErrCode::Terminated) {
@@ -107,6 +121,10 @@ Expect<void> Executor::execute(Runtime::StackManager &StackMgr,
       [[fallthrough]];
     case OpCode::End:
       PC = StackMgr.maybePopFrame(PC);
+      if (Stat && Stat->isProfiling()) {
+        // Return to the caller in the profiled call path.
+        syncProfile(StackMgr);
+      }
       return {};
     case OpCode::Br:
       return runBrOp(StackMgr, Instr, PC);
@@ -1613,9 +1631,20 @@ Expect<void> Executor::execute(Runtime::StackManager &StackMgr,
     }
   };
 
+  // The profiled call path of this invocation, which is synchronized at the
+  // function calls and returns.
+  ProfileState *Profile = nullptr;
+  if (Stat && Stat->isProfiling() && !ProfileStack.empty()) {
+    Profile = &ProfileStack.back();
+  }
+
   while (PC != PCEnd) {
     if (Stat) {
       OpCode Code = PC->getOpCode();
+      if (Profile) {
+        Profile->InstrCount++;
+        Profile->Cost += Stat->getInstrCost(Code);
+      }
       if (Conf.getStatisticsConfigure().isInstructionCounting()) {
         Stat->incInstrCount();
       }
@@ -1630,6 +1659,9 @@ Expect<void> Executor::execute(Runtime::StackManager &StackMgr,
       }
     }
     if (auto Res = Dispatch(); !Res) {
+      if (Res.error() != ErrCode::Terminated) {
+        recordBacktrace(StackMgr, PC->getOffset());
+      }
       return Unexpect(Res);
     }
     PC++;
@@ -13,6 +13,7 @@ namespace Executor {
 Expect<std::unique_ptr<Runtime::Instance::ModuleInstance>>
 Executor::instantiateModule(Runtime::StoreManager &StoreMgr,
                             const AST::Module &Mod) {
+  Backtrace.clear();
   if (auto Res = instantiate(StoreMgr, Mod)) {
     return Res;
   } else {
@@ -30,6 +31,7 @@ Executor::instantiateModule(Runtime::StoreManager &StoreMgr,
 Expect<std::unique_ptr<Runtime::Instance::ModuleInstance>>
 Executor::registerModule(Runtime::StoreManager &StoreMgr,
                          const AST::Module &Mod, std::string_view Name) {
+  Backtrace.clear();
   if (auto Res = instantiate(StoreMgr, Mod, Name)) {
     return Res;
   } else {
@@ -60,6 +62,10 @@ Expect<std::vector<std::pair<ValVariant, ValType>>>
 Executor::invoke(const Runtime::Instance::FunctionInstance &FuncInst,
                  Span<const ValVariant> Params,
                  Span<const ValType> ParamTypes) {
+  // Frames of the former invocation in this thread must not be reported for
+  // the errors below.
+  Backtrace.clear();
+
   // Check parameter and function type.
   const auto &FuncType = FuncInst.getFuncType();
   const auto &PTypes = FuncType.getParamTypes();
@@ -47,15 +47,20 @@ Executor::enterFunction(Runtime::StackManager &StackMgr,
     }
 
     // Push frame.
-    StackMgr.pushFrame(nullptr,   // Host function instance don't have module
-                       RetIt,     // Return PC
-                       ArgsN,     // Only args, no locals in stack
-                       RetsN,     // Returns num
-                       IsTailCall // For tail-call
+    StackMgr.pushFrame(nullptr,    // Host function instance don't have module
+                       RetIt,      // Return PC
+                       ArgsN,      // Only args, no locals in stack
+                       RetsN,      // Returns num
+                       IsTailCall, // For tail-call
+                       &Func       // Function instance
     );
 
     // Do the statistics if the statistics turned on.
     if (Stat) {
+      // Enter the host function in the profiled call path.
+      if (Stat->isProfiling()) {
+        syncProfile(StackMgr);
+      }
       // Check host function cost.
       if (unlikely(!Stat->addCost(HostFunc.getCost()))) {
         spdlog::error(ErrCode::CostLimitExceeded);
@@ -93,7 +98,12 @@ Executor::enterFunction(Runtime::StackManager &StackMgr,
 
     // For host function case, the continuation will be the continuation from
     // the popped frame.
-    return StackMgr.popFrame();
+    const auto Cont = StackMgr.popFrame();
+    if (Stat && Stat->isProfiling()) {
+      // Return to the caller in the profiled call path.
+      syncProfile(StackMgr);
+    }
+    return Cont;
   } else if (Func.isCompiledFunction()) {
     // Compiled function case: Execute the function and jump to the
     // continuation.
@@ -103,7 +113,8 @@ Executor::enterFunction(Runtime::StackManager &StackMgr,
                        RetIt,            // Return PC
                        ArgsN,            // Only args, no locals in stack
                        RetsN,            // Returns num
-                       IsTailCall        // For tail-call
+                       IsTailCall,       // For tail-call
+                       &Func             // Function instance
     );
 
     // Prepare arguments.
@@ -168,15 +179,139 @@ Executor::enterFunction(Runtime::StackManager &StackMgr,
                        RetIt - 1,                  // Return PC
                        ArgsN + Func.getLocalNum(), // Arguments num + local num
                        RetsN,                      // Returns num
-                       IsTailCall                  // For tail-call
+                       IsTailCall,                 // For tail-call
+                       &Func                       // Function instance
     );
 
+    // Enter the function in the profiled call path.
+    if (Stat && Stat->isProfiling()) {
+      syncProfile(StackMgr);
+    }
+
     // For native function case, the continuation will be the start of the
     // function body.
     return Func.getInstrs().begin();
   }
 }
 
+void Executor::locateFunction(const Runtime::Instance::FunctionInstance &Func,
+                              std::string &ModuleName, uint32_t &FuncIdx) {
+  ModuleName.clear();
+  FuncIdx = 0;
+  if (const auto *ModInst = Func.getModule()) {
+    ModuleName = ModInst->getModuleName();
+    FuncIdx = ModInst->getFuncIndex(&Func).value_or(0);
+  }
+}
+
+void Executor::recordBacktrace(const Runtime::StackManager &StackMgr,
+                               std::optional<uint64_t> Offset) {
+  // Offset: the offset of the current instruction in the innermost
+  // interpreted function.
+  Backtrace.clear();
+  const auto Frames = StackMgr.getFrames();
+  for (auto It = Frames.rbegin(); It != Frames.rend(); ++It) {
+    const auto *Func = It->Func;
+    if (Func == nullptr) {
+      // Skip the dummy frames.
+      continue;
+    }
+    TrapFrame Frame;
+    locateFunction(*Func, Frame.ModuleName, Frame.FuncIdx);
+    if (!Func->isHostFunction() && !Func->isCompiledFunction()) {
+      // The return PC of an interpreted frame is the call instruction of its
+      // caller.
+      Frame.Offset = Offset;
+      Offset = It->From->getOffset();
+    } else if (Func->isCompiledFunction()) {
+      // The call instruction of the caller is unknown.
+      Offset = std::nullopt;
+    }
+    Backtrace.push_back(std::move(Frame));
+  }
+}
+
+void Executor::startProfile() {
+  if (!ProfileStack.empty()) {
+    // The nested invocation is not charged to the outer one.
+    chargeProfile(ProfileStack.back());
+  }
+  ProfileStack.emplace_back();
+  ProfileStack.back().Time = Timer::Timer::Clock::now();
+}
+
+void Executor::stopProfile() {
+  chargeProfile(ProfileStack.back());
+  ProfileStack.pop_back();
+  if (!ProfileStack.empty()) {
+    ProfileStack.back().Time = Timer::Timer::Clock::now();
+  }
+}
+
+void Executor::chargeProfile(ProfileState &State) {
+  const auto Now = Timer::Timer::Clock::now();
+  if (!State.Path.empty()) {
+    Stat->chargeProfile(State.Path.back().second, State.InstrCount, State.Cost,
+                        Now - State.Time);
+  }
+  State.InstrCount = 0;
+  State.Cost = 0;
+  State.Time = Now;
+}
+
+void Executor::syncProfile(const Runtime::StackManager &StackMgr) {
+  if (ProfileStack.empty()) {
+    // The profiling is turned on during the invocation.
+    return;
+  }
+  auto &State = ProfileStack.back();
+  auto &Path = State.Path;
+  const auto Frames = StackMgr.getFrames();
+  if (Frames.size() == State.FrameCount &&
+      (Path.empty() || Frames.back().Func == Path.back().first)) {
+    // The call path is not changed.
+    return;
+  }
+
+  // Charge the innermost function before leaving it.
+  chargeProfile(State);
+  State.FrameCount = Frames.size();
+
+  // Keep the common outer part of the call path.
+  size_t Depth = 0;
+  auto It = Frames.begin();
+  for (; It != Frames.end(); ++It) {
+    if (It->Func == nullptr) {
+      // Skip the dummy frames.
+      continue;
+    }
+    if (Depth >= Path.size() || Path[Depth].first != It->Func) {
+      break;
+    }
+    Depth++;
+  }
+  Path.resize(Depth);
+
+  // Enter the new inner functions.
+  for (; It != Frames.end(); ++It) {
+    const auto *Func = It->Func;
+    if (Func == nullptr) {
+      continue;
+    }
+    std::optional<uint32_t> Parent;
+    if (!Path.empty()) {
+      Parent = Path.back().second;
+    }
+    const uint32_t Node = Stat->getProfileNode(Parent, Func, [Func]() {
+      Statistics::Statistics::ProfileNode NewNode;
+      locateFunction(*Func, NewNode.ModuleName, NewNode.FuncIdx);
+      NewNode.IsHost = Func->isHostFunction();
+      return NewNode;
+    });
+    Path.emplace_back(Func, Node);
+  }
+}
+
 Expect<void> Executor::branchToLabel(Runtime::StackManager &StackMgr,
                                      uint32_t EraseBegin, uint32_t EraseEnd,
                                      int32_t PCOffset,
@@ -36,6 +36,9 @@ bool isLimitMatched(const AST::Limit &Lim1, const AST::Limit &Lim2) {
   if ((Lim1.getMin() < Lim2.getMin()) || (!Lim1.hasMax() && Lim2.hasMax())) {
     return false;
   }
+  if (Lim1.isShared() != Lim2.isShared()) {
+    return false;
+  }
   if (Lim1.hasMax() && Lim2.hasMax() && Lim1.getMax() > Lim2.getMax()) {
     return false;
   }
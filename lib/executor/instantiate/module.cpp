@@ -7,6 +7,7 @@
 #include "common/log.h"
 
 #include <cstdint>
+#include <set>
 #include <string_view>
 
 namespace WasmEdge {
@@ -162,6 +163,17 @@ Executor::instantiate(Runtime::StoreManager &StoreMgr, const AST::Module &Mod,
   // Pop Frame.
   StackMgr.popFrame();
 
+  // Record the registered modules this module imports from, so that they
+  // cannot be unregistered while this module instance is alive.
+  std::set<const Runtime::Instance::ModuleInstance *> ImportedMods;
+  for (const auto &ImpDesc : ImportSec.getContent()) {
+    if (const auto *TargetModInst =
+            StoreMgr.findModule(ImpDesc.getModuleName())) {
+      ImportedMods.insert(TargetModInst);
+    }
+  }
+  StoreMgr.registerImporter(ModInst.get(), std::move(ImportedMods));
+
   // For the named modules, register it into the store.
   if (Name.has_value()) {
     StoreMgr.registerModule(ModInst.get());
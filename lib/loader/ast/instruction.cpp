@@ -51,6 +51,7 @@ Expect<AST::InstrVec> Loader::loadInstrSeq(std::optional<uint64_t> SizeBound) {
 
     // Check with proposals.
     if (auto Res = checkInstrProposals(Code, Offset); !Res) {
+      Diag.Code = Code;
       return Unexpect(Res);
     }
 
@@ -102,6 +103,7 @@ Expect<AST::InstrVec> Loader::loadInstrSeq(std::optional<uint64_t> SizeBound) {
     // Create the instruction node and load contents.
     Instrs.emplace_back(Code, Offset);
     if (auto Res = loadInstruction(Instrs.back()); !Res) {
+      Diag.Code = Code;
       return Unexpect(Res);
     }
     if (Code == OpCode::End) {
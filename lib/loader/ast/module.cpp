@@ -220,6 +220,14 @@ Expect<std::unique_ptr<AST::Module>> Loader::loadModule() {
       break;
     case 0x0A:
       if (auto Res = loadSection(Mod->getCodeSection()); !Res) {
+        if (Diag.FuncIndex) {
+          // Count the imported functions into the function index.
+          for (const auto &ImpDesc : Mod->getImportSection().getContent()) {
+            if (ImpDesc.getExternalType() == ExternalType::Function) {
+              ++*Diag.FuncIndex;
+            }
+          }
+        }
         spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
         return Unexpect(Res);
       }
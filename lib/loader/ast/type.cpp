@@ -18,6 +18,16 @@ Expect<void> Loader::loadLimit(AST::Limit &Lim) {
     case AST::Limit::LimitType::HasMinMax:
       Lim.setHasMax(true);
       break;
+    case AST::Limit::LimitType::SharedNoMax:
+    case AST::Limit::LimitType::Shared:
+      if (unlikely(!Conf.hasProposal(Proposal::Threads))) {
+        return logNeedProposal(ErrCode::IntegerTooLarge, Proposal::Threads,
+                               FMgr.getLastOffset(), ASTNodeAttr::Type_Limit);
+      }
+      Lim.setShared(true);
+      Lim.setHasMax(static_cast<AST::Limit::LimitType>(*Res) ==
+                    AST::Limit::LimitType::Shared);
+      break;
     default:
       if (*Res == 0x80 || *Res == 0x81) {
         // LEB128 cases will fail.
@@ -152,6 +162,11 @@ Expect<void> Loader::loadType(AST::TableType &TabType) {
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Type_Table));
     return Unexpect(Res);
   }
+  // Tables cannot be shared.
+  if (unlikely(TabType.getLimit().isShared())) {
+    return logLoadError(ErrCode::SharedTable, FMgr.getLastOffset(),
+                        ASTNodeAttr::Type_Table);
+  }
   return {};
 }
 
@@ -65,6 +65,7 @@ Expect<std::unique_ptr<AST::Module>>
 Loader::parseModule(const std::filesystem::path &FilePath) {
   using namespace std::literals::string_view_literals;
   std::lock_guard Lock(Mutex);
+  Diag.reset();
   // Set path and check the header.
   if (auto Res = FMgr.setPath(FilePath); !Res) {
     spdlog::error(Res.error());
@@ -138,6 +139,7 @@ Loader::parseModule(const std::filesystem::path &FilePath) {
 Expect<std::unique_ptr<AST::Module>>
 Loader::parseModule(Span<const uint8_t> Code) {
   std::lock_guard Lock(Mutex);
+  Diag.reset();
   if (auto Res = FMgr.setCode(Code); !Res) {
     return Unexpect(Res);
   }
@@ -31,6 +31,7 @@ void FormChecker::reset(bool CleanGlobal) {
   CtrlStack.clear();
   Locals.clear();
   Returns.clear();
+  Diag.reset();
 
   if (CleanGlobal) {
     Types.clear();
@@ -194,6 +195,8 @@ Expect<void> FormChecker::checkInstrs(AST::InstrView Instrs) {
   // Validate instructions
   for (auto &Instr : Instrs) {
     if (auto Res = checkInstr(Instr); !Res) {
+      Diag.Code = Instr.getOpCode();
+      Diag.Offset = Instr.getOffset();
       return Unexpect(Res);
     }
   }
@@ -305,6 +308,8 @@ Expect<void> FormChecker::checkInstr(const AST::Instruction &Instr) {
       }
       spdlog::error(ErrCode::TypeCheckFailed);
       spdlog::error(ErrInfo::InfoMismatch(ExpV, GotV));
+      Diag.ExpTypes = std::move(ExpV);
+      Diag.GotTypes = std::move(GotV);
       return Unexpect(ErrCode::TypeCheckFailed);
     }
     return {};
@@ -1355,6 +1360,8 @@ Expect<VType> FormChecker::popType() {
 Expect<VType> FormChecker::popType(VType E) {
   auto Res = popType();
   if (!Res) {
+    Diag.ExpTypes = {VTypeToAST(E)};
+    Diag.GotTypes.clear();
     return Unexpect(Res);
   }
   if (*Res == VType::Unknown) {
@@ -1367,6 +1374,8 @@ Expect<VType> FormChecker::popType(VType E) {
     // Expect value on value stack is not matched
     spdlog::error(ErrCode::TypeCheckFailed);
     spdlog::error(ErrInfo::InfoMismatch(VTypeToAST(E), VTypeToAST(*Res)));
+    Diag.ExpTypes = {VTypeToAST(E)};
+    Diag.GotTypes = {VTypeToAST(*Res)};
     return Unexpect(ErrCode::TypeCheckFailed);
   }
   return *Res;
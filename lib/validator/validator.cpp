@@ -19,6 +19,7 @@ namespace Validator {
 Expect<void> Validator::validate(const AST::Module &Mod) {
   // https://webassembly.github.io/spec/core/valid/modules.html
   Checker.reset(true);
+  Diag.reset();
 
   // Register type definitions into FormChecker.
   for (auto &Type : Mod.getTypeSection().getContent()) {
@@ -27,6 +28,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate and register import section into FormChecker.
   if (auto Res = validate(Mod.getImportSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Import);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Import));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -34,6 +36,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate function section and register functions into FormChecker.
   if (auto Res = validate(Mod.getFunctionSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Function);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Function));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -41,6 +44,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate table section and register tables into FormChecker.
   if (auto Res = validate(Mod.getTableSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Table);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Table));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -48,6 +52,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate memory section and register memories into FormChecker.
   if (auto Res = validate(Mod.getMemorySection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Memory);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Memory));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -55,6 +60,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate global section and register globals into FormChecker.
   if (auto Res = validate(Mod.getGlobalSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Global);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Global));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -62,6 +68,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate export section.
   if (auto Res = validate(Mod.getExportSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Export);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Export));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -69,6 +76,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate start section.
   if (auto Res = validate(Mod.getStartSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Start);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Start));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -76,6 +84,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate element section which initialize tables.
   if (auto Res = validate(Mod.getElementSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Element);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Element));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -83,6 +92,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate data section which initialize memories.
   if (auto Res = validate(Mod.getDataSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Data);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Data));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -90,6 +100,7 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
 
   // Validate code section and expressions.
   if (auto Res = validate(Mod.getCodeSection()); !Res) {
+    setDiagnostic(ASTNodeAttr::Sec_Code);
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Sec_Code));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
     return Unexpect(Res);
@@ -117,6 +128,16 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
   return {};
 }
 
+// Record the diagnostic of the failed section. See
+// "include/validator/validator.h".
+void Validator::setDiagnostic(ASTNodeAttr Section) {
+  // Keep the function index recorded in the code section validation.
+  auto FuncIdx = Diag.FuncIndex;
+  Diag = Checker.getDiagnostic();
+  Diag.Section = Section;
+  Diag.FuncIndex = FuncIdx;
+}
+
 // Validate Limit type. See "include/validator/validator.h".
 Expect<void> Validator::validate(const AST::Limit &Lim) {
   if (Lim.hasMax() && Lim.getMin() > Lim.getMax()) {
@@ -149,6 +170,11 @@ Expect<void> Validator::validate(const AST::MemoryType &Mem) {
     spdlog::error(ErrInfo::InfoLimit(Lim.hasMax(), Lim.getMin(), Lim.getMax()));
     return Unexpect(ErrCode::InvalidMemPages);
   }
+  if (Lim.isShared() && !Lim.hasMax()) {
+    spdlog::error(ErrCode::SharedMemoryNoMax);
+    spdlog::error(ErrInfo::InfoLimit(Lim.hasMax(), Lim.getMin(), Lim.getMax()));
+    return Unexpect(ErrCode::SharedMemoryNoMax);
+  }
   return {};
 }
 
@@ -445,6 +471,7 @@ Expect<void> Validator::validate(const AST::CodeSection &CodeSec) {
       return Unexpect(ErrCode::InvalidFuncIdx);
     }
     if (auto Res = validate(CodeVec[Id], FuncVec[TId]); !Res) {
+      Diag.FuncIndex = TId;
       spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Seg_Code));
       return Unexpect(Res);
     }
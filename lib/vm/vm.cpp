@@ -7,6 +7,8 @@
 #include "host/wasi/wasimodule.h"
 #include "plugin/plugin.h"
 
+#include <algorithm>
+
 namespace WasmEdge {
 namespace VM {
 
@@ -126,6 +128,21 @@ VM::unsafeRegisterModule(const Runtime::Instance::ModuleInstance &ModInst) {
   return ExecutorEngine.registerModule(StoreRef, ModInst);
 }
 
+Expect<void> VM::unsafeUnregisterModule(std::string_view Name) {
+  const auto *ModInst = StoreRef.findModule(Name);
+  if (auto Res = StoreRef.unregisterModule(Name); unlikely(!Res)) {
+    return Unexpect(Res);
+  }
+  // Destroy the module instance if it is owned by this VM.
+  auto Iter = std::find_if(
+      RegModInst.begin(), RegModInst.end(),
+      [ModInst](const auto &Inst) { return Inst.get() == ModInst; });
+  if (Iter != RegModInst.end()) {
+    RegModInst.erase(Iter);
+  }
+  return {};
+}
+
 Expect<std::vector<std::pair<ValVariant, ValType>>>
 VM::unsafeRunWasmFile(const std::filesystem::path &Path, std::string_view Func,
                       Span<const ValVariant> Params,
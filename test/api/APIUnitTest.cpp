@@ -11,6 +11,7 @@
 #include <gtest/gtest.h>
 #include <string>
 #include <string_view>
+#include <thread>
 #include <vector>
 
 namespace {
@@ -293,6 +294,38 @@ TEST(APICoreTest, Log) {
   EXPECT_TRUE(true);
   WasmEdge_LogSetErrorLevel();
   EXPECT_TRUE(true);
+
+  // Forward the log messages to the callback
+  std::vector<std::pair<WasmEdge_LogLevel, std::string>> Messages;
+  WasmEdge_LogSetLevel(WasmEdge_LogLevel_Error);
+  WasmEdge_LogSetCallback(
+      [](void *Data, const WasmEdge_LogLevel Level, const WasmEdge_String,
+         const WasmEdge_String Message) {
+        static_cast<std::vector<std::pair<WasmEdge_LogLevel, std::string>> *>(
+            Data)
+            ->emplace_back(Level, std::string(Message.Buf, Message.Length));
+      },
+      &Messages);
+  WasmEdge_LoaderContext *Loader = WasmEdge_LoaderCreate(nullptr);
+  WasmEdge_ASTModuleContext *Mod = nullptr;
+  const uint8_t Malformed[] = {0x00, 0x61, 0x73, 0x00};
+  EXPECT_FALSE(WasmEdge_ResultOK(
+      WasmEdge_LoaderParseFromBuffer(Loader, &Mod, Malformed, 4)));
+  EXPECT_FALSE(Messages.empty());
+  for (const auto &[Level, Message] : Messages) {
+    EXPECT_EQ(Level, WasmEdge_LogLevel_Error);
+    EXPECT_FALSE(Message.empty());
+  }
+
+  // Restore the console output
+  const size_t Len = Messages.size();
+  WasmEdge_LogSetCallback(nullptr, nullptr);
+  EXPECT_FALSE(WasmEdge_ResultOK(
+      WasmEdge_LoaderParseFromBuffer(Loader, &Mod, Malformed, 4)));
+  EXPECT_EQ(Messages.size(), Len);
+  WasmEdge_LoaderDelete(Loader);
+  WasmEdge_LogSetErrorLevel();
+  EXPECT_TRUE(true);
 }
 
 TEST(APICoreTest, Value) {
@@ -533,6 +566,16 @@ TEST(APICoreTest, MemoryType) {
   WasmEdge_MemoryTypeDelete(nullptr);
   WasmEdge_MemoryTypeDelete(MType);
   WasmEdge_MemoryTypeDelete(nullptr);
+  WasmEdge_Limit Lim3 = {
+      .HasMax = true, .Min = 10, .Max = 20, .Shared = true};
+  WasmEdge_Limit Lim4 = {
+      .HasMax = false, .Min = 30, .Max = 30, .Shared = true};
+  MType = WasmEdge_MemoryTypeCreate(Lim3);
+  EXPECT_TRUE(WasmEdge_LimitIsEqual(WasmEdge_MemoryTypeGetLimit(MType), Lim3));
+  EXPECT_FALSE(
+      WasmEdge_LimitIsEqual(WasmEdge_MemoryTypeGetLimit(MType), Lim1));
+  WasmEdge_MemoryTypeDelete(MType);
+  EXPECT_EQ(WasmEdge_MemoryTypeCreate(Lim4), nullptr);
 }
 
 TEST(APICoreTest, GlobalType) {
@@ -884,6 +927,55 @@ TEST(APICoreTest, ExportType) {
   WasmEdge_ASTModuleDelete(Mod);
 }
 
+TEST(APICoreTest, CustomSection) {
+  WasmEdge_ASTModuleContext *Mod = nullptr;
+  WasmEdge_String Name;
+  const uint8_t *Content = nullptr;
+  WasmEdge_LoaderContext *Loader = WasmEdge_LoaderCreate(nullptr);
+
+  // Module with two custom sections: "meta" with content {0x01, 0x02} and an
+  // empty "version" section.
+  std::vector<uint8_t> Buf = {0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00,
+                              0x00, 0x07, 0x04, 0x6D, 0x65, 0x74, 0x61, 0x01,
+                              0x02, 0x00, 0x08, 0x07, 0x76, 0x65, 0x72, 0x73,
+                              0x69, 0x6F, 0x6E};
+  EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_LoaderParseFromBuffer(
+      Loader, &Mod, Buf.data(), static_cast<uint32_t>(Buf.size()))));
+  EXPECT_NE(Mod, nullptr);
+
+  // AST list custom sections
+  EXPECT_EQ(WasmEdge_ASTModuleListCustomSectionsLength(nullptr), 0U);
+  EXPECT_EQ(WasmEdge_ASTModuleListCustomSectionsLength(Mod), 2U);
+
+  // AST get custom section name
+  Name = WasmEdge_ASTModuleGetCustomSectionName(nullptr, 0);
+  EXPECT_EQ(Name.Length, 0U);
+  EXPECT_EQ(Name.Buf, nullptr);
+  Name = WasmEdge_ASTModuleGetCustomSectionName(Mod, 2);
+  EXPECT_EQ(Name.Length, 0U);
+  EXPECT_EQ(Name.Buf, nullptr);
+  Name = WasmEdge_ASTModuleGetCustomSectionName(Mod, 0);
+  EXPECT_EQ(std::string(Name.Buf, Name.Length), std::string("meta"));
+  Name = WasmEdge_ASTModuleGetCustomSectionName(Mod, 1);
+  EXPECT_EQ(std::string(Name.Buf, Name.Length), std::string("version"));
+
+  // AST get custom section content
+  EXPECT_EQ(WasmEdge_ASTModuleGetCustomSectionContent(nullptr, 0, &Content),
+            0U);
+  EXPECT_EQ(Content, nullptr);
+  EXPECT_EQ(WasmEdge_ASTModuleGetCustomSectionContent(Mod, 2, &Content), 0U);
+  EXPECT_EQ(Content, nullptr);
+  EXPECT_EQ(WasmEdge_ASTModuleGetCustomSectionContent(Mod, 0, nullptr), 2U);
+  EXPECT_EQ(WasmEdge_ASTModuleGetCustomSectionContent(Mod, 0, &Content), 2U);
+  EXPECT_NE(Content, nullptr);
+  EXPECT_EQ(Content[0], 0x01U);
+  EXPECT_EQ(Content[1], 0x02U);
+  EXPECT_EQ(WasmEdge_ASTModuleGetCustomSectionContent(Mod, 1, &Content), 0U);
+
+  WasmEdge_LoaderDelete(Loader);
+  WasmEdge_ASTModuleDelete(Mod);
+}
+
 #ifdef WASMEDGE_BUILD_AOT_RUNTIME
 TEST(APICoreTest, Compiler) {
   WasmEdge_ConfigureContext *Conf = WasmEdge_ConfigureCreate();
@@ -1052,6 +1144,114 @@ TEST(APICoreTest, Validator) {
   WasmEdge_ConfigureDelete(Conf);
 }
 
+TEST(APICoreTest, Diagnostic) {
+  // (module
+  //   (import "m" "f" (func))
+  //   (func (result i32) i32.const 1 i64.const 2 i32.add))
+  std::vector<uint8_t> Wasm = {
+      0x00U, 0x61U, 0x73U, 0x6DU, 0x01U, 0x00U, 0x00U, 0x00U, 0x01U, 0x08U,
+      0x02U, 0x60U, 0x00U, 0x00U, 0x60U, 0x00U, 0x01U, 0x7FU, 0x02U, 0x07U,
+      0x01U, 0x01U, 0x6DU, 0x01U, 0x66U, 0x00U, 0x00U, 0x03U, 0x02U, 0x01U,
+      0x01U, 0x0AU, 0x09U, 0x01U, 0x06U, 0x00U, 0x41U, 0x01U, 0x42U, 0x02U,
+      0x6AU, 0x0BU};
+  WasmEdge_LoaderContext *Loader = WasmEdge_LoaderCreate(nullptr);
+  WasmEdge_ValidatorContext *Validator = WasmEdge_ValidatorCreate(nullptr);
+  WasmEdge_ASTModuleContext *Mod = nullptr;
+  const WasmEdge_DiagnosticContext *Diag = nullptr;
+  WasmEdge_String Str;
+  uint64_t Offset = 0;
+  uint32_t FuncIdx = 0;
+  WasmEdge_ValType Types[2];
+
+  // Get diagnostic
+  EXPECT_EQ(WasmEdge_LoaderGetDiagnostic(nullptr), nullptr);
+  EXPECT_EQ(WasmEdge_ValidatorGetDiagnostic(nullptr), nullptr);
+  Str = WasmEdge_DiagnosticGetSection(nullptr);
+  EXPECT_EQ(Str.Length, 0U);
+  EXPECT_FALSE(WasmEdge_DiagnosticGetOffset(nullptr, &Offset));
+  EXPECT_FALSE(WasmEdge_DiagnosticGetFunctionIndex(nullptr, &FuncIdx));
+  EXPECT_EQ(WasmEdge_DiagnosticGetExpectedTypes(nullptr, Types, 2), 0U);
+
+  // Loading failure
+  EXPECT_FALSE(WasmEdge_ResultOK(WasmEdge_LoaderParseFromBuffer(
+      Loader, &Mod, Wasm.data(), static_cast<uint32_t>(Wasm.size() - 1))));
+  Diag = WasmEdge_LoaderGetDiagnostic(Loader);
+  EXPECT_NE(Diag, nullptr);
+  Str = WasmEdge_DiagnosticGetSection(Diag);
+  EXPECT_EQ(std::string(Str.Buf, Str.Length), std::string("code section"));
+  EXPECT_TRUE(WasmEdge_DiagnosticGetOffset(Diag, &Offset));
+
+  // Validation failure
+  EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_LoaderParseFromBuffer(
+      Loader, &Mod, Wasm.data(), static_cast<uint32_t>(Wasm.size()))));
+  Diag = WasmEdge_LoaderGetDiagnostic(Loader);
+  EXPECT_FALSE(WasmEdge_DiagnosticGetOffset(Diag, &Offset));
+  EXPECT_FALSE(WasmEdge_ResultOK(WasmEdge_ValidatorValidate(Validator, Mod)));
+  Diag = WasmEdge_ValidatorGetDiagnostic(Validator);
+  EXPECT_NE(Diag, nullptr);
+  Str = WasmEdge_DiagnosticGetSection(Diag);
+  EXPECT_EQ(std::string(Str.Buf, Str.Length), std::string("code section"));
+  EXPECT_TRUE(WasmEdge_DiagnosticGetOffset(Diag, &Offset));
+  EXPECT_EQ(Offset, 40U);
+  EXPECT_TRUE(WasmEdge_DiagnosticGetFunctionIndex(Diag, &FuncIdx));
+  EXPECT_EQ(FuncIdx, 1U);
+  Str = WasmEdge_DiagnosticGetInstruction(Diag);
+  EXPECT_EQ(std::string(Str.Buf, Str.Length), std::string("i32.add"));
+  EXPECT_EQ(WasmEdge_DiagnosticGetExpectedTypes(Diag, Types, 2), 1U);
+  EXPECT_EQ(Types[0], WasmEdge_ValType_I32);
+  EXPECT_EQ(WasmEdge_DiagnosticGetActualTypes(Diag, Types, 2), 1U);
+  EXPECT_EQ(Types[0], WasmEdge_ValType_I64);
+
+  WasmEdge_ASTModuleDelete(Mod);
+  WasmEdge_ValidatorDelete(Validator);
+  WasmEdge_LoaderDelete(Loader);
+}
+
+TEST(APICoreTest, Backtrace) {
+  // (module
+  //   (func (export "f") call 1)
+  //   (func unreachable))
+  std::vector<uint8_t> Wasm = {
+      0x00U, 0x61U, 0x73U, 0x6DU, 0x01U, 0x00U, 0x00U, 0x00U, 0x01U, 0x04U,
+      0x01U, 0x60U, 0x00U, 0x00U, 0x03U, 0x03U, 0x02U, 0x00U, 0x00U, 0x07U,
+      0x05U, 0x01U, 0x01U, 0x66U, 0x00U, 0x00U, 0x0AU, 0x0AU, 0x02U, 0x04U,
+      0x00U, 0x10U, 0x01U, 0x0BU, 0x03U, 0x00U, 0x00U, 0x0BU};
+  WasmEdge_VMContext *VM = WasmEdge_VMCreate(nullptr, nullptr);
+  WasmEdge_String FuncName = WasmEdge_StringCreateByCString("f");
+  WasmEdge_TrapFrame Frames[4];
+
+  // Trap in the callee
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_Unreachable,
+                         WasmEdge_VMRunWasmFromBuffer(
+                             VM, Wasm.data(), static_cast<uint32_t>(Wasm.size()),
+                             FuncName, nullptr, 0, nullptr, 0)));
+  EXPECT_EQ(WasmEdge_TrapGetBacktraceLength(), 2U);
+  EXPECT_EQ(WasmEdge_TrapGetBacktrace(nullptr, 4), 2U);
+  EXPECT_EQ(WasmEdge_TrapGetBacktrace(Frames, 4), 2U);
+  EXPECT_EQ(Frames[0].ModuleName.Length, 0U);
+  EXPECT_EQ(Frames[0].FunctionIndex, 1U);
+  EXPECT_TRUE(Frames[0].HasOffset);
+  EXPECT_EQ(Frames[0].Offset, 36U);
+  EXPECT_EQ(Frames[1].FunctionIndex, 0U);
+  EXPECT_TRUE(Frames[1].HasOffset);
+  EXPECT_EQ(Frames[1].Offset, 31U);
+
+  // The backtrace is recorded again by the next failed invocation
+  EXPECT_FALSE(WasmEdge_ResultOK(
+      WasmEdge_VMExecute(VM, FuncName, nullptr, 0, nullptr, 0)));
+  EXPECT_EQ(WasmEdge_TrapGetBacktraceLength(), 2U);
+
+  // The backtrace is cleared when the invocation fails before running
+  WasmEdge_Value Param = WasmEdge_ValueGenI32(0);
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_FuncSigMismatch,
+      WasmEdge_VMExecute(VM, FuncName, &Param, 1, nullptr, 0)));
+  EXPECT_EQ(WasmEdge_TrapGetBacktraceLength(), 0U);
+
+  WasmEdge_StringDelete(FuncName);
+  WasmEdge_VMDelete(VM);
+}
+
 TEST(APICoreTest, ExecutorWithStatistics) {
   // Create contexts
   WasmEdge_ConfigureContext *Conf = WasmEdge_ConfigureCreate();
@@ -1091,6 +1291,31 @@ TEST(APICoreTest, ExecutorWithStatistics) {
   EXPECT_TRUE(true);
   WasmEdge_StatisticsSetCostLimit(nullptr, 1ULL);
   EXPECT_TRUE(true);
+  EXPECT_EQ(WasmEdge_StatisticsGetCostLimit(Stat), 100000000000000ULL);
+  EXPECT_EQ(WasmEdge_StatisticsGetCostLimit(nullptr), 0ULL);
+
+  // Statistics set cost limit handler
+  WasmEdge_StatisticsSetCostLimitHandler(
+      Stat,
+      [](void *, WasmEdge_StatisticsContext *Cxt) {
+        WasmEdge_StatisticsSetCostLimit(
+            Cxt, WasmEdge_StatisticsGetCostLimit(Cxt) + 100000ULL);
+        return true;
+      },
+      nullptr);
+  EXPECT_TRUE(true);
+  WasmEdge_StatisticsSetCostLimitHandler(nullptr, nullptr, nullptr);
+  EXPECT_TRUE(true);
+
+  // Statistics set profiling and get profile
+  WasmEdge_StatisticsSetProfiling(Stat, true);
+  EXPECT_TRUE(true);
+  WasmEdge_StatisticsSetProfiling(nullptr, true);
+  EXPECT_TRUE(true);
+  EXPECT_EQ(WasmEdge_StatisticsGetProfileLength(Stat), 0U);
+  EXPECT_EQ(WasmEdge_StatisticsGetProfileLength(nullptr), 0U);
+  EXPECT_EQ(WasmEdge_StatisticsGetProfile(Stat, nullptr, 0), 0U);
+  EXPECT_EQ(WasmEdge_StatisticsGetProfile(nullptr, nullptr, 0), 0U);
 
   // Executor creation and deletion
   WasmEdge_ExecutorContext *ExecCxt = WasmEdge_ExecutorCreate(nullptr, nullptr);
@@ -1442,6 +1667,23 @@ TEST(APICoreTest, Store) {
   EXPECT_EQ(std::string(Names[0].Buf, Names[0].Length), std::string("extern"));
   EXPECT_EQ(std::string(Names[1].Buf, Names[1].Length), std::string("module"));
 
+  // Store unregister module
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_StoreUnregisterModule(nullptr, ModName[1])));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_UnknownModule,
+                         WasmEdge_StoreUnregisterModule(Store, ModName[2])));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_ModuleInUse,
+                         WasmEdge_StoreUnregisterModule(Store, ModName[1])));
+  EXPECT_EQ(WasmEdge_StoreFindModule(Store, ModName[1]), HostMod);
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_StoreUnregisterModule(Store, ModName[0])));
+  EXPECT_EQ(WasmEdge_StoreFindModule(Store, ModName[0]), nullptr);
+  EXPECT_EQ(WasmEdge_StoreListModuleLength(Store), 1U);
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_UnknownModule,
+                         WasmEdge_StoreUnregisterModule(Store, ModName[0])));
+  EXPECT_TRUE(registerModule(nullptr, Store, ModRegCxt));
+  EXPECT_EQ(WasmEdge_StoreFindModule(Store, ModName[0]), ModRegCxt);
+
   // Module instance get module name
   Names[0] = WasmEdge_ModuleInstanceGetModuleName(nullptr);
   EXPECT_EQ(std::string(Names[0].Buf, Names[0].Length), std::string(""));
@@ -1544,11 +1786,39 @@ TEST(APICoreTest, Store) {
   EXPECT_EQ(WasmEdge_ModuleInstanceFindGlobal(nullptr, Names[1]), nullptr);
   EXPECT_EQ(WasmEdge_ModuleInstanceFindGlobal(ModCxt, ErrName), nullptr);
 
+  // Module instance list exports in the export order
+  WasmEdge_ExternalType Types[15];
+  EXPECT_EQ(WasmEdge_ModuleInstanceListExportLength(ModCxt), 16U);
+  EXPECT_EQ(WasmEdge_ModuleInstanceListExportLength(nullptr), 0U);
+  EXPECT_EQ(WasmEdge_ModuleInstanceListExport(nullptr, Names, Types, 15), 0U);
+  EXPECT_EQ(WasmEdge_ModuleInstanceListExport(ModCxt, nullptr, nullptr, 15),
+            16U);
+  std::memset(Names, 0, sizeof(WasmEdge_String) * 15);
+  EXPECT_EQ(WasmEdge_ModuleInstanceListExport(ModCxt, Names, Types, 15), 16U);
+  EXPECT_EQ(std::string(Names[0].Buf, Names[0].Length), std::string("func-1"));
+  EXPECT_EQ(Types[0], WasmEdge_ExternalType_Function);
+  EXPECT_EQ(std::string(Names[5].Buf, Names[5].Length),
+            std::string("func-mul-2"));
+  EXPECT_EQ(Types[5], WasmEdge_ExternalType_Function);
+  EXPECT_EQ(std::string(Names[11].Buf, Names[11].Length),
+            std::string("tab-func"));
+  EXPECT_EQ(Types[11], WasmEdge_ExternalType_Table);
+  EXPECT_EQ(std::string(Names[13].Buf, Names[13].Length), std::string("mem"));
+  EXPECT_EQ(Types[13], WasmEdge_ExternalType_Memory);
+  EXPECT_EQ(std::string(Names[14].Buf, Names[14].Length),
+            std::string("glob-mut-i32"));
+  EXPECT_EQ(Types[14], WasmEdge_ExternalType_Global);
+
   // Delete module
-  WasmEdge_ModuleInstanceDelete(HostMod);
   WasmEdge_ModuleInstanceDelete(ModCxt);
   WasmEdge_ModuleInstanceDelete(ModRegCxt);
 
+  // Store unregister module after the importing modules deleted
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_StoreUnregisterModule(Store, ModName[1])));
+  EXPECT_TRUE(registerModule(nullptr, Store, HostMod));
+  WasmEdge_ModuleInstanceDelete(HostMod);
+
   // Test store after module deletion
   EXPECT_EQ(WasmEdge_StoreListModuleLength(Store), 0U);
 
@@ -1689,6 +1959,31 @@ TEST(APICoreTest, Instance) {
                 WasmEdge_ValueGetExternRef(Val)),
             &TabCxt);
 
+  // Table instance fill and copy
+  Val = WasmEdge_ValueGenExternRef(&TabType);
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_TableInstanceFill(TabCxt, Val, 0, 3)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_TableInstanceFill(nullptr, Val, 0, 3)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_RefTypeMismatch,
+                         WasmEdge_TableInstanceFill(TabCxt, TmpVal, 0, 3)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_TableOutOfBounds,
+                         WasmEdge_TableInstanceFill(TabCxt, Val, 16, 3)));
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_TableInstanceCopy(TabCxt, TabCxt, 1, 0, 3)));
+  Val = WasmEdge_ValueGenI32(0);
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_TableInstanceGetData(TabCxt, &Val, 3)));
+  EXPECT_EQ(reinterpret_cast<WasmEdge_TableTypeContext **>(
+                WasmEdge_ValueGetExternRef(Val)),
+            &TabType);
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_TableInstanceCopy(nullptr, TabCxt, 0, 0, 3)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_TableInstanceCopy(TabCxt, nullptr, 0, 0, 3)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_TableOutOfBounds,
+                         WasmEdge_TableInstanceCopy(TabCxt, TabCxt, 16, 0, 3)));
+
   // Table instance deletion
   WasmEdge_TableInstanceDelete(nullptr);
   EXPECT_TRUE(true);
@@ -1799,12 +2094,63 @@ TEST(APICoreTest, Instance) {
       WasmEdge_MemoryInstanceGetData(MemCxt, DataGet.data(), 70000, 10)));
   EXPECT_EQ(DataGet, DataSet);
 
+  // Memory instance atomic notify and wait on a non-shared memory
+  uint32_t AtomicRes = 0;
+  EXPECT_TRUE(WasmEdge_ResultOK(
+      WasmEdge_MemoryInstanceAtomicNotify(MemCxt, 100, 1, &AtomicRes)));
+  EXPECT_EQ(AtomicRes, 0U);
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_WrongVMWorkflow,
+      WasmEdge_MemoryInstanceAtomicNotify(nullptr, 100, 1, &AtomicRes)));
+  EXPECT_TRUE(
+      isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                 WasmEdge_MemoryInstanceAtomicNotify(MemCxt, 100, 1, nullptr)));
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_UnalignedAtomicAccess,
+      WasmEdge_MemoryInstanceAtomicNotify(MemCxt, 101, 1, &AtomicRes)));
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_MemoryOutOfBounds,
+      WasmEdge_MemoryInstanceAtomicNotify(MemCxt, 131072, 1, &AtomicRes)));
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_ExpectSharedMemory,
+      WasmEdge_MemoryInstanceAtomicWait32(MemCxt, 100, 0, -1, &AtomicRes)));
+
   // Memory instance deletion
   WasmEdge_MemoryInstanceDelete(nullptr);
   EXPECT_TRUE(true);
   WasmEdge_MemoryInstanceDelete(MemCxt);
   EXPECT_TRUE(true);
 
+  // Memory instance atomic notify and wait on a shared memory
+  MemType = WasmEdge_MemoryTypeCreate(
+      WasmEdge_Limit{.HasMax = true, .Min = 1, .Max = 1, .Shared = true});
+  MemCxt = WasmEdge_MemoryInstanceCreate(MemType);
+  WasmEdge_MemoryTypeDelete(MemType);
+  EXPECT_NE(MemCxt, nullptr);
+  EXPECT_TRUE(WasmEdge_ResultOK(
+      WasmEdge_MemoryInstanceAtomicWait32(MemCxt, 0, 1, -1, &AtomicRes)));
+  EXPECT_EQ(AtomicRes, 1U);
+  EXPECT_TRUE(WasmEdge_ResultOK(
+      WasmEdge_MemoryInstanceAtomicWait64(MemCxt, 8, 0, 1000000, &AtomicRes)));
+  EXPECT_EQ(AtomicRes, 2U);
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_UnalignedAtomicAccess,
+      WasmEdge_MemoryInstanceAtomicWait64(MemCxt, 4, 0, -1, &AtomicRes)));
+  uint32_t WaitRes = UINT32_MAX;
+  std::thread Waiter([&]() {
+    EXPECT_TRUE(WasmEdge_ResultOK(
+        WasmEdge_MemoryInstanceAtomicWait32(MemCxt, 0, 0, -1, &WaitRes)));
+  });
+  do {
+    std::this_thread::yield();
+    EXPECT_TRUE(WasmEdge_ResultOK(
+        WasmEdge_MemoryInstanceAtomicNotify(MemCxt, 0, 1, &AtomicRes)));
+  } while (AtomicRes == 0);
+  Waiter.join();
+  EXPECT_EQ(AtomicRes, 1U);
+  EXPECT_EQ(WaitRes, 0U);
+  WasmEdge_MemoryInstanceDelete(MemCxt);
+
   // Global instance
   WasmEdge_GlobalInstanceContext *GlobCCxt, *GlobVCxt;
   WasmEdge_GlobalTypeContext *GlobCType, *GlobVType;
@@ -243,6 +243,9 @@ TEST(TypeTest, LoadTableType) {
   WasmEdge::Loader::Loader LdrNoRefType(Conf);
   Conf.addProposal(WasmEdge::Proposal::BulkMemoryOperations);
   Conf.addProposal(WasmEdge::Proposal::ReferenceTypes);
+  Conf.addProposal(WasmEdge::Proposal::Threads);
+  WasmEdge::Loader::Loader LdrThreads(Conf);
+  Conf.removeProposal(WasmEdge::Proposal::Threads);
 
   // 3. Test load table type, which is reference type and limit.
   //
@@ -254,6 +257,7 @@ TEST(TypeTest, LoadTableType) {
   //   6.  Load limit with min and max.
   //   7.  Load invalid ExternRef without Ref-Types proposal.
   //   8.  Load invalid reference type without Ref-Types proposal.
+  //   9.  Load invalid shared limit with Threads proposal.
 
   Vec = {
       0x04U, // Table section
@@ -332,6 +336,19 @@ TEST(TypeTest, LoadTableType) {
       0x00U  // Min = 0
   };
   EXPECT_FALSE(LdrNoRefType.parseModule(prefixedVec(Vec)));
+
+  Vec = {
+      0x04U, // Table section
+      0x05U, // Content size = 5
+      0x01U, // Vector length = 1
+      0x70U, // Reference type
+      0x03U, // Shared limit with min and max
+      0x00U, // Min = 0
+      0x01U  // Max = 1
+  };
+  auto Res = LdrThreads.parseModule(prefixedVec(Vec));
+  ASSERT_FALSE(Res);
+  EXPECT_EQ(WasmEdge::ErrCode::SharedTable, Res.error());
 }
 
 TEST(TypeTest, LoadMemoryType) {